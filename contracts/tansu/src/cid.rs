@@ -0,0 +1,192 @@
+//! IPFS content identifier (CID) validation and binary encoding.
+//!
+//! CIDs enter the contract as strings, either CIDv0 (base58btc `Qm...`) or
+//! CIDv1 with a multibase prefix: base32 (`b`, `B`), base58btc (`z`) or
+//! base16 (`f`, `F`). They are stored in binary form: the multihash for a
+//! CIDv0, the version, content codec and multihash for a CIDv1.
+
+use soroban_sdk::{Bytes, Env, String, panic_with_error};
+
+use crate::errors::ContractErrors;
+
+const CIDV0_LENGTH: u32 = 46;
+const MAX_CID_LENGTH: usize = 128;
+const MAX_BINARY_LENGTH: usize = 80;
+const MAX_DIGEST_LENGTH: u64 = 64;
+const SHA2_256: u8 = 0x12;
+const SHA2_256_LENGTH: u8 = 32;
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Decode a CID string to its binary form.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `cid` - The CID string
+///
+/// # Returns
+/// * `Result<Bytes, ContractErrors>` - The binary CID or `InvalidCid`
+pub fn decode(env: &Env, cid: &String) -> Result<Bytes, ContractErrors> {
+    let len = cid.len() as usize;
+    if !(2..=MAX_CID_LENGTH).contains(&len) {
+        return Err(ContractErrors::InvalidCid);
+    }
+    let mut text = [0u8; MAX_CID_LENGTH];
+    let text = &mut text[..len];
+    cid.copy_into_slice(text);
+
+    let mut raw = [0u8; MAX_BINARY_LENGTH];
+    let raw_len = if cid.len() == CIDV0_LENGTH && text.starts_with(b"Qm") {
+        // a CIDv0 is a bare sha2-256 multihash
+        let raw_len = decode_base58(text, &mut raw)?;
+        if raw_len != 2 + SHA2_256_LENGTH as usize
+            || raw[0] != SHA2_256
+            || raw[1] != SHA2_256_LENGTH
+        {
+            return Err(ContractErrors::InvalidCid);
+        }
+        raw_len
+    } else {
+        let encoded = &text[1..];
+        let raw_len = match text[0] {
+            b'b' => decode_base32(encoded, false, &mut raw)?,
+            b'B' => decode_base32(encoded, true, &mut raw)?,
+            b'z' => decode_base58(encoded, &mut raw)?,
+            b'f' | b'F' => decode_base16(encoded, &mut raw)?,
+            _ => return Err(ContractErrors::InvalidCid),
+        };
+        validate_cidv1(&raw[..raw_len])?;
+        raw_len
+    };
+
+    Ok(Bytes::from_slice(env, &raw[..raw_len]))
+}
+
+/// Decode a CID string to its binary form, see `decode`.
+///
+/// # Panics
+/// * If the CID is malformed
+pub fn parse(env: &Env, cid: &String) -> Bytes {
+    decode(env, cid).unwrap_or_else(|error| panic_with_error!(env, error))
+}
+
+/// Check the structure of a binary CIDv1: `<version><codec><multihash>`.
+fn validate_cidv1(raw: &[u8]) -> Result<(), ContractErrors> {
+    let mut position = 0;
+    let version = read_varint(raw, &mut position)?;
+    let _codec = read_varint(raw, &mut position)?;
+    let _hash_function = read_varint(raw, &mut position)?;
+    let digest_length = read_varint(raw, &mut position)?;
+    if version != 1
+        || digest_length == 0
+        || digest_length > MAX_DIGEST_LENGTH
+        || (raw.len() - position) as u64 != digest_length
+    {
+        return Err(ContractErrors::InvalidCid);
+    }
+    Ok(())
+}
+
+/// Read an unsigned varint as used by multiformats.
+fn read_varint(raw: &[u8], position: &mut usize) -> Result<u64, ContractErrors> {
+    let mut value: u64 = 0;
+    // multiformats varints are at most 9 bytes long
+    for shift in (0..63).step_by(7) {
+        let byte = *raw.get(*position).ok_or(ContractErrors::InvalidCid)?;
+        *position += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(ContractErrors::InvalidCid)
+}
+
+fn decode_base58(text: &[u8], raw: &mut [u8; MAX_BINARY_LENGTH]) -> Result<usize, ContractErrors> {
+    // big number in little endian order
+    let mut number = [0u8; MAX_BINARY_LENGTH];
+    let mut number_len = 0;
+    for c in text {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|digit| digit == c)
+            .ok_or(ContractErrors::InvalidCid)? as u32;
+        for byte in number[..number_len].iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            if number_len == MAX_BINARY_LENGTH {
+                return Err(ContractErrors::InvalidCid);
+            }
+            number[number_len] = carry as u8;
+            number_len += 1;
+            carry >>= 8;
+        }
+    }
+
+    // each leading '1' is a leading zero byte
+    let zeros = text.iter().take_while(|c| **c == b'1').count();
+    if zeros + number_len > MAX_BINARY_LENGTH {
+        return Err(ContractErrors::InvalidCid);
+    }
+    raw[..zeros].fill(0);
+    for (byte, digit) in raw[zeros..zeros + number_len]
+        .iter_mut()
+        .zip(number[..number_len].iter().rev())
+    {
+        *byte = *digit;
+    }
+    Ok(zeros + number_len)
+}
+
+fn decode_base32(
+    text: &[u8],
+    upper_case: bool,
+    raw: &mut [u8; MAX_BINARY_LENGTH],
+) -> Result<usize, ContractErrors> {
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    let mut raw_len = 0;
+    for c in text {
+        if c.is_ascii_alphabetic() && c.is_ascii_uppercase() != upper_case {
+            return Err(ContractErrors::InvalidCid);
+        }
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|digit| *digit == c.to_ascii_lowercase())
+            .ok_or(ContractErrors::InvalidCid)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            if raw_len == MAX_BINARY_LENGTH {
+                return Err(ContractErrors::InvalidCid);
+            }
+            raw[raw_len] = (buffer >> bits) as u8;
+            raw_len += 1;
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    // no padding, the remaining bits must be zero
+    if bits >= 5 || buffer != 0 {
+        return Err(ContractErrors::InvalidCid);
+    }
+    Ok(raw_len)
+}
+
+fn decode_base16(text: &[u8], raw: &mut [u8; MAX_BINARY_LENGTH]) -> Result<usize, ContractErrors> {
+    if !text.len().is_multiple_of(2) || text.len() / 2 > MAX_BINARY_LENGTH {
+        return Err(ContractErrors::InvalidCid);
+    }
+    for (byte, pair) in raw.iter_mut().zip(text.chunks(2)) {
+        let high = (pair[0] as char).to_digit(16);
+        let low = (pair[1] as char).to_digit(16);
+        match (high, low) {
+            (Some(high), Some(low)) => *byte = (high << 4 | low) as u8,
+            _ => return Err(ContractErrors::InvalidCid),
+        }
+    }
+    Ok(text.len() / 2)
+}
@@ -1,7 +1,8 @@
 #![allow(clippy::too_many_arguments)]
 
 use crate::{
-    DaoTrait, MembershipTrait, Tansu, TansuArgs, TansuClient, TansuTrait, errors, events, types,
+    DaoTrait, MembershipTrait, Tansu, TansuArgs, TansuClient, TansuTrait, cid, errors, events, ttl,
+    types,
 };
 use soroban_sdk::crypto::bls12_381::G1Affine;
 use soroban_sdk::{
@@ -12,7 +13,7 @@ use soroban_sdk::{
 const PROPOSAL_COLLATERAL: i128 = 100 * 10_000_000;
 const VOTE_COLLATERAL: i128 = 10 * 10_000_000;
 const MAX_TITLE_LENGTH: u32 = 256;
-const MAX_PROPOSALS_PER_PAGE: u32 = 9;
+pub const MAX_PROPOSALS_PER_PAGE: u32 = 9;
 const MAX_PAGES: u32 = 1000;
 const MIN_VOTING_PERIOD: u64 = 24 * 3600; // 1 day in seconds
 const MAX_VOTING_PERIOD: u64 = 30 * 24 * 3600; // 30 days in seconds
@@ -38,6 +39,7 @@ impl DaoTrait for Tansu {
         project_key: Bytes,
         public_key: String,
     ) {
+        let project_key = crate::resolve_key(&env, &project_key);
         crate::auth_maintainers(&env, &maintainer, &project_key);
 
         // generators
@@ -83,6 +85,7 @@ impl DaoTrait for Tansu {
     /// # Panics
     /// * If no anonymous voting configuration exists for the project
     fn get_anonymous_voting_config(env: Env, project_key: Bytes) -> types::AnonymousVoteConfig {
+        let project_key = crate::resolve_key(&env, &project_key);
         env.storage()
             .persistent()
             .get::<types::ProjectKey, types::AnonymousVoteConfig>(
@@ -119,6 +122,7 @@ impl DaoTrait for Tansu {
         votes: Vec<u128>,
         seeds: Vec<u128>,
     ) -> Vec<BytesN<96>> {
+        let project_key = crate::resolve_key(&env, &project_key);
         // Validate that votes and seeds have the same length
         if votes.len() != seeds.len() {
             panic_with_error!(&env, &errors::ContractErrors::TallySeedError);
@@ -166,6 +170,7 @@ impl DaoTrait for Tansu {
     /// # Panics
     /// * If the title is too long
     /// * If the voting period is invalid
+    /// * If the CID is malformed
     /// * If the project doesn't exist
     #[allow(clippy::too_many_arguments)]
     fn create_proposal(
@@ -181,19 +186,21 @@ impl DaoTrait for Tansu {
     ) -> u32 {
         Tansu::require_not_paused(env.clone());
 
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::require_active(&env, &project_key);
+
         // Some input validations
         let curr_timestamp = env.ledger().timestamp();
         let min_voting_timestamp = curr_timestamp + MIN_VOTING_PERIOD;
         let max_voting_timestamp = curr_timestamp + MAX_VOTING_PERIOD;
-        let ipfs_len = ipfs.len();
         let title_len = title.len();
 
         if !((min_voting_timestamp..=max_voting_timestamp).contains(&voting_ends_at)
-            && (10..=MAX_TITLE_LENGTH).contains(&title_len)
-            && (32..=64).contains(&ipfs_len))
+            && (10..=MAX_TITLE_LENGTH).contains(&title_len))
         {
             panic_with_error!(&env, &errors::ContractErrors::ProposalInputValidation);
         }
+        let ipfs = cid::parse(&env, &ipfs);
 
         // proposers deposit a collateral
         proposer.require_auth();
@@ -287,18 +294,19 @@ impl DaoTrait for Tansu {
             panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound);
         }
 
-        env.storage().persistent().set(
-            &types::ProjectKey::DaoTotalProposals(project_key.clone()),
-            &next_id,
-        );
+        let total_proposals_key = types::ProjectKey::DaoTotalProposals(project_key.clone());
+        env.storage()
+            .persistent()
+            .set(&total_proposals_key, &next_id);
+        ttl::extend(&env, &total_proposals_key);
 
         let mut dao_page = Self::get_dao(env.clone(), project_key.clone(), page);
         dao_page.proposals.push_back(proposal.clone());
 
-        env.storage().persistent().set(
-            &types::ProjectKey::Dao(project_key.clone(), page),
-            &dao_page,
-        );
+        let dao_key = types::ProjectKey::Dao(project_key.clone(), page);
+        env.storage().persistent().set(&dao_key, &dao_page);
+        ttl::extend(&env, &dao_key);
+        ttl::extend_instance(&env);
 
         events::ProposalCreated {
             project_key,
@@ -331,11 +339,12 @@ impl DaoTrait for Tansu {
     fn revoke_proposal(env: Env, maintainer: Address, project_key: Bytes, proposal_id: u32) {
         Tansu::require_not_paused(env.clone());
 
+        let project_key = crate::resolve_key(&env, &project_key);
         let admins_config = Tansu::get_admins_config(env.clone());
         if admins_config.admins.contains(maintainer.clone()) {
             maintainer.require_auth();
         } else {
-            crate::auth_maintainers(&env, &maintainer, &project_key);
+            crate::auth_maintainers_any_status(&env, &maintainer, &project_key);
         }
 
         let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
@@ -353,15 +362,14 @@ impl DaoTrait for Tansu {
 
         // we obfuscate the proposal to avoid any DMCA or else
         proposal.title = String::from_str(&env, "REDACTED");
-        proposal.ipfs = String::from_str(&env, "NONE");
+        proposal.ipfs = Bytes::new(&env);
         proposal.status = types::ProposalStatus::Malicious;
 
         dao_page.proposals.set(sub_id, proposal.clone());
 
-        env.storage().persistent().set(
-            &types::ProjectKey::Dao(project_key.clone(), page),
-            &dao_page,
-        );
+        let dao_key = types::ProjectKey::Dao(project_key.clone(), page);
+        env.storage().persistent().set(&dao_key, &dao_page);
+        ttl::extend(&env, &dao_key);
 
         events::ProposalExecuted {
             project_key: project_key.clone(),
@@ -399,8 +407,11 @@ impl DaoTrait for Tansu {
     fn vote(env: Env, voter: Address, project_key: Bytes, proposal_id: u32, vote: types::Vote) {
         Tansu::require_not_paused(env.clone());
 
+        let project_key = crate::resolve_key(&env, &project_key);
         voter.require_auth();
 
+        crate::require_active(&env, &project_key);
+
         let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
         let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
         let mut dao_page = Self::get_dao(env.clone(), project_key.clone(), page);
@@ -507,10 +518,10 @@ impl DaoTrait for Tansu {
 
         dao_page.proposals.set(sub_id, proposal);
 
-        env.storage().persistent().set(
-            &types::ProjectKey::Dao(project_key.clone(), page),
-            &dao_page,
-        );
+        let dao_key = types::ProjectKey::Dao(project_key.clone(), page);
+        env.storage().persistent().set(&dao_key, &dao_page);
+        ttl::extend(&env, &dao_key);
+        ttl::extend_instance(&env);
 
         events::VoteCast {
             project_key,
@@ -555,7 +566,9 @@ impl DaoTrait for Tansu {
     ) -> types::ProposalStatus {
         Tansu::require_not_paused(env.clone());
 
-        crate::auth_maintainers(&env, &maintainer, &project_key);
+        let project_key = crate::resolve_key(&env, &project_key);
+        // proposals of read-only projects can still be closed
+        crate::auth_maintainers_any_status(&env, &maintainer, &project_key);
 
         let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
         let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
@@ -645,10 +658,9 @@ impl DaoTrait for Tansu {
 
         dao_page.proposals.set(sub_id, proposal.clone());
 
-        env.storage().persistent().set(
-            &types::ProjectKey::Dao(project_key.clone(), page),
-            &dao_page,
-        );
+        let dao_key = types::ProjectKey::Dao(project_key.clone(), page);
+        env.storage().persistent().set(&dao_key, &dao_page);
+        ttl::extend(&env, &dao_key);
 
         events::ProposalExecuted {
             project_key: project_key.clone(),
@@ -719,6 +731,7 @@ impl DaoTrait for Tansu {
         tallies: Vec<u128>,
         seeds: Vec<u128>,
     ) -> bool {
+        let project_key = crate::resolve_key(&env, &project_key);
         // Proof validation only applies to active proposals (before execution)
         if proposal.status != types::ProposalStatus::Active {
             panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
@@ -811,6 +824,7 @@ impl DaoTrait for Tansu {
             panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound);
         }
 
+        let project_key = crate::resolve_key(&env, &project_key);
         let key_ = types::ProjectKey::Key(project_key.clone());
         if env
             .storage()
@@ -842,6 +856,7 @@ impl DaoTrait for Tansu {
     /// # Panics
     /// * If the proposal doesn't exist
     fn get_proposal(env: Env, project_key: Bytes, proposal_id: u32) -> types::Proposal {
+        let project_key = crate::resolve_key(&env, &project_key);
         let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
         let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
         let dao_page = Self::get_dao(env.clone(), project_key, page);
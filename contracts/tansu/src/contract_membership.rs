@@ -1,6 +1,9 @@
 use soroban_sdk::{Address, Bytes, Env, String, Vec, contractimpl, panic_with_error};
 
-use crate::{MembershipTrait, Tansu, TansuArgs, TansuClient, TansuTrait, errors, events, types};
+use crate::index::{self, Index};
+use crate::{
+    MembershipTrait, Tansu, TansuArgs, TansuClient, TansuTrait, errors, events, ttl, types,
+};
 
 #[contractimpl]
 impl MembershipTrait for Tansu {
@@ -32,6 +35,7 @@ impl MembershipTrait for Tansu {
                 meta,
             };
             env.storage().persistent().set(&member_key_, &member);
+            ttl::extend(&env, &member_key_);
 
             events::MemberAdded { member_address }.publish(&env);
         };
@@ -84,6 +88,7 @@ impl MembershipTrait for Tansu {
     ) {
         Tansu::require_not_paused(env.clone());
 
+        let key = crate::resolve_key(&env, &key);
         crate::auth_maintainers(&env, &maintainer, &key);
 
         let member_key_ = types::DataKey::Member(member.clone());
@@ -154,6 +159,15 @@ impl MembershipTrait for Tansu {
 
         env.storage().persistent().set(&badges_key_, &badges_);
         env.storage().persistent().set(&member_key_, &member_);
+        ttl::extend(&env, &badges_key_);
+        ttl::extend(&env, &member_key_);
+
+        let member_index = Index::Member(member.clone());
+        if badges.is_empty() {
+            index::remove(&env, &member_index, &key);
+        } else {
+            index::insert(&env, &member_index, &key);
+        }
 
         events::BadgesUpdated {
             project_key: key,
@@ -176,6 +190,7 @@ impl MembershipTrait for Tansu {
     /// # Returns
     /// * `types::Badges` - Structure containing member addresses for each badge type
     fn get_badges(env: Env, key: Bytes) -> types::Badges {
+        let key = crate::resolve_key(&env, &key);
         let badges_key_ = types::ProjectKey::Badges(key);
         if let Some(badges_) = env
             .storage()
@@ -193,6 +208,19 @@ impl MembershipTrait for Tansu {
         }
     }
 
+    /// Get a page of the projects where an address holds badges.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `member` - The member address
+    /// * `page` - The page number (0-based)
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - Project keys on the requested page, empty if none
+    fn get_projects_by_member(env: Env, member: Address, page: u32) -> Vec<Bytes> {
+        index::page(&env, &Index::Member(member), page)
+    }
+
     /// Get the maximum voting weight for an address in a specific project.
     ///
     /// Calculates the sum of all badge weights for the address in the project.
@@ -207,6 +235,7 @@ impl MembershipTrait for Tansu {
     /// # Returns
     /// * `u32` - The maximum voting weight for the address
     fn get_max_weight(env: Env, project_key: Bytes, member_address: Address) -> u32 {
+        let project_key = crate::resolve_key(&env, &project_key);
         let member_key = types::DataKey::Member(member_address.clone());
 
         if let Some(member) = env
@@ -1,13 +1,24 @@
-use soroban_sdk::{Address, Bytes, Env, String, Vec, contractimpl};
+use soroban_sdk::{
+    Address, Bytes, Env, Map, String, Symbol, TryFromVal, Val, Vec, contractimpl, panic_with_error,
+};
 
-use crate::{MigrationTrait, Tansu, TansuArgs, TansuClient, TansuTrait, types};
+use crate::contract_dao::MAX_PROPOSALS_PER_PAGE;
+use crate::contract_tansu::check_not_paused;
+use crate::errors::ContractErrors;
+use crate::index::{self, Index};
+use crate::{MigrationTrait, Tansu, TansuArgs, TansuClient, cid, events, types};
 
-pub const MAX_PROJECTS_PER_PAGE: u32 = 10;
+const MAX_MIGRATION_BATCH: u32 = 20;
+const MAX_VERIFY_PAGES: u32 = 5;
 
 #[contractimpl]
 impl MigrationTrait for Tansu {
     /// Add projects to the new pagination list. This is used to migrate projects when the project was created before the pagination was implemented.
     ///
+    /// Projects which are already listed are skipped, so the call can be
+    /// repeated safely. Keys listed before the migration to version 1 are
+    /// only known once it ran, it removes the duplicates added meanwhile.
+    ///
     /// # Arguments
     /// * `env` - The environment object
     /// * `admin` - The admin address
@@ -16,51 +27,504 @@ impl MigrationTrait for Tansu {
     /// # Returns
     /// * `()`
     fn add_projects_to_pagination(env: Env, admin: Address, names: Vec<String>) {
-        Tansu::require_not_paused(env.clone());
+        check_not_paused(&env);
         crate::contract_tansu::auth_admin(&env, &admin);
 
-        let mut total_projects = env
-            .storage()
-            .persistent()
-            .get(&types::ProjectKey::TotalProjects)
-            .unwrap_or(0u32);
-
-        let mut current_page = total_projects / MAX_PROJECTS_PER_PAGE;
-        let mut current_project_keys: Vec<Bytes> = env
-            .storage()
-            .persistent()
-            .get(&types::ProjectKey::ProjectKeys(current_page))
-            .unwrap_or(Vec::new(&env));
-
         for name in names {
             let key: Bytes = env.crypto().keccak256(&name.to_bytes()).into();
             let key_ = types::ProjectKey::Key(key.clone());
 
             // Only migrate if the project exists
             if env.storage().persistent().has(&key_) {
-                current_project_keys.push_back(key.clone());
-                total_projects += 1;
-
-                if current_project_keys.len() >= MAX_PROJECTS_PER_PAGE {
-                    env.storage().persistent().set(
-                        &types::ProjectKey::ProjectKeys(current_page),
-                        &current_project_keys,
-                    );
-                    current_page += 1;
-                    current_project_keys = Vec::new(&env);
+                index::insert(&env, &Index::Projects, &key);
+            }
+        }
+    }
+
+    /// Run a batch of the migration to the next storage layout version.
+    ///
+    /// Each call processes at most `limit` entries (max 20) per version and
+    /// records its progress, call it again until `done` is set. The migration scheduled
+    /// by an upgrade, if any, runs first. Admins can migrate while the
+    /// contract is paused. Migrations:
+    /// * to version 1: remove duplicated and unknown keys from the projects
+    ///   listing, see `verify_project_index`
+    /// * to version 2: add the upgrade timelock to the admins configuration
+    /// * to version 3: convert the projects and their DAO pages to the
+    ///   current layout. Unlisted projects must first be added with
+    ///   `add_projects_to_pagination`
+    /// * to version 4: list the projects of their maintainers and badge
+    ///   holders, see `get_projects_by_maintainer` and `get_projects_by_member`
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `limit` - The maximum number of entries to process
+    ///
+    /// # Returns
+    /// * `types::MigrationStatus` - The progress of the migration
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    fn migrate(env: Env, admin: Address, limit: u32) -> types::MigrationStatus {
+        crate::contract_tansu::auth_admin(&env, &admin);
+
+        run_pending_migration(&env);
+        migrate_batch(&env, &admin, limit)
+    }
+
+    /// Get the storage layout version and the progress of its migration.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    ///
+    /// # Returns
+    /// * `types::MigrationStatus` - The progress of the migration
+    fn get_migration_status(env: Env) -> types::MigrationStatus {
+        let storage = env.storage().instance();
+        let schema_version: u32 = storage.get(&types::DataKey::SchemaVersion).unwrap_or(0);
+        types::MigrationStatus {
+            schema_version,
+            cursor: storage.get(&types::DataKey::MigrationCursor).unwrap_or(0),
+            done: schema_version >= types::SCHEMA_VERSION,
+        }
+    }
+
+    /// Check a range of pages of the projects listing against `TotalProjects`.
+    ///
+    /// Pages before the last one must be full and no page may exist past
+    /// the last one. Every key must be listed once and belong to a project:
+    /// a key is a duplicate if it is listed twice in the range, or if the
+    /// listing stores another position for it. Call it again from
+    /// `next_page` to check the rest of the listing.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `start_page` - The first page to check
+    /// * `pages` - The maximum number of pages to check (max 5)
+    ///
+    /// # Returns
+    /// * `types::IndexReport` - The inconsistencies of the pages
+    fn verify_project_index(env: Env, start_page: u32, pages: u32) -> types::IndexReport {
+        let total = index::total(&env, &Index::Projects);
+        let total_pages = total.div_ceil(index::MAX_KEYS_PER_PAGE);
+        let end_page = start_page.saturating_add(pages.min(MAX_VERIFY_PAGES));
+
+        let mut listed = 0;
+        let mut seen = Map::<Bytes, ()>::new(&env);
+        let mut duplicates = Vec::new(&env);
+        let mut unknown = Vec::new(&env);
+        let mut partial_pages = Vec::new(&env);
+
+        let mut page = start_page;
+        while page < end_page {
+            let page_key = types::ProjectKey::ProjectKeys(page);
+            if page >= total_pages && !env.storage().persistent().has(&page_key) {
+                break;
+            }
+            let project_keys = index::page(&env, &Index::Projects, page);
+
+            let expected = if page + 1 < total_pages {
+                index::MAX_KEYS_PER_PAGE
+            } else if page + 1 == total_pages {
+                total - page * index::MAX_KEYS_PER_PAGE
+            } else {
+                0
+            };
+            if project_keys.len() != expected {
+                partial_pages.push_back(page);
+            }
+
+            for (offset, key) in project_keys.iter().enumerate() {
+                listed += 1;
+                let position = page * index::MAX_KEYS_PER_PAGE + offset as u32;
+                let listed_at = index::position(&env, &Index::Projects, &key);
+                if seen.contains_key(key.clone())
+                    || listed_at.is_some_and(|listed_at| listed_at != position)
+                {
+                    if !duplicates.contains(&key) {
+                        duplicates.push_back(key);
+                    }
+                    continue;
+                }
+                seen.set(key.clone(), ());
+                if !env
+                    .storage()
+                    .persistent()
+                    .has(&types::ProjectKey::Key(key.clone()))
+                {
+                    unknown.push_back(key);
                 }
             }
+            page += 1;
         }
 
-        if !current_project_keys.is_empty() {
-            env.storage().persistent().set(
-                &types::ProjectKey::ProjectKeys(current_page),
-                &current_project_keys,
-            );
+        let next_page = if page < total_pages
+            || env
+                .storage()
+                .persistent()
+                .has(&types::ProjectKey::ProjectKeys(page))
+        {
+            Some(page)
+        } else {
+            None
+        };
+        types::IndexReport {
+            total,
+            listed,
+            consistent: duplicates.is_empty() && unknown.is_empty() && partial_pages.is_empty(),
+            duplicates,
+            unknown,
+            partial_pages,
+            next_page,
         }
+    }
+}
+
+/// Run the migration scheduled by `finalize_upgrade`, if any.
+///
+/// It was approved with the upgrade, so it runs without admin authorization.
+pub fn run_pending_migration(env: &Env) {
+    let storage = env.storage().instance();
+    let Some(pending) =
+        storage.get::<_, types::PendingMigration>(&types::DataKey::PendingMigration)
+    else {
+        return;
+    };
+    storage.remove(&types::DataKey::PendingMigration);
+
+    let limit = migration_limit(env, &pending.call);
+    migrate_batch(env, &pending.admin, limit);
+}
+
+/// Get the `limit` argument of a migration call, only `migrate` is supported.
+///
+/// # Panics
+/// * If the call is not `migrate` with a single `u32` argument
+pub fn migration_limit(env: &Env, call: &types::MigrationCall) -> u32 {
+    if call.function != Symbol::new(env, "migrate") || call.args.len() != 1 {
+        panic_with_error!(env, &ContractErrors::UpgradeError);
+    }
+    u32::try_from_val(env, &call.args.get_unchecked(0))
+        .unwrap_or_else(|_| panic_with_error!(env, &ContractErrors::UpgradeError))
+}
+
+/// Run a batch of the migration to the next storage layout version, see
+/// `migrate`.
+fn migrate_batch(env: &Env, admin: &Address, limit: u32) -> types::MigrationStatus {
+    let limit = limit.min(MAX_MIGRATION_BATCH);
+    let storage = env.storage().instance();
+
+    let mut status = Tansu::get_migration_status(env.clone());
+    while !status.done {
+        let cursor = match status.schema_version {
+            0 => migrate_project_index(env, status.cursor, limit),
+            1 => migrate_admins_config(env),
+            2 => migrate_project_layout(env, status.cursor, limit),
+            3 => migrate_reverse_indexes(env, status.cursor, limit),
+            _ => None,
+        };
+
+        if let Some(cursor) = cursor {
+            storage.set(&types::DataKey::MigrationCursor, &cursor);
+            return Tansu::get_migration_status(env.clone());
+        }
+        storage.remove(&types::DataKey::MigrationCursor);
+        storage.set(&types::DataKey::SchemaVersion, &(status.schema_version + 1));
+        events::SchemaMigrated {
+            admin: admin.clone(),
+            schema_version: status.schema_version + 1,
+        }
+        .publish(env);
+
+        status = Tansu::get_migration_status(env.clone());
+    }
+    status
+}
+
+/// Read the admins configuration, also in its version 1 layout which has
+/// no timelock: the default one applies.
+///
+/// The fields are read one by one as decoding a struct with missing
+/// fields would trap.
+pub fn read_admins_config(env: &Env) -> Option<types::AdminsConfig> {
+    let config: Map<Symbol, Val> = env
+        .storage()
+        .instance()
+        .get(&types::DataKey::AdminsConfig)?;
+
+    Some(types::AdminsConfig {
+        threshold: field(env, &config, "threshold")?,
+        admins: field(env, &config, "admins")?,
+        timelock: field_or(env, &config, "timelock", types::TIMELOCK_DELAY)?,
+    })
+}
+
+/// Read a field of a struct stored as a map.
+fn field<T: TryFromVal<Env, Val>>(env: &Env, value: &Map<Symbol, Val>, name: &str) -> Option<T> {
+    T::try_from_val(env, &value.get(Symbol::new(env, name))?).ok()
+}
 
+/// Read a field of a struct stored as a map, `default` if it is missing.
+fn field_or<T: TryFromVal<Env, Val>>(
+    env: &Env,
+    value: &Map<Symbol, Val>,
+    name: &str,
+    default: T,
+) -> Option<T> {
+    match value.get(Symbol::new(env, name)) {
+        Some(field) => T::try_from_val(env, &field).ok(),
+        None => Some(default),
+    }
+}
+
+/// Store the admins configuration with its timelock.
+///
+/// # Returns
+/// * `Option<u32>` - Always None, done in one step
+fn migrate_admins_config(env: &Env) -> Option<u32> {
+    if let Some(admins_config) = read_admins_config(env) {
         env.storage()
+            .instance()
+            .set(&types::DataKey::AdminsConfig, &admins_config);
+    }
+    None
+}
+
+/// Remove the duplicated and unknown keys of the projects listing.
+///
+/// The position of the first occurrence of a key is stored, later
+/// occurrences are removed. A removed key is replaced by the last key of the
+/// listing, which is checked next.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `cursor` - The position of the first key to check
+/// * `limit` - The maximum number of keys to check
+///
+/// # Returns
+/// * `Option<u32>` - The position to resume from, None once done
+fn migrate_project_index(env: &Env, cursor: u32, limit: u32) -> Option<u32> {
+    let mut position = cursor;
+    for _ in 0..limit {
+        if position >= index::total(env, &Index::Projects) {
+            return None;
+        }
+        let page = position / index::MAX_KEYS_PER_PAGE;
+        let offset = position % index::MAX_KEYS_PER_PAGE;
+        let key = index::page(env, &Index::Projects, page).get_unchecked(offset);
+
+        let listed_at = index::position(env, &Index::Projects, &key);
+        let known = env
+            .storage()
+            .persistent()
+            .has(&types::ProjectKey::Key(key.clone()));
+        if listed_at.unwrap_or(position) == position && known {
+            if listed_at.is_none() {
+                index::set_position(env, &Index::Projects, &key, position);
+            }
+            position += 1;
+        } else {
+            index::remove_at(env, &Index::Projects, page, offset);
+        }
+    }
+    if position >= index::total(env, &Index::Projects) {
+        None
+    } else {
+        Some(position)
+    }
+}
+
+/// Convert the projects and their DAO pages to the version 3 layout.
+///
+/// CIDs used to be stored as strings: valid ones are converted to their
+/// binary form, invalid legacy values are kept as raw bytes. Fields added
+/// since get their defaults: a commit threshold of 1 with the default
+/// approval window, `HashAlgorithm::Any` and `Origin::Original`.
+///
+/// Each project counts as one entry, and so does each of its DAO pages.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `cursor` - The position of the project in the listing
+/// * `limit` - The maximum number of entries to convert
+///
+/// # Returns
+/// * `Option<u32>` - The position to resume from, None once done
+fn migrate_project_layout(env: &Env, cursor: u32, limit: u32) -> Option<u32> {
+    let storage = env.storage().instance();
+    // 0 for the project itself, then its DAO pages
+    let mut entry: u32 = storage.get(&types::DataKey::MigrationEntry).unwrap_or(0);
+    let mut position = cursor;
+
+    for _ in 0..limit {
+        if position >= index::total(env, &Index::Projects) {
+            break;
+        }
+        let key = index::page(env, &Index::Projects, position / index::MAX_KEYS_PER_PAGE)
+            .get_unchecked(position % index::MAX_KEYS_PER_PAGE);
+
+        if entry == 0 {
+            migrate_project(env, &key);
+        } else {
+            migrate_dao_page(env, &key, entry - 1);
+        }
+
+        let total_proposals: u32 = env
+            .storage()
             .persistent()
-            .set(&types::ProjectKey::TotalProjects, &total_projects);
+            .get(&types::ProjectKey::DaoTotalProposals(key))
+            .unwrap_or(0);
+        if entry < total_proposals.div_ceil(MAX_PROPOSALS_PER_PAGE) {
+            entry += 1;
+        } else {
+            entry = 0;
+            position += 1;
+        }
+    }
+
+    if position >= index::total(env, &Index::Projects) {
+        storage.remove(&types::DataKey::MigrationEntry);
+        None
+    } else {
+        storage.set(&types::DataKey::MigrationEntry, &entry);
+        Some(position)
+    }
+}
+
+/// Add the listed projects to the indexes of their maintainers and of the
+/// holders of their badges.
+///
+/// Keys already in an index are skipped, so a batch can be repeated safely.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `cursor` - The position of the first project in the listing
+/// * `limit` - The maximum number of projects to index
+///
+/// # Returns
+/// * `Option<u32>` - The position to resume from, None once done
+fn migrate_reverse_indexes(env: &Env, cursor: u32, limit: u32) -> Option<u32> {
+    let storage = env.storage().persistent();
+    for key in index::range(env, &Index::Projects, cursor, limit).iter() {
+        // deregistered projects stay in the listing only
+        if storage.get(&types::ProjectKey::Status(key.clone()))
+            == Some(types::ProjectStatus::Deregistered)
+        {
+            continue;
+        }
+        if let Some(project) =
+            storage.get::<_, types::Project>(&types::ProjectKey::Key(key.clone()))
+        {
+            index::set_maintainers(env, &key, &Vec::new(env), &project.maintainers);
+        }
+
+        let badges: Option<types::Badges> = storage.get(&types::ProjectKey::Badges(key.clone()));
+        if let Some(badges) = badges {
+            for members in [
+                badges.developer,
+                badges.triage,
+                badges.community,
+                badges.verified,
+            ] {
+                for member in members.iter() {
+                    index::insert(env, &Index::Member(member), &key);
+                }
+            }
+        }
+    }
+
+    let position = cursor.saturating_add(limit);
+    if position >= index::total(env, &Index::Projects) {
+        None
+    } else {
+        Some(position)
+    }
+}
+
+/// Store a project in the current layout.
+fn migrate_project(env: &Env, key: &Bytes) {
+    let project_key = types::ProjectKey::Key(key.clone());
+    let Some(value) = env
+        .storage()
+        .persistent()
+        .get::<_, Map<Symbol, Val>>(&project_key)
+    else {
+        return;
+    };
+    if let Some(project) = read_project(env, &value) {
+        env.storage().persistent().set(&project_key, &project);
+    }
+}
+
+/// Store a DAO page in the current layout.
+fn migrate_dao_page(env: &Env, key: &Bytes, page: u32) {
+    let dao_key = types::ProjectKey::Dao(key.clone(), page);
+    let Some(value) = env
+        .storage()
+        .persistent()
+        .get::<_, Map<Symbol, Val>>(&dao_key)
+    else {
+        return;
+    };
+    let Some(legacy_proposals) = field::<Vec<Map<Symbol, Val>>>(env, &value, "proposals") else {
+        return;
+    };
+
+    let mut proposals = Vec::new(env);
+    for proposal in legacy_proposals.iter() {
+        match read_proposal(env, &proposal) {
+            Some(proposal) => proposals.push_back(proposal),
+            None => return,
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&dao_key, &types::Dao { proposals });
+}
+
+/// Read a project stored in the current or a previous layout.
+fn read_project(env: &Env, value: &Map<Symbol, Val>) -> Option<types::Project> {
+    let config: Map<Symbol, Val> = field(env, value, "config")?;
+    Some(types::Project {
+        name: field(env, value, "name")?,
+        config: types::Config {
+            url: field(env, &config, "url")?,
+            ipfs: read_cid(env, &config)?,
+            commit_threshold: field_or(env, &config, "commit_threshold", 1)?,
+            commit_approval_window: field_or(
+                env,
+                &config,
+                "commit_approval_window",
+                types::DEFAULT_COMMIT_APPROVAL_WINDOW,
+            )?,
+            hash_algorithm: field_or(env, &config, "hash_algorithm", types::HashAlgorithm::Any)?,
+        },
+        maintainers: field(env, value, "maintainers")?,
+        sub_projects: field(env, value, "sub_projects")?,
+        origin: field_or(env, value, "origin", types::Origin::Original)?,
+    })
+}
+
+/// Read a DAO proposal stored in the current or a previous layout.
+fn read_proposal(env: &Env, value: &Map<Symbol, Val>) -> Option<types::Proposal> {
+    Some(types::Proposal {
+        id: field(env, value, "id")?,
+        title: field(env, value, "title")?,
+        proposer: field(env, value, "proposer")?,
+        ipfs: read_cid(env, value)?,
+        vote_data: field(env, value, "vote_data")?,
+        status: field(env, value, "status")?,
+        outcome_contracts: field(env, value, "outcome_contracts")?,
+    })
+}
+
+/// Read the `ipfs` field, a binary CID or a legacy CID string.
+fn read_cid(env: &Env, value: &Map<Symbol, Val>) -> Option<Bytes> {
+    let ipfs = value.get(Symbol::new(env, "ipfs"))?;
+    if let Ok(ipfs) = Bytes::try_from_val(env, &ipfs) {
+        return Some(ipfs);
     }
+    let ipfs = String::try_from_val(env, &ipfs).ok()?;
+    Some(cid::decode(env, &ipfs).unwrap_or_else(|_| ipfs.to_bytes()))
 }
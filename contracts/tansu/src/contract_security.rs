@@ -0,0 +1,604 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec, contractimpl, panic_with_error};
+
+use crate::index::{self, Index};
+use crate::semver::{RangeBuf, Version, VersionBuf};
+use crate::{
+    MembershipTrait, SecurityTrait, Tansu, TansuArgs, TansuClient, TansuTrait, VersioningTrait,
+    cid, errors, events, hash, semver, types,
+};
+
+/// Maximum number of attestations for a commit, all digests included.
+const MAX_ATTESTATIONS: u32 = 20;
+/// Maximum number of dependencies of a project.
+const MAX_DEPENDENCIES: u32 = 20;
+
+#[contractimpl]
+impl SecurityTrait for Tansu {
+    /// Yank an anchored commit hash.
+    ///
+    /// The hash stays in the commit history and its releases remain
+    /// queryable, but consumers get an on-chain signal that it should not
+    /// be used anymore.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `hash` - The anchored commit hash to yank
+    /// * `reason` - Why the hash is yanked
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the hash does not match the project's hash algorithm
+    /// * If the hash was never anchored
+    /// * If the hash is already yanked
+    fn yank(env: Env, maintainer: Address, project_key: Bytes, hash: String, reason: String) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let hash_ = hash::parse(&env, &hash, &project.config.hash_algorithm);
+        crate::contract_versioning::require_anchored(&env, &project_key, &hash_);
+
+        let yank_key = types::ProjectKey::Yank(project_key.clone(), hash_.clone());
+        if env.storage().persistent().has(&yank_key) {
+            panic_with_error!(&env, &errors::ContractErrors::HashAlreadyYanked);
+        }
+
+        let yank = types::Yank {
+            reason: reason.clone(),
+            maintainer: maintainer.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&yank_key, &yank);
+
+        events::HashYanked {
+            project_key,
+            hash: hash::to_hex(&env, &hash_),
+            reason,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get the yank information of a commit hash.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `hash` - The commit hash
+    ///
+    /// # Returns
+    /// * `Option<types::Yank>` - The yank information, None if not yanked
+    fn get_yank(env: Env, project_key: Bytes, hash: String) -> Option<types::Yank> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let hash = hash::decode(&env, &hash, &types::HashAlgorithm::Any).ok()?;
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Yank(project_key, hash))
+    }
+
+    /// Publish a security advisory.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `severity` - Severity of the vulnerability
+    /// * `affected` - Semver range of affected versions, e.g. `>=1.0.0 <1.2.3`
+    /// * [`Option<fixed_version>`] - First version with a fix
+    /// * `ipfs` - CID of the advisory details
+    ///
+    /// # Returns
+    /// * `u32` - The ID of the advisory
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the range or the fixed version are not valid semver
+    /// * If the CID is malformed
+    fn publish_advisory(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        severity: types::Severity,
+        affected: String,
+        fixed_version: Option<String>,
+        ipfs: String,
+    ) -> u32 {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        match RangeBuf::from_string(&affected) {
+            Some(range_buf) if semver::valid_range(range_buf.as_slice()) => (),
+            _ => panic_with_error!(&env, &errors::ContractErrors::InvalidVersion),
+        }
+        if let Some(fixed_version) = &fixed_version {
+            crate::contract_versioning::parse_version(&env, fixed_version);
+        }
+
+        let total_advisories_key = types::ProjectKey::TotalAdvisories(project_key.clone());
+        let advisory_id: u32 = env
+            .storage()
+            .persistent()
+            .get(&total_advisories_key)
+            .unwrap_or(0);
+
+        let advisory = types::Advisory {
+            id: advisory_id,
+            severity,
+            affected: affected.clone(),
+            fixed_version,
+            ipfs: cid::parse(&env, &ipfs),
+            maintainer: maintainer.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(
+            &types::ProjectKey::Advisory(project_key.clone(), advisory_id),
+            &advisory,
+        );
+        env.storage()
+            .persistent()
+            .set(&total_advisories_key, &(advisory_id + 1));
+
+        events::AdvisoryPublished {
+            project_key,
+            advisory_id,
+            affected,
+            maintainer,
+        }
+        .publish(&env);
+
+        advisory_id
+    }
+
+    /// Get a security advisory.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `advisory_id` - The ID of the advisory
+    ///
+    /// # Returns
+    /// * `types::Advisory` - The advisory
+    ///
+    /// # Panics
+    /// * If the advisory doesn't exist
+    fn get_advisory(env: Env, project_key: Bytes, advisory_id: u32) -> types::Advisory {
+        let project_key = crate::resolve_key(&env, &project_key);
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Advisory(project_key, advisory_id))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoAdvisoryFound);
+            })
+    }
+
+    /// List the advisories affecting a version or a commit hash.
+    ///
+    /// If the input is not a valid semver version, it is treated as a commit
+    /// hash and every release tagged on that hash is checked.
+    ///
+    /// Note: the cost of this call grows with the number of advisories
+    /// published for the project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `version_or_hash` - A semver version or an anchored commit hash
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - IDs of the advisories affecting the version, empty if
+    ///   none or if the hash has no release
+    fn is_version_affected(env: Env, project_key: Bytes, version_or_hash: String) -> Vec<u32> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let versions = match VersionBuf::from_string(&version_or_hash) {
+            Some(version_buf) if Version::parse(version_buf.as_slice()).is_some() => {
+                Vec::from_array(&env, [version_or_hash])
+            }
+            _ => match hash::decode(&env, &version_or_hash, &types::HashAlgorithm::Any) {
+                Ok(hash) => env
+                    .storage()
+                    .persistent()
+                    .get(&types::ProjectKey::CommitReleases(
+                        project_key.clone(),
+                        hash,
+                    ))
+                    .unwrap_or(Vec::new(&env)),
+                Err(_) => Vec::new(&env),
+            },
+        };
+
+        let total_advisories: u32 = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::TotalAdvisories(project_key.clone()))
+            .unwrap_or(0);
+
+        let mut advisory_ids = Vec::new(&env);
+        for advisory_id in 0..total_advisories {
+            let advisory = Self::get_advisory(env.clone(), project_key.clone(), advisory_id);
+            // validated when the advisory was published
+            let range_buf = RangeBuf::from_string(&advisory.affected).unwrap();
+
+            let is_affected = versions.iter().any(|version| {
+                let version_buf = VersionBuf::from_string(&version).unwrap();
+                let version_ = Version::parse(version_buf.as_slice()).unwrap();
+                semver::satisfies(range_buf.as_slice(), &version_).unwrap_or(false)
+            });
+            if is_affected {
+                advisory_ids.push_back(advisory_id);
+            }
+        }
+        advisory_ids
+    }
+
+    /// Attest that building a commit produces an artifact digest.
+    ///
+    /// Only members holding the `Verified` or `Developer` badge of the
+    /// project can attest. Each of them attests a commit once.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `attester` - The address of the builder
+    /// * `project_key` - The project key identifier
+    /// * `commit_hash` - The anchored commit hash which was built
+    /// * `digest` - The digest of the artifact, e.g. a WASM hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the project is archived or deregistered
+    /// * If the attester does not hold a `Verified` or `Developer` badge
+    /// * If the hash does not match the project's hash algorithm
+    /// * If the commit hash was never anchored
+    /// * If the attester already attested the commit
+    /// * If the commit has too many attestations
+    fn attest_build(
+        env: Env,
+        attester: Address,
+        project_key: Bytes,
+        commit_hash: String,
+        digest: BytesN<32>,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        attester.require_auth();
+
+        let project = <Tansu as VersioningTrait>::get_project(env.clone(), project_key.clone());
+        crate::require_active(&env, &project_key);
+        let badges = <Tansu as MembershipTrait>::get_badges(env.clone(), project_key.clone());
+        if !badges.verified.contains(&attester) && !badges.developer.contains(&attester) {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+        }
+
+        let commit_hash_ = hash::parse(&env, &commit_hash, &project.config.hash_algorithm);
+        crate::contract_versioning::require_anchored(&env, &project_key, &commit_hash_);
+
+        let attestations_key =
+            types::ProjectKey::Attestations(project_key.clone(), commit_hash_.clone());
+        let mut digests: Vec<types::ArtifactDigest> = env
+            .storage()
+            .persistent()
+            .get(&attestations_key)
+            .unwrap_or(Vec::new(&env));
+
+        let mut total_attestations = 0;
+        for artifact in digests.iter() {
+            if artifact.attesters.contains(&attester) {
+                panic_with_error!(&env, &errors::ContractErrors::AlreadyAttested);
+            }
+            total_attestations += artifact.attesters.len();
+        }
+        if total_attestations >= MAX_ATTESTATIONS {
+            panic_with_error!(&env, &errors::ContractErrors::TooManyAttestations);
+        }
+
+        match digests
+            .iter()
+            .position(|artifact| artifact.digest == digest)
+        {
+            Some(index) => {
+                let mut artifact = digests.get_unchecked(index as u32);
+                artifact.attesters.push_back(attester.clone());
+                digests.set(index as u32, artifact);
+            }
+            None => digests.push_back(types::ArtifactDigest {
+                digest: digest.clone(),
+                attesters: Vec::from_array(&env, [attester.clone()]),
+            }),
+        }
+        env.storage().persistent().set(&attestations_key, &digests);
+
+        let threshold = attestation_threshold(&env, &project_key);
+        let verified = verified_digest(&digests, threshold) == Some(digest.clone());
+
+        events::ArtifactAttested {
+            project_key,
+            commit_hash: hash::to_hex(&env, &commit_hash_),
+            digest,
+            attester,
+            verified,
+        }
+        .publish(&env);
+    }
+
+    /// Set how many matching attestations verify a build.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `threshold` - Number of matching attestations, at least 1
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the threshold is 0
+    fn set_attestation_threshold(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        threshold: u32,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        if threshold == 0 {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidThreshold);
+        }
+
+        env.storage().persistent().set(
+            &types::ProjectKey::AttestationThreshold(project_key.clone()),
+            &threshold,
+        );
+
+        events::ProjectConfigUpdated {
+            project_key,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get the attestations of a commit and whether its build is verified.
+    ///
+    /// A build is verified when exactly one digest has at least `threshold`
+    /// attesters. Several digests are a sign of a non reproducible build or
+    /// of a compromised builder.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `commit_hash` - The commit hash
+    ///
+    /// # Returns
+    /// * `types::BuildVerification` - The verified digest if any, and the
+    ///   attesters of every digest
+    ///
+    /// # Panics
+    /// * If the hash is not a valid hex hash
+    fn get_build_verification(
+        env: Env,
+        project_key: Bytes,
+        commit_hash: String,
+    ) -> types::BuildVerification {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let commit_hash = hash::parse(&env, &commit_hash, &types::HashAlgorithm::Any);
+        let digests: Vec<types::ArtifactDigest> = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::Attestations(
+                project_key.clone(),
+                commit_hash,
+            ))
+            .unwrap_or(Vec::new(&env));
+
+        let threshold = attestation_threshold(&env, &project_key);
+        types::BuildVerification {
+            threshold,
+            verified_digest: verified_digest(&digests, threshold),
+            digests,
+        }
+    }
+
+    /// Declare that a project depends on another project.
+    ///
+    /// The dependency is listed in the dependents of the other project so
+    /// that advisories and yanks can be traced to the projects they affect.
+    /// Declaring a dependency again updates its requirement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The key of the dependent project
+    /// * `dependency_key` - The key of the project depended on
+    /// * `requirement` - Semver range of compatible releases, e.g. `>=1.2.0 <2.0.0`
+    ///
+    /// # Panics
+    /// * If either project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project depends on itself
+    /// * If the requirement is not a valid semver range
+    /// * If the project already has 20 dependencies
+    fn add_dependency(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        dependency_key: Bytes,
+        requirement: String,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let dependency_key = crate::resolve_key(&env, &dependency_key);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+        let dependency_key = Self::resolve_project_key(env.clone(), dependency_key);
+        if dependency_key == project_key {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+        }
+        match RangeBuf::from_string(&requirement) {
+            Some(range_buf) if semver::valid_range(range_buf.as_slice()) => (),
+            _ => panic_with_error!(&env, &errors::ContractErrors::InvalidVersion),
+        }
+
+        let dependency = types::Dependency {
+            project_key: dependency_key.clone(),
+            requirement: requirement.clone(),
+        };
+        let mut dependencies = Self::get_dependencies(env.clone(), project_key.clone());
+        match dependency_index(&dependencies, &dependency_key) {
+            Some(position) => dependencies.set(position, dependency),
+            None => {
+                if dependencies.len() >= MAX_DEPENDENCIES {
+                    panic_with_error!(&env, &errors::ContractErrors::TooManyDependencies);
+                }
+                dependencies.push_back(dependency);
+                index::push(
+                    &env,
+                    &Index::Dependents(dependency_key.clone()),
+                    &project_key,
+                );
+            }
+        }
+        env.storage().persistent().set(
+            &types::ProjectKey::Dependencies(project_key.clone()),
+            &dependencies,
+        );
+
+        events::DependencyDeclared {
+            project_key,
+            dependency_key,
+            requirement,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Remove a dependency of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The key of the dependent project
+    /// * `dependency_key` - The key of the project depended on
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the dependency was not declared
+    fn remove_dependency(env: Env, maintainer: Address, project_key: Bytes, dependency_key: Bytes) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+        let dependency_key = crate::resolve_key(&env, &dependency_key);
+
+        let mut dependencies = Self::get_dependencies(env.clone(), project_key.clone());
+        let Some(position) = dependency_index(&dependencies, &dependency_key) else {
+            panic_with_error!(&env, &errors::ContractErrors::NoDependencyFound)
+        };
+        dependencies.remove(position);
+        env.storage().persistent().set(
+            &types::ProjectKey::Dependencies(project_key.clone()),
+            &dependencies,
+        );
+        index::remove(
+            &env,
+            &Index::Dependents(dependency_key.clone()),
+            &project_key,
+        );
+
+        events::DependencyRemoved {
+            project_key,
+            dependency_key,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get the dependencies of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<types::Dependency>` - The projects depended on and their requirement
+    fn get_dependencies(env: Env, project_key: Bytes) -> Vec<types::Dependency> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Dependencies(project_key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a page of the projects depending on a project.
+    ///
+    /// Combined with `get_dependencies`, this tells which dependents have a
+    /// requirement matching the versions of an advisory or a yanked release.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `page` - The page number (0-based)
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - Keys of the dependent projects, empty if none
+    fn get_dependents(env: Env, project_key: Bytes, page: u32) -> Vec<Bytes> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        index::page(&env, &Index::Dependents(project_key), page)
+    }
+}
+
+/// Get the position of a project in a list of dependencies.
+fn dependency_index(dependencies: &Vec<types::Dependency>, project_key: &Bytes) -> Option<u32> {
+    dependencies
+        .iter()
+        .position(|dependency| dependency.project_key == *project_key)
+        .map(|position| position as u32)
+}
+
+/// Get the number of matching attestations verifying a build.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Returns
+/// * `u32` - The threshold, `types::DEFAULT_ATTESTATION_THRESHOLD` if not set
+fn attestation_threshold(env: &Env, project_key: &Bytes) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&types::ProjectKey::AttestationThreshold(
+            project_key.clone(),
+        ))
+        .unwrap_or(types::DEFAULT_ATTESTATION_THRESHOLD)
+}
+
+/// Get the digest verified by the attestations, if any.
+///
+/// # Arguments
+/// * `digests` - The attested digests
+/// * `threshold` - Number of matching attestations
+///
+/// # Returns
+/// * `Option<BytesN<32>>` - The only digest reaching the threshold, None if
+///   no digest or several digests reach it
+fn verified_digest(digests: &Vec<types::ArtifactDigest>, threshold: u32) -> Option<BytesN<32>> {
+    let mut verified = None;
+    for artifact in digests.iter() {
+        if artifact.attesters.len() >= threshold {
+            if verified.is_some() {
+                return None;
+            }
+            verified = Some(artifact.digest);
+        }
+    }
+    verified
+}
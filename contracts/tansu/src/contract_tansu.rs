@@ -1,4 +1,7 @@
-use crate::{Tansu, TansuArgs, TansuClient, TansuTrait, events, types, validate_contract};
+use crate::{
+    MigrationTrait, Tansu, TansuArgs, TansuClient, TansuTrait, contract_migration, events, name,
+    types, validate_contract,
+};
 use soroban_sdk::{Address, BytesN, Env, String, contractimpl, panic_with_error, vec};
 
 #[contractimpl]
@@ -17,11 +20,17 @@ impl TansuTrait for Tansu {
         let admins_config = types::AdminsConfig {
             threshold: 1,
             admins: vec![&env, admin.clone()],
+            timelock: types::TIMELOCK_DELAY,
         };
         env.storage()
             .instance()
             .set(&types::DataKey::AdminsConfig, &admins_config);
 
+        // nothing to migrate on a new deployment
+        env.storage()
+            .instance()
+            .set(&types::DataKey::SchemaVersion, &types::SCHEMA_VERSION);
+
         Self::pause(env, admin, true);
     }
 
@@ -53,19 +62,21 @@ impl TansuTrait for Tansu {
         events::ContractPaused { paused, admin }.publish(&env);
     }
 
-    /// Require that the contract is not paused, panic if it is
+    /// Require that the contract is not paused and that its storage layout
+    /// is up to date, panic if not.
+    ///
+    /// The first call after an upgrade runs the migration of the upgrade
+    /// proposal, if any.
     ///
     /// # Panics
     /// * If the contract is paused.
+    /// * If the storage layout still has to be migrated, see `migrate`.
     fn require_not_paused(env: Env) {
-        let paused = env
-            .storage()
-            .instance()
-            .get(&types::DataKey::Paused)
-            .unwrap_or(false);
+        check_not_paused(&env);
 
-        if paused {
-            panic_with_error!(&env, &crate::errors::ContractErrors::ContractPaused);
+        contract_migration::run_pending_migration(&env);
+        if !Self::get_migration_status(env.clone()).done {
+            panic_with_error!(&env, &crate::errors::ContractErrors::MigrationPending);
         }
     }
 
@@ -77,12 +88,9 @@ impl TansuTrait for Tansu {
     /// # Returns
     /// * `types::AdminsConfig` - The administrators configuration
     fn get_admins_config(env: Env) -> types::AdminsConfig {
-        env.storage()
-            .instance()
-            .get(&types::DataKey::AdminsConfig)
-            .unwrap_or_else(|| {
-                panic_with_error!(&env, &crate::errors::ContractErrors::UnexpectedError);
-            })
+        contract_migration::read_admins_config(&env).unwrap_or_else(|| {
+            panic_with_error!(&env, &crate::errors::ContractErrors::UnexpectedError);
+        })
     }
 
     /// Set the Soroban Domain contract.
@@ -134,6 +142,57 @@ impl TansuTrait for Tansu {
         .publish(&env);
     }
 
+    /// Reserve a project name or release it.
+    ///
+    /// A reserved name cannot be registered nor used to rename a project.
+    /// Existing projects are not affected.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `name` - The project name, normalized before use
+    /// * `reserved` - Reserve or release the name
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    /// * If the name is invalid
+    fn set_reserved_name(env: Env, admin: Address, name: String, reserved: bool) {
+        auth_admin(&env, &admin);
+
+        let name = name::parse(&env, &name);
+        let key_ = types::ProjectKey::Reserved(name::key(&env, &name));
+        if reserved {
+            env.storage().persistent().set(&key_, &true);
+        } else {
+            env.storage().persistent().remove(&key_);
+        }
+
+        events::NameReserved {
+            name,
+            reserved,
+            admin,
+        }
+        .publish(&env);
+    }
+
+    /// Check if a project name is reserved.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `name` - The project name, normalized before use
+    ///
+    /// # Returns
+    /// * `bool` - True if the name is reserved
+    ///
+    /// # Panics
+    /// * If the name is invalid
+    fn is_name_reserved(env: Env, name: String) -> bool {
+        let name = name::parse(&env, &name);
+        env.storage()
+            .persistent()
+            .has(&types::ProjectKey::Reserved(name::key(&env, &name)))
+    }
+
     /// Propose a contract upgrade.
     ///
     /// # Arguments
@@ -141,41 +200,78 @@ impl TansuTrait for Tansu {
     /// * `admin` - An admin address
     /// * `new_wasm_hash` - The new WASM hash
     /// * `new_admins_config` - Optional new admin configuration (None to keep current)
+    ///   including the timelock of the next upgrades
+    /// * `migration` - Optional migration to run after the upgrade, only
+    ///   `migrate` with its `limit` argument
+    ///
+    /// The upgrade can be executed once the timelock of the current
+    /// configuration is over, and until the execution window closes. An
+    /// expired proposal is cancelled by the next one.
     ///
     /// # Panics
     /// * If the admin is not authorized
     /// * If there is already an existing proposal (cancel the previous first)
+    /// * If the new threshold or timelock is out of bounds
+    /// * If the migration is not supported
     fn propose_upgrade(
         env: Env,
         admin: Address,
         new_wasm_hash: BytesN<32>,
         new_admins_config: Option<types::AdminsConfig>,
+        migration: Option<types::MigrationCall>,
     ) {
-        auth_admin(&env, &admin);
+        let current_config = auth_admin(&env, &admin);
 
-        if env
+        if let Some(previous) = env
             .storage()
             .instance()
-            .has(&types::DataKey::UpgradeProposal)
+            .get::<_, types::UpgradeProposal>(&types::DataKey::UpgradeProposal)
         {
-            panic_with_error!(&env, &crate::errors::ContractErrors::UpgradeError);
+            if env.ledger().timestamp() <= previous.expires_at {
+                panic_with_error!(&env, &crate::errors::ContractErrors::UpgradeError);
+            }
+
+            env.storage()
+                .instance()
+                .remove(&types::DataKey::UpgradeProposal);
+
+            events::UpgradeStatus {
+                admin: admin.clone(),
+                wasm_hash: previous.wasm_hash.into(),
+                status: String::from_str(&env, "Expired"),
+            }
+            .publish(&env);
         }
 
-        let executable_at = env.ledger().timestamp() + types::TIMELOCK_DELAY;
+        let executable_at = env.ledger().timestamp() + current_config.timelock;
+        let expires_at = executable_at + types::UPGRADE_EXECUTION_WINDOW;
         let approvals = vec![&env, admin.clone()];
-        let admins_config =
-            new_admins_config.unwrap_or_else(|| Self::get_admins_config(env.clone()));
+        let admins_config = new_admins_config.unwrap_or(current_config);
 
-        // Validate that threshold is reasonable
-        if admins_config.threshold == 0 || admins_config.threshold > admins_config.admins.len() {
+        // Validate that threshold and timelock are reasonable
+        if admins_config.threshold == 0
+            || admins_config.threshold > admins_config.admins.len()
+            || admins_config.timelock < types::MIN_UPGRADE_TIMELOCK
+            || admins_config.timelock > types::MAX_UPGRADE_TIMELOCK
+        {
             panic_with_error!(&env, &crate::errors::ContractErrors::UpgradeError);
         }
 
+        let migration = match migration {
+            Some(call) => {
+                contract_migration::migration_limit(&env, &call);
+                types::UpgradeMigration::Call(call)
+            }
+            None => types::UpgradeMigration::None,
+        };
+
         let upgrade_proposal = types::UpgradeProposal {
             wasm_hash: new_wasm_hash.clone(),
             executable_at,
+            expires_at,
             approvals,
             admins_config,
+            migration,
         };
 
         env.storage()
@@ -186,6 +282,7 @@ impl TansuTrait for Tansu {
             admin,
             wasm_hash: new_wasm_hash.into(),
             executable_at,
+            expires_at,
         }
         .publish(&env);
     }
@@ -199,18 +296,12 @@ impl TansuTrait for Tansu {
     /// # Panics
     /// * If the admin is not authorized
     /// * If the admin already approved
-    /// * If there is no upgrade to approve
+    /// * If there is no upgrade to approve or if it expired
     fn approve_upgrade(env: Env, admin: Address) {
         let admins_config = auth_admin(&env, &admin);
 
         // Get upgrade proposal
-        let mut upgrade_proposal: types::UpgradeProposal = env
-            .storage()
-            .instance()
-            .get(&types::DataKey::UpgradeProposal)
-            .unwrap_or_else(|| {
-                panic_with_error!(&env, &crate::errors::ContractErrors::UpgradeError)
-            });
+        let mut upgrade_proposal = Self::get_upgrade_proposal(env.clone());
 
         // Prevent double approvals
         if upgrade_proposal.approvals.contains(admin.clone()) {
@@ -235,6 +326,37 @@ impl TansuTrait for Tansu {
         .publish(&env);
     }
 
+    /// Revoke the approval of an upgrade proposal
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - An admin address
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    /// * If the admin did not approve
+    /// * If there is no upgrade to revoke from or if it expired
+    fn revoke_upgrade_approval(env: Env, admin: Address) {
+        auth_admin(&env, &admin);
+
+        let mut upgrade_proposal = Self::get_upgrade_proposal(env.clone());
+
+        let Some(index) = upgrade_proposal.approvals.first_index_of(&admin) else {
+            panic_with_error!(&env, &crate::errors::ContractErrors::NoApprovalFound);
+        };
+        upgrade_proposal.approvals.remove(index);
+
+        env.storage()
+            .instance()
+            .set(&types::DataKey::UpgradeProposal, &upgrade_proposal);
+
+        events::UpgradeApprovalRevoked {
+            admin,
+            approvals_count: upgrade_proposal.approvals.len(),
+        }
+        .publish(&env);
+    }
+
     /// Execute or cancel upgrade proposal
     ///
     /// # Arguments
@@ -247,21 +369,31 @@ impl TansuTrait for Tansu {
     /// Note that current governance rules apply. New config changes only
     /// in force after an update.
     ///
+    /// The new WASM only runs once this call ends, so the migration of the
+    /// proposal cannot run here. It is scheduled instead and runs on the first
+    /// call after the upgrade, before anything else. Calls changing the ledger
+    /// fail with `MigrationPending` until the storage layout is up to date:
+    /// if the migration does not complete in a single batch, admins finish it
+    /// with `migrate`.
+    ///
     /// # Panics
     /// * If the admin is not authorized
     /// * If it is too early to execute
     /// * If there are not enough approvals
-    /// * If there is no upgrade to execute
+    /// * If there is no upgrade to execute or if it expired
     fn finalize_upgrade(env: Env, admin: Address, accept: bool) {
         let admins_config = auth_admin(&env, &admin);
 
-        let upgrade_proposal: types::UpgradeProposal = env
-            .storage()
-            .instance()
-            .get(&types::DataKey::UpgradeProposal)
-            .unwrap_or_else(|| {
-                panic_with_error!(&env, &crate::errors::ContractErrors::UpgradeError)
-            });
+        let upgrade_proposal: types::UpgradeProposal = if accept {
+            Self::get_upgrade_proposal(env.clone())
+        } else {
+            env.storage()
+                .instance()
+                .get(&types::DataKey::UpgradeProposal)
+                .unwrap_or_else(|| {
+                    panic_with_error!(&env, &crate::errors::ContractErrors::UpgradeError)
+                })
+        };
 
         if accept {
             if (upgrade_proposal.approvals.len()) < admins_config.threshold {
@@ -286,6 +418,16 @@ impl TansuTrait for Tansu {
             env.deployer()
                 .update_current_contract_wasm(upgrade_proposal.wasm_hash.clone());
 
+            if let types::UpgradeMigration::Call(call) = upgrade_proposal.migration {
+                env.storage().instance().set(
+                    &types::DataKey::PendingMigration,
+                    &types::PendingMigration {
+                        admin: admin.clone(),
+                        call,
+                    },
+                );
+            }
+
             events::UpgradeStatus {
                 admin,
                 wasm_hash: upgrade_proposal.wasm_hash.into(),
@@ -308,10 +450,14 @@ impl TansuTrait for Tansu {
     }
 
     /// Get upgrade proposal details
+    ///
+    /// # Panics
+    /// * If there is no upgrade proposal or if it expired
     fn get_upgrade_proposal(env: Env) -> types::UpgradeProposal {
         env.storage()
             .instance()
-            .get(&types::DataKey::UpgradeProposal)
+            .get::<_, types::UpgradeProposal>(&types::DataKey::UpgradeProposal)
+            .filter(|upgrade_proposal| env.ledger().timestamp() <= upgrade_proposal.expires_at)
             .unwrap_or_else(|| {
                 panic_with_error!(&env, &crate::errors::ContractErrors::UpgradeError)
             })
@@ -319,10 +465,26 @@ impl TansuTrait for Tansu {
 
     /// Get the current version of the contract.
     ///
+    /// This is the version of the code, see `get_migration_status` for the
+    /// version of the storage layout.
+    ///
     /// # Returns
     /// * `u32` - The contract version number
     fn version() -> u32 {
-        2
+        types::CONTRACT_VERSION
+    }
+}
+
+/// Panic if the contract is paused, without checking the storage layout.
+pub fn check_not_paused(env: &Env) {
+    let paused = env
+        .storage()
+        .instance()
+        .get(&types::DataKey::Paused)
+        .unwrap_or(false);
+
+    if paused {
+        panic_with_error!(env, &crate::errors::ContractErrors::ContractPaused);
     }
 }
 
@@ -1,14 +1,30 @@
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec, contractimpl, panic_with_error,
-    vec,
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec, contractimpl,
+    panic_with_error, vec,
 };
 
+use crate::index::{self, Index};
+use crate::semver::{Version, VersionBuf};
 use crate::{
-    Tansu, TansuArgs, TansuClient, TansuTrait, VersioningTrait, domain_contract, errors, events,
-    types,
+    Tansu, TansuArgs, TansuClient, TansuTrait, VersioningTrait, cid, domain_contract, errors,
+    events, hash, name, semver, ttl, types,
 };
 
-const MAX_PROJECTS_PER_PAGE: u32 = 10;
+const MAX_COMMITS_PER_PAGE: u32 = 10;
+const MAX_RELEASES_PER_PAGE: u32 = 10;
+const MAX_BRANCHES: u32 = 20;
+const MAX_BRANCH_NAME_LENGTH: u32 = 64;
+const MAX_PENDING_COMMITS: u32 = 10;
+const MAX_PROJECT_TREE_DEPTH: u32 = 5;
+const MAX_LIST_LIMIT: u32 = 20;
+const MAX_LIST_SCAN: u32 = 50;
+const MAX_LICENSE_LENGTH: u32 = 64;
+const MAX_DESCRIPTION_LENGTH: u32 = 256;
+const MAX_TAGS: u32 = 10;
+const MAX_TAG_LENGTH: u32 = 32;
+const MAX_MIRRORS: u32 = 5;
+const MAX_URL_LENGTH: u32 = 128;
+const MAX_TTL_BATCH: u32 = 30;
 
 #[contractimpl]
 impl VersioningTrait for Tansu {
@@ -16,12 +32,12 @@ impl VersioningTrait for Tansu {
     ///
     /// Creates a new project entry with maintainers, URL, and commit hash.
     /// Also registers the project name in the domain contract if not already registered.
-    /// The project key is generated using keccak256 hash of the project name.
+    /// The project key is the keccak256 hash of the name converted to lower case.
     ///
     /// # Arguments
     /// * `env` - The environment object
     /// * `maintainer` - The address of the maintainer calling this function
-    /// * `name` - The project name (max 15 characters)
+    /// * `name` - The project name (max 15 ASCII letters)
     /// * `maintainers` - List of maintainer addresses for the project
     /// * `url` - The project's Git repository URL
     /// * `ipfs` - CID of the tansu.toml file with associated metadata
@@ -30,9 +46,12 @@ impl VersioningTrait for Tansu {
     /// * `Bytes` - The project key (keccak256 hash of the name)
     ///
     /// # Panics
-    /// * If the project name is longer than 15 characters
+    /// * If the project name is empty or longer than 15 characters
+    /// * If the project name contains anything else than ASCII letters
     /// * If the project already exists
+    /// * If the project name is reserved
     /// * If the maintainer is not authorized
+    /// * If the CID is malformed
     /// * If the domain registration fails
     /// * If the maintainer doesn't own an existing domain
     fn register(
@@ -46,121 +65,126 @@ impl VersioningTrait for Tansu {
         Tansu::require_not_paused(env.clone());
 
         let project = types::Project {
-            name: name.clone(),
-            config: types::Config { url, ipfs },
-            maintainers: maintainers.clone(),
+            name,
+            config: types::Config {
+                url,
+                ipfs: cid::parse(&env, &ipfs),
+                commit_threshold: 1,
+                commit_approval_window: types::DEFAULT_COMMIT_APPROVAL_WINDOW,
+                hash_algorithm: types::HashAlgorithm::Any,
+            },
+            maintainers,
             sub_projects: None,
+            origin: types::Origin::Original,
         };
-        let str_len = name.len() as usize;
-        if str_len > 15 {
-            // could add more checks but handled in any case with later calls
-            panic_with_error!(&env, &errors::ContractErrors::InvalidDomainError);
-        }
-
-        let name_b = name.to_bytes();
-        let key: Bytes = env.crypto().keccak256(&name_b).into();
-
-        let key_ = types::ProjectKey::Key(key.clone());
-        if env
-            .storage()
-            .persistent()
-            .get::<types::ProjectKey, types::Project>(&key_)
-            .is_some()
-        {
-            panic_with_error!(&env, &errors::ContractErrors::ProjectAlreadyExist);
-        } else {
-            maintainer.require_auth();
-            if !project.maintainers.contains(&maintainer) {
-                panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
-            }
-
-            let domain_contract =
-                crate::retrieve_contract(&env, types::ContractKey::DomainContract);
-
-            let node = domain_node(&env, &key);
-            let record_keys = domain_contract::RecordKeys::Record(node);
-
-            let domain_client = domain_contract::Client::new(&env, &domain_contract.address);
-            match domain_client.try_record(&record_keys) {
-                Ok(Ok(None)) => {
-                    domain_register(&env, &name_b, &maintainer, domain_contract.address)
-                }
-                Ok(Ok(Some(domain_contract::Record::Domain(domain)))) => {
-                    if domain.owner != maintainer {
-                        panic_with_error!(&env, &errors::ContractErrors::MaintainerNotDomainOwner)
-                    }
-                }
-                _ => panic_with_error!(&env, &errors::ContractErrors::InvalidDomainError),
-            }
-            env.storage().persistent().set(&key_, &project);
-
-            // Add to project list
-            let total_projects = env
-                .storage()
-                .persistent()
-                .get(&types::ProjectKey::TotalProjects)
-                .unwrap_or(0u32);
-            let page = total_projects / MAX_PROJECTS_PER_PAGE;
+        register_project(&env, &maintainer, project)
+    }
 
-            let mut project_keys: Vec<Bytes> = env
-                .storage()
-                .persistent()
-                .get(&types::ProjectKey::ProjectKeys(page))
-                .unwrap_or(Vec::new(&env));
+    /// Register a new project as a fork of another project.
+    ///
+    /// Same as `register`, recording the upstream project and its current
+    /// commit hash, if any. The fork uses the hash algorithm of the upstream
+    /// project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `name` - The project name (max 15 ASCII letters)
+    /// * `upstream_key` - The key of the forked project
+    /// * `maintainers` - List of maintainer addresses for the project
+    /// * `url` - The project's Git repository URL
+    /// * `ipfs` - CID of the tansu.toml file with associated metadata
+    ///
+    /// # Returns
+    /// * `Bytes` - The project key (keccak256 hash of the name)
+    ///
+    /// # Panics
+    /// * If the upstream project doesn't exist
+    /// * Same as `register`
+    fn register_fork(
+        env: Env,
+        maintainer: Address,
+        name: String,
+        upstream_key: Bytes,
+        maintainers: Vec<Address>,
+        url: String,
+        ipfs: String,
+    ) -> Bytes {
+        Tansu::require_not_paused(env.clone());
 
-            project_keys.push_back(key.clone());
+        let upstream = Self::get_project(env.clone(), upstream_key.clone());
+        let upstream_key = crate::resolve_key(&env, &upstream_key);
 
-            env.storage()
-                .persistent()
-                .set(&types::ProjectKey::ProjectKeys(page), &project_keys);
+        let project = types::Project {
+            name,
+            config: types::Config {
+                url,
+                ipfs: cid::parse(&env, &ipfs),
+                commit_threshold: 1,
+                commit_approval_window: types::DEFAULT_COMMIT_APPROVAL_WINDOW,
+                hash_algorithm: upstream.config.hash_algorithm.clone(),
+            },
+            maintainers,
+            sub_projects: None,
+            origin: types::Origin::Fork(types::Fork {
+                upstream: upstream_key.clone(),
+                commit_hash: raw_last_hash(&env, &upstream_key),
+                timestamp: env.ledger().timestamp(),
+            }),
+        };
+        let key = register_project(&env, &maintainer, project);
+        index::push(&env, &Index::Forks(upstream_key.clone()), &key);
 
-            env.storage()
-                .persistent()
-                .set(&types::ProjectKey::TotalProjects, &(total_projects + 1));
+        events::ProjectForked {
+            project_key: key.clone(),
+            commit_hash: last_hash(&env, &upstream_key),
+            upstream_key,
+        }
+        .publish(&env);
 
-            events::ProjectRegistered {
-                project_key: key.clone(),
-                name,
-                maintainer,
-            }
-            .publish(&env);
+        key
+    }
 
-            key
-        }
+    /// Get a page of the forks of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `upstream_key` - The key of the forked project
+    /// * `page` - The page number (0-based)
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - Keys of the forks, empty if none
+    fn get_forks(env: Env, upstream_key: Bytes, page: u32) -> Vec<Bytes> {
+        let upstream_key = crate::resolve_key(&env, &upstream_key);
+        index::page(&env, &Index::Forks(upstream_key), page)
     }
 
     /// Update the configuration of an existing project.
     ///
-    /// Allows maintainers to change the project's URL, IPFS metadata, and maintainer list.
+    /// Allows maintainers to change the project's URL and IPFS metadata.
+    /// Maintainers are changed with `propose_maintainers_change`.
     ///
     /// # Arguments
     /// * `env` - The environment object
     /// * `maintainer` - The address of the maintainer calling this function
     /// * `key` - The project key identifier
-    /// * `maintainers` - New list of maintainer addresses
     /// * `url` - New Git repository URL
     /// * `ipfs` - New CID of the tansu.toml file with metadata
     ///
     /// # Panics
     /// * If the project doesn't exist
     /// * If the maintainer is not authorized
-    fn update_config(
-        env: Env,
-        maintainer: Address,
-        key: Bytes,
-        maintainers: Vec<Address>,
-        url: String,
-        ipfs: String,
-    ) {
+    /// * If the CID is malformed
+    fn update_config(env: Env, maintainer: Address, key: Bytes, url: String, ipfs: String) {
         Tansu::require_not_paused(env.clone());
 
+        let key = crate::resolve_key(&env, &key);
         let key_ = types::ProjectKey::Key(key.clone());
 
         let mut project = crate::auth_maintainers(&env, &maintainer, &key);
 
-        let config = types::Config { url, ipfs };
-        project.config = config;
-        project.maintainers = maintainers;
+        project.config.url = url;
+        project.config.ipfs = cid::parse(&env, &ipfs);
         env.storage().persistent().set(&key_, &project);
 
         events::ProjectConfigUpdated {
@@ -170,176 +194,2943 @@ impl VersioningTrait for Tansu {
         .publish(&env);
     }
 
-    /// Set the latest commit hash for a project.
+    /// Set the structured metadata of a project.
     ///
-    /// Updates the current commit hash for the specified project.
+    /// This mirrors the main fields of the tansu.toml file so that they can
+    /// be read on-chain. Empty fields are not set.
     ///
     /// # Arguments
     /// * `env` - The environment object
     /// * `maintainer` - The address of the maintainer calling this function
     /// * `project_key` - The project key identifier
-    /// * `hash` - The new commit hash
+    /// * `metadata` - The metadata
     ///
     /// # Panics
     /// * If the project doesn't exist
     /// * If the maintainer is not authorized
-    fn commit(env: Env, maintainer: Address, project_key: Bytes, hash: String) {
+    /// * If the license is longer than 64 characters or not an SPDX expression
+    /// * If the description is longer than 256 characters or has control
+    ///   characters
+    /// * If there are more than 10 tags, or a tag is empty, longer than 32
+    ///   characters or not made of lowercase letters, digits and dashes
+    /// * If there are more than 5 mirrors, or a mirror or the homepage is
+    ///   longer than 128 characters
+    /// * If a mirror or a non-empty homepage is not an `https://` URL
+    ///   without spaces
+    fn set_metadata(env: Env, maintainer: Address, project_key: Bytes, metadata: types::Metadata) {
         Tansu::require_not_paused(env.clone());
 
+        let project_key = crate::resolve_key(&env, &project_key);
         crate::auth_maintainers(&env, &maintainer, &project_key);
+        validate_metadata(&env, &metadata);
+
         env.storage()
             .persistent()
-            .set(&types::ProjectKey::LastHash(project_key.clone()), &hash);
+            .set(&types::ProjectKey::Metadata(project_key.clone()), &metadata);
 
-        events::Commit { project_key, hash }.publish(&env);
+        events::MetadataUpdated {
+            project_key,
+            maintainer,
+        }
+        .publish(&env);
     }
 
-    /// Get the latest commit hash for a project.
+    /// Propose to add and remove maintainers of a project.
+    ///
+    /// The change needs the approval of a quorum of the current maintainers,
+    /// see `get_maintainers_quorum`, and can only be executed after a
+    /// 24 hours delay. The proposer's approval is counted. A project has at
+    /// most one pending change.
     ///
     /// # Arguments
     /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
     /// * `project_key` - The project key identifier
-    ///
-    /// # Returns
-    /// * `String` - The current commit hash
+    /// * `add` - The addresses to add as maintainers
+    /// * `remove` - The maintainers to remove
+    /// * [`Option<quorum>`] - New quorum (None to keep current)
     ///
     /// # Panics
     /// * If the project doesn't exist
-    fn get_commit(env: Env, project_key: Bytes) -> String {
-        let key_ = types::ProjectKey::Key(project_key.clone());
-        if env
-            .storage()
-            .persistent()
-            .get::<types::ProjectKey, types::Project>(&key_)
-            .is_some()
-        {
-            env.storage()
-                .persistent()
-                .get(&types::ProjectKey::LastHash(project_key))
-                .unwrap_or_else(|| {
-                    panic_with_error!(&env, &errors::ContractErrors::NoHashFound);
-                })
-        } else {
-            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+    /// * If the maintainer is not authorized
+    /// * If there is already a pending change (cancel the previous first)
+    /// * If the change is empty, adds a maintainer or removes a non-maintainer
+    /// * If the resulting maintainers are fewer than the quorum or the commit
+    ///   threshold
+    fn propose_maintainers_change(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        add: Vec<Address>,
+        remove: Vec<Address>,
+        quorum: Option<u32>,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let change_key = types::ProjectKey::MaintainersChange(project_key.clone());
+        if env.storage().persistent().has(&change_key) {
+            panic_with_error!(&env, &errors::ContractErrors::MaintainersChangePending);
+        }
+
+        let executable_at = env.ledger().timestamp() + types::MAINTAINERS_CHANGE_DELAY;
+        let change = types::MaintainersChange {
+            add: add.clone(),
+            remove: remove.clone(),
+            quorum,
+            executable_at,
+            approvals: vec![&env, maintainer.clone()],
+        };
+        // only validates the change, it is applied once approved
+        apply_maintainers_change(&env, &project_key, &project, &change);
+
+        env.storage().persistent().set(&change_key, &change);
+
+        events::MaintainersChangeProposed {
+            project_key,
+            add,
+            remove,
+            executable_at,
+            maintainer,
         }
+        .publish(&env);
     }
 
-    /// Get project information including configuration and maintainers.
+    /// Approve the pending change of maintainers.
     ///
     /// # Arguments
     /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
     /// * `project_key` - The project key identifier
     ///
-    /// # Returns
-    /// * `types::Project` - Project information including name, config, and maintainers
-    ///
     /// # Panics
     /// * If the project doesn't exist
-    fn get_project(env: Env, project_key: Bytes) -> types::Project {
-        let key_ = types::ProjectKey::Key(project_key.clone());
+    /// * If the maintainer is not authorized
+    /// * If there is no change to approve
+    /// * If the maintainer already approved
+    fn approve_maintainers_change(env: Env, maintainer: Address, project_key: Bytes) {
+        Tansu::require_not_paused(env.clone());
 
-        env.storage()
-            .persistent()
-            .get::<types::ProjectKey, types::Project>(&key_)
-            .unwrap_or_else(|| {
-                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
-            })
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let mut change = Self::get_maintainers_change(env.clone(), project_key.clone());
+        if change.approvals.contains(&maintainer) {
+            panic_with_error!(&env, &errors::ContractErrors::AlreadyVoted);
+        }
+        change.approvals.push_back(maintainer.clone());
+
+        env.storage().persistent().set(
+            &types::ProjectKey::MaintainersChange(project_key.clone()),
+            &change,
+        );
+
+        let approvals_count = count_approvals(&project, &change);
+        let quorum = maintainers_quorum(&env, &project_key, &project);
+
+        events::MaintainersChangeApproved {
+            project_key,
+            maintainer,
+            approvals_count,
+            quorum_reached: approvals_count >= quorum,
+        }
+        .publish(&env);
     }
 
-    /// Get a page of projects.
+    /// Execute or cancel the pending change of maintainers.
+    ///
+    /// Changes can always be cancelled but only executed if enough current
+    /// maintainers approved it and the delay is over. Emits a
+    /// `MaintainerAdded` or `MaintainerRemoved` event per address.
     ///
     /// # Arguments
     /// * `env` - The environment object
-    /// * `page` - The page number (0-based)
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `accept` - true to execute and false to cancel
     ///
-    /// # Returns
-    /// * `Vec<types::Project>` - List of projects on the requested page
-    fn get_projects(env: Env, page: u32) -> Vec<types::Project> {
-        if let Some(project_keys) = env
-            .storage()
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If there is no change to execute
+    /// * If there are not enough approvals
+    /// * If it is too early to execute
+    /// * If the change is not valid anymore
+    fn finalize_maintainers_change(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        accept: bool,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let mut project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let change = Self::get_maintainers_change(env.clone(), project_key.clone());
+        let change_key = types::ProjectKey::MaintainersChange(project_key.clone());
+
+        if !accept {
+            env.storage().persistent().remove(&change_key);
+
+            events::MaintainersChangeCancelled {
+                project_key,
+                maintainer,
+            }
+            .publish(&env);
+            return;
+        }
+
+        // approvals of removed maintainers do not count anymore
+        if count_approvals(&project, &change) < maintainers_quorum(&env, &project_key, &project) {
+            panic_with_error!(&env, &errors::ContractErrors::QuorumNotReached);
+        }
+        if env.ledger().timestamp() < change.executable_at {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        }
+
+        let previous_maintainers = project.maintainers.clone();
+        project.maintainers = apply_maintainers_change(&env, &project_key, &project, &change);
+        env.storage()
             .persistent()
-            .get::<_, Vec<Bytes>>(&types::ProjectKey::ProjectKeys(page))
-        {
-            let mut projects = Vec::new(&env);
-            for key in project_keys {
-                let key_ = types::ProjectKey::Key(key.clone());
-                let project = env
-                    .storage()
-                    .persistent()
-                    .get::<types::ProjectKey, types::Project>(&key_)
-                    .expect("Invalid project key");
+            .set(&types::ProjectKey::Key(project_key.clone()), &project);
+        index::set_maintainers(
+            &env,
+            &project_key,
+            &previous_maintainers,
+            &project.maintainers,
+        );
+        if let Some(quorum) = change.quorum {
+            env.storage().persistent().set(
+                &types::ProjectKey::MaintainersQuorum(project_key.clone()),
+                &quorum,
+            );
+        }
+        env.storage().persistent().remove(&change_key);
 
-                projects.push_back(project);
+        for added in change.add.iter() {
+            events::MaintainerAdded {
+                project_key: project_key.clone(),
+                maintainer: added,
+                executor: maintainer.clone(),
             }
-            projects
-        } else {
-            panic_with_error!(&env, &errors::ContractErrors::NoProjectPageFound);
+            .publish(&env);
+        }
+        for removed in change.remove.iter() {
+            events::MaintainerRemoved {
+                project_key: project_key.clone(),
+                maintainer: removed,
+                executor: maintainer.clone(),
+            }
+            .publish(&env);
         }
     }
 
-    /// Get sub-projects for a project (if it's an organization).
+    /// Get the pending change of maintainers.
     ///
     /// # Arguments
     /// * `env` - The environment object
     /// * `project_key` - The project key identifier
     ///
     /// # Returns
-    /// * `Vec<Bytes>` - List of sub-project keys, empty if not an organization
-    fn get_sub_projects(env: Env, project_key: Bytes) -> Vec<Bytes> {
-        let key_ = types::ProjectKey::Key(project_key.clone());
-        let project = env
-            .storage()
+    /// * `types::MaintainersChange` - The pending change
+    ///
+    /// # Panics
+    /// * If there is no pending change
+    fn get_maintainers_change(env: Env, project_key: Bytes) -> types::MaintainersChange {
+        let project_key = crate::resolve_key(&env, &project_key);
+        env.storage()
             .persistent()
-            .get::<types::ProjectKey, types::Project>(&key_)
+            .get(&types::ProjectKey::MaintainersChange(project_key))
             .unwrap_or_else(|| {
-                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
-            });
+                panic_with_error!(&env, &errors::ContractErrors::NoMaintainersChangeFound);
+            })
+    }
 
-        project.sub_projects.unwrap_or_else(|| Vec::new(&env))
+    /// Get how many maintainer approvals are needed to change the maintainers.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `u32` - The quorum, a majority of the maintainers if not set
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_maintainers_quorum(env: Env, project_key: Bytes) -> u32 {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = Self::get_project(env.clone(), project_key.clone());
+        maintainers_quorum(&env, &project_key, &project)
     }
 
-    /// Set sub-projects for a project (making it an organization).
+    /// Archive a project, making it read-only.
     ///
-    /// Note: by design, sub-project keys are not validated against existing
-    /// projects. This allows reserving a project space before the project is
-    /// registered (since the key is derived from the name). A project can
-    /// also appear in multiple organizations.
+    /// Commits, releases, proposals, votes and badge changes are rejected
+    /// while the history stays queryable. Open proposals can still be
+    /// executed or revoked. An admin archives a project directly, otherwise
+    /// each call is a maintainer approval and the project is archived once
+    /// the maintainers quorum is reached.
     ///
     /// # Arguments
     /// * `env` - The environment object
-    /// * `maintainer` - The maintainer address calling this function
+    /// * `caller` - An admin or a maintainer of the project
     /// * `project_key` - The project key identifier
-    /// * `sub_projects` - List of sub-project keys to associate
     ///
     /// # Panics
     /// * If the project doesn't exist
-    /// * If the maintainer is not authorized
-    /// * If more than 10 sub-projects are provided
-    fn set_sub_projects(
-        env: Env,
-        maintainer: Address,
-        project_key: Bytes,
-        sub_projects: Vec<Bytes>,
-    ) {
+    /// * If the caller is neither an admin nor a maintainer
+    /// * If the project is already archived or deregistered
+    /// * If the maintainer already approved
+    fn archive_project(env: Env, caller: Address, project_key: Bytes) {
         Tansu::require_not_paused(env.clone());
-        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
 
-        if sub_projects.len() > 10 {
-            panic_with_error!(&env, &errors::ContractErrors::TooManySubProjects);
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::require_active(&env, &project_key);
+        if !approve_action(
+            &env,
+            &caller,
+            &project_key,
+            &types::ProjectAction::Archive,
+            true,
+        ) {
+            return;
         }
 
-        let key_ = types::ProjectKey::Key(project_key.clone());
-        let mut updated_project = project;
-        updated_project.sub_projects = Some(sub_projects.clone());
+        env.storage().persistent().set(
+            &types::ProjectKey::Status(project_key.clone()),
+            &types::ProjectStatus::Archived,
+        );
 
-        env.storage().persistent().set(&key_, &updated_project);
+        events::ProjectArchived {
+            project_key,
+            caller,
+        }
+        .publish(&env);
+    }
 
-        events::SubProjectsUpdated {
+    /// Deregister a project.
+    ///
+    /// The project is removed from the projects of its maintainers and
+    /// members, and becomes read-only like an archived project. It keeps its
+    /// place in the projects listing so that cursors stay valid, but is no
+    /// longer returned. Its data stays queryable and its
+    /// name cannot be registered again. An admin deregisters a project
+    /// directly, otherwise each call is a maintainer approval and the
+    /// project is deregistered once the maintainers quorum is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `caller` - An admin or a maintainer of the project
+    /// * `project_key` - The project key identifier
+    /// * `release_domain` - Burn the Soroban domain of the current project
+    ///   name, the domain owner must authorize it
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the caller is neither an admin nor a maintainer
+    /// * If the project is already deregistered
+    /// * If the maintainer already approved
+    /// * If the domain cannot be released
+    fn deregister_project(env: Env, caller: Address, project_key: Bytes, release_domain: bool) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        if Self::get_project_status(env.clone(), project_key.clone())
+            == types::ProjectStatus::Deregistered
+        {
+            panic_with_error!(&env, &errors::ContractErrors::ProjectReadOnly);
+        }
+        if !approve_action(
+            &env,
+            &caller,
+            &project_key,
+            &types::ProjectAction::Deregister,
+            true,
+        ) {
+            return;
+        }
+
+        let project = Self::get_project(env.clone(), project_key.clone());
+        index::set_maintainers(&env, &project_key, &project.maintainers, &Vec::new(&env));
+        let badges: Option<types::Badges> = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::Badges(project_key.clone()));
+        if let Some(badges) = badges {
+            for members in [
+                badges.developer,
+                badges.triage,
+                badges.community,
+                badges.verified,
+            ] {
+                for member in members.iter() {
+                    index::remove(&env, &Index::Member(member), &project_key);
+                }
+            }
+        }
+
+        if release_domain {
+            let domain_contract =
+                crate::retrieve_contract(&env, types::ContractKey::DomainContract);
+            let domain_client = domain_contract::Client::new(&env, &domain_contract.address);
+            let name_key = name::key(&env, &project.name);
+            let record_keys = domain_contract::RecordKeys::Record(domain_node(&env, &name_key));
+            if !matches!(domain_client.try_burn_record(&record_keys), Ok(Ok(()))) {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidDomainError);
+            }
+        }
+
+        env.storage().persistent().set(
+            &types::ProjectKey::Status(project_key.clone()),
+            &types::ProjectStatus::Deregistered,
+        );
+
+        events::ProjectDeregistered {
             project_key,
-            sub_projects,
+            caller,
+            domain_released: release_domain,
         }
         .publish(&env);
     }
+
+    /// Get the lifecycle status of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::ProjectStatus` - The status of the project
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_project_status(env: Env, project_key: Bytes) -> types::ProjectStatus {
+        Self::get_project(env.clone(), project_key.clone());
+        let project_key = crate::resolve_key(&env, &project_key);
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Status(project_key))
+            .unwrap_or(types::ProjectStatus::Active)
+    }
+
+    /// Rename a project.
+    ///
+    /// The domain of the new name is registered, or must be owned by the
+    /// maintainer approving last. The project keeps the key it was
+    /// registered with and the key of the new name becomes an alias of it,
+    /// every call accepts both keys. See `resolve_project_key`.
+    ///
+    /// Each call is a maintainer approval and the project is renamed once
+    /// the maintainers quorum is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `name` - The new project name (max 15 ASCII letters)
+    ///
+    /// # Returns
+    /// * `Bytes` - The key of the new name (keccak256 hash of the name)
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived or deregistered
+    /// * If the new name is not 1 to 15 ASCII letters
+    /// * If the new name is used by a project or reserved
+    /// * If the maintainer already approved
+    /// * If the domain registration fails
+    /// * If the maintainer doesn't own an existing domain
+    fn rename_project(env: Env, maintainer: Address, project_key: Bytes, name: String) -> Bytes {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::require_active(&env, &project_key);
+        let name = name::parse(&env, &name);
+        let alias = available_key(&env, &name);
+
+        if !approve_action(
+            &env,
+            &maintainer,
+            &project_key,
+            &types::ProjectAction::Rename(name.clone()),
+            false,
+        ) {
+            return alias;
+        }
+
+        claim_domain(&env, &name.to_bytes(), &alias, &maintainer);
+
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        let mut project = Self::get_project(env.clone(), project_key.clone());
+        let previous_name = project.name;
+        project.name = name.clone();
+        env.storage().persistent().set(&key_, &project);
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Alias(alias.clone()), &project_key);
+
+        events::ProjectRenamed {
+            project_key,
+            previous_name,
+            name,
+            alias: alias.clone(),
+            caller: maintainer,
+        }
+        .publish(&env);
+
+        alias
+    }
+
+    /// Get the registered key of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The registered key or the key of a new name
+    ///
+    /// # Returns
+    /// * `Bytes` - The key the project was registered with
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn resolve_project_key(env: Env, project_key: Bytes) -> Bytes {
+        Self::get_project(env.clone(), project_key.clone());
+        crate::resolve_key(&env, &project_key)
+    }
+
+    /// Offer a project to new maintainers.
+    ///
+    /// Each call is a maintainer approval and the offer is made once the
+    /// maintainers quorum is reached. It replaces any previous offer. The new
+    /// maintainers take over with `accept_transfer`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `maintainers` - The new maintainers
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived or deregistered
+    /// * If the new maintainers are empty or contain duplicates
+    /// * If the maintainer already approved
+    fn transfer_project(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        maintainers: Vec<Address>,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::require_active(&env, &project_key);
+        if maintainers.is_empty()
+            || maintainers
+                .iter()
+                .enumerate()
+                .any(|(i, address)| maintainers.first_index_of(&address) != Some(i as u32))
+        {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersChange);
+        }
+
+        if !approve_action(
+            &env,
+            &maintainer,
+            &project_key,
+            &types::ProjectAction::Transfer(maintainers.clone()),
+            false,
+        ) {
+            return;
+        }
+
+        env.storage().persistent().set(
+            &types::ProjectKey::TransferOffer(project_key.clone()),
+            &maintainers,
+        );
+
+        events::ProjectTransferOffered {
+            project_key,
+            maintainers,
+        }
+        .publish(&env);
+    }
+
+    /// Accept the transfer of a project.
+    ///
+    /// Every new maintainer must authorize the call. They replace the
+    /// maintainers, the maintainers quorum is reset to a majority and the
+    /// commit threshold is lowered to the number of new maintainers if
+    /// needed. Pending commits and maintainers changes are dropped.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If there is no transfer offer
+    /// * If a new maintainer did not authorize the call
+    /// * If the project is archived or deregistered
+    fn accept_transfer(env: Env, project_key: Bytes) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let maintainers = Self::get_transfer_offer(env.clone(), project_key.clone());
+        for maintainer in maintainers.iter() {
+            maintainer.require_auth();
+        }
+        crate::require_active(&env, &project_key);
+
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        let mut project = Self::get_project(env.clone(), project_key.clone());
+        let previous_maintainers = project.maintainers;
+        project.maintainers = maintainers.clone();
+        project.config.commit_threshold = project.config.commit_threshold.min(maintainers.len());
+        env.storage().persistent().set(&key_, &project);
+        index::set_maintainers(&env, &project_key, &previous_maintainers, &maintainers);
+
+        for key in [
+            types::ProjectKey::TransferOffer(project_key.clone()),
+            types::ProjectKey::MaintainersQuorum(project_key.clone()),
+            types::ProjectKey::MaintainersChange(project_key.clone()),
+            types::ProjectKey::PendingCommits(project_key.clone()),
+        ] {
+            env.storage().persistent().remove(&key);
+        }
+
+        events::ProjectTransferred {
+            project_key,
+            previous_maintainers,
+            maintainers,
+        }
+        .publish(&env);
+    }
+
+    /// Cancel the transfer offer of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If there is no transfer offer
+    fn cancel_transfer(env: Env, maintainer: Address, project_key: Bytes) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::auth_maintainers_any_status(&env, &maintainer, &project_key);
+        Self::get_transfer_offer(env.clone(), project_key.clone());
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::TransferOffer(project_key.clone()));
+
+        events::ProjectTransferCancelled {
+            project_key,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get the maintainers a project is offered to.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<Address>` - The new maintainers
+    ///
+    /// # Panics
+    /// * If there is no transfer offer
+    fn get_transfer_offer(env: Env, project_key: Bytes) -> Vec<Address> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::TransferOffer(project_key))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoTransferFound);
+            })
+    }
+
+    /// Set the latest commit hash for a project.
+    ///
+    /// The hash is appended to the commit history. A given `parent_hash` must
+    /// match the current commit hash, see `force_commit` for rewrites. With
+    /// a commit threshold above 1 this is an approval, see `approve_commit`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The maintainer or delegate calling this function
+    /// * `project_key` - The project key identifier
+    /// * `hash` - The new commit hash
+    /// * [`Option<parent_hash>`] - The expected current commit hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer or delegate is not authorized
+    /// * If the hash does not match the project's hash algorithm
+    /// * If the parent hash does not match the current commit hash
+    fn commit(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        hash: String,
+        parent_hash: Option<String>,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let branch = String::from_str(&env, types::DEFAULT_BRANCH);
+        let project = crate::auth_role(
+            &env,
+            &maintainer,
+            &project_key,
+            &types::Role::Committer(branch.clone()),
+        );
+        let hash_algorithm = &project.config.hash_algorithm;
+        let hash = hash::parse(&env, &hash, hash_algorithm);
+        let parent_hash =
+            parent_hash.map(|parent_hash| hash::parse(&env, &parent_hash, hash_algorithm));
+
+        submit_commit(
+            &env,
+            &project,
+            &maintainer,
+            &project_key,
+            &branch,
+            &hash,
+            parent_hash,
+        );
+    }
+
+    /// Set the latest commit hash for a project, rewriting its history.
+    ///
+    /// Same as `commit` without any parent hash check. This is meant for
+    /// deliberate history rewrites (e.g. force-push) and emits a dedicated
+    /// `HistoryRewritten` event so that rewrites are visible to everyone.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `hash` - The new commit hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project requires more than one maintainer approval
+    /// * If the hash does not match the project's hash algorithm
+    fn force_commit(env: Env, maintainer: Address, project_key: Bytes, hash: String) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        // a single maintainer must not be able to bypass the approvals
+        if project.config.commit_threshold > 1 {
+            panic_with_error!(&env, &errors::ContractErrors::CommitApprovalRequired);
+        }
+
+        let hash_ = hash::parse(&env, &hash, &project.config.hash_algorithm);
+        let previous_hash = last_hash(&env, &project_key);
+
+        let last_hash_key = types::ProjectKey::LastHash(project_key.clone());
+        env.storage().persistent().set(&last_hash_key, &hash_);
+        ttl::extend(&env, &last_hash_key);
+
+        let branch = String::from_str(&env, types::DEFAULT_BRANCH);
+        append_commit_history(&env, &project_key, &branch, &hash_, &maintainer);
+
+        events::HistoryRewritten {
+            project_key,
+            previous_hash,
+            hash: hash::to_hex(&env, &hash_),
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Approve a commit hash for a branch of a project.
+    ///
+    /// The hash only becomes the head of the branch once enough distinct
+    /// maintainers approved it (see `set_commit_threshold`) within the
+    /// approval window. Approvals which are not completed in time expire.
+    /// With a threshold of 1, this directly anchors the hash.
+    ///
+    /// Delegates can propose a commit this way, but only maintainers'
+    /// approvals count towards the threshold.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer or delegate calling this function
+    /// * `project_key` - The project key identifier
+    /// * `branch` - The branch name
+    /// * `hash` - The commit hash to approve
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer or delegate is not authorized
+    /// * If the hash does not match the project's hash algorithm
+    /// * If the maintainer already approved this commit
+    /// * If there are too many pending commits
+    /// * If the head moved away from the parent hash of the commit
+    fn approve_commit(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        branch: String,
+        hash: String,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = crate::auth_role(
+            &env,
+            &maintainer,
+            &project_key,
+            &types::Role::Committer(branch.clone()),
+        );
+
+        validate_branch_name(&env, &branch);
+        let hash = hash::parse(&env, &hash, &project.config.hash_algorithm);
+
+        submit_commit(
+            &env,
+            &project,
+            &maintainer,
+            &project_key,
+            &branch,
+            &hash,
+            None,
+        );
+    }
+
+    /// Get the commits of a project waiting for maintainer approvals.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<types::PendingCommit>` - Pending commits which did not expire
+    fn get_pending_commits(env: Env, project_key: Bytes) -> Vec<types::PendingCommit> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let pending_commits: Vec<types::PendingCommit> = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::PendingCommits(project_key))
+            .unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
+
+        let mut active_commits = Vec::new(&env);
+        for pending_commit in pending_commits {
+            if pending_commit.expires_at > now {
+                active_commits.push_back(pending_commit);
+            }
+        }
+        active_commits
+    }
+
+    /// Declare the hash algorithm of the project's version control system.
+    ///
+    /// Commit hashes are validated against it.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `hash_algorithm` - The hash algorithm, `Any` accepts 40 or 64
+    ///   characters hex hashes
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    fn set_hash_algorithm(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        hash_algorithm: types::HashAlgorithm,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let mut project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        project.config.hash_algorithm = hash_algorithm;
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Key(project_key.clone()), &project);
+
+        events::ProjectConfigUpdated {
+            project_key,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Set how many maintainer approvals are needed to anchor a commit.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `threshold` - Number of distinct maintainer approvals (K-of-N)
+    /// * `approval_window` - Time in seconds to gather the approvals
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the threshold is 0 or larger than the number of maintainers
+    /// * If the approval window is 0
+    fn set_commit_threshold(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        threshold: u32,
+        approval_window: u64,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let mut project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        if threshold == 0 || threshold > project.maintainers.len() || approval_window == 0 {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidThreshold);
+        }
+
+        project.config.commit_threshold = threshold;
+        project.config.commit_approval_window = approval_window;
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Key(project_key.clone()), &project);
+
+        events::ProjectConfigUpdated {
+            project_key,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Add a delegate allowed to commit on behalf of the maintainers.
+    ///
+    /// Delegates are meant for automation such as CI, so that maintainer keys
+    /// do not need to be shared. They can only commit, optionally limited to
+    /// some branches, until they expire. Adding an existing delegate replaces
+    /// its scope.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `delegate` - The address of the delegate
+    /// * `branches` - The branches the delegate can commit to, empty for any
+    /// * `expires_at` - Ledger timestamp after which the delegate is invalid
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If there are too many branches or a branch name is invalid
+    /// * If the expiry is not in the future
+    fn add_delegate(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        delegate: Address,
+        branches: Vec<String>,
+        expires_at: u64,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        if branches.len() > MAX_BRANCHES {
+            panic_with_error!(&env, &errors::ContractErrors::TooManyBranches);
+        }
+        for branch in branches.iter() {
+            validate_branch_name(&env, &branch);
+        }
+        if expires_at <= env.ledger().timestamp() {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidExpiry);
+        }
+
+        env.storage().persistent().set(
+            &types::ProjectKey::Delegate(project_key.clone(), delegate.clone()),
+            &types::Delegate {
+                branches: branches.clone(),
+                expires_at,
+                maintainer: maintainer.clone(),
+            },
+        );
+
+        events::DelegateAdded {
+            project_key,
+            delegate,
+            branches,
+            expires_at,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Revoke a delegate.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `delegate` - The address of the delegate
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the delegate doesn't exist
+    fn revoke_delegate(env: Env, maintainer: Address, project_key: Bytes, delegate: Address) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let delegate_key = types::ProjectKey::Delegate(project_key.clone(), delegate.clone());
+        if !env.storage().persistent().has(&delegate_key) {
+            panic_with_error!(&env, &errors::ContractErrors::UnknownDelegate);
+        }
+        env.storage().persistent().remove(&delegate_key);
+
+        events::DelegateRevoked {
+            project_key,
+            delegate,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get the scope of a delegate.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `delegate` - The address of the delegate
+    ///
+    /// # Returns
+    /// * `Option<types::Delegate>` - The delegate, None if unknown or revoked
+    fn get_delegate(env: Env, project_key: Bytes, delegate: Address) -> Option<types::Delegate> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Delegate(project_key, delegate))
+    }
+
+    /// Get the latest commit hash for a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `String` - The current commit hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_commit(env: Env, project_key: Bytes) -> String {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        if env
+            .storage()
+            .persistent()
+            .get::<types::ProjectKey, types::Project>(&key_)
+            .is_some()
+        {
+            last_hash(&env, &project_key).unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoHashFound);
+            })
+        } else {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+        }
+    }
+
+    /// Set the head commit hash of a branch of a project.
+    ///
+    /// Committing to the default branch (`main`) is the same as calling
+    /// `commit` without a parent hash. Other branches are created on their
+    /// first commit. Every commit is appended to the project's history.
+    ///
+    /// If the project requires more than one maintainer approval, this
+    /// counts as an approval, see `approve_commit`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer or delegate calling this function
+    /// * `project_key` - The project key identifier
+    /// * `branch` - The branch name
+    /// * `hash` - The new commit hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer or delegate is not authorized
+    /// * If the branch name is empty or too long
+    /// * If the project already tracks too many branches
+    fn commit_branch(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        branch: String,
+        hash: String,
+    ) {
+        let project_key = crate::resolve_key(&env, &project_key);
+        if branch == String::from_str(&env, types::DEFAULT_BRANCH) {
+            return Self::commit(env, maintainer, project_key, hash, None);
+        }
+
+        Self::approve_commit(env, maintainer, project_key, branch, hash);
+    }
+
+    /// Get the head commit hash of a branch of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `branch` - The branch name
+    ///
+    /// # Returns
+    /// * `String` - The head commit hash of the branch
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the branch has no commit
+    fn get_branch_head(env: Env, project_key: Bytes, branch: String) -> String {
+        let project_key = crate::resolve_key(&env, &project_key);
+        if branch == String::from_str(&env, types::DEFAULT_BRANCH) {
+            return Self::get_commit(env, project_key);
+        }
+
+        let hash: Bytes = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::BranchHead(project_key, branch))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoHashFound);
+            });
+        hash::to_hex(&env, &hash)
+    }
+
+    /// List the branches of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Branch names, starting with the default branch if
+    ///   it has a commit
+    fn list_branches(env: Env, project_key: Bytes) -> Vec<String> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let mut branches = Vec::new(&env);
+        if env
+            .storage()
+            .persistent()
+            .has(&types::ProjectKey::LastHash(project_key.clone()))
+        {
+            branches.push_back(String::from_str(&env, types::DEFAULT_BRANCH));
+        }
+        let other_branches: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::Branches(project_key))
+            .unwrap_or(Vec::new(&env));
+        branches.append(&other_branches);
+        branches
+    }
+
+    /// Delete a branch of a project.
+    ///
+    /// Only the branch head is removed, its commits stay in the history.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `branch` - The branch name
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the branch is the default branch
+    /// * If the branch doesn't exist
+    fn delete_branch(env: Env, maintainer: Address, project_key: Bytes, branch: String) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        if branch == String::from_str(&env, types::DEFAULT_BRANCH) {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidBranch);
+        }
+
+        let branches_key = types::ProjectKey::Branches(project_key.clone());
+        let mut branches: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&branches_key)
+            .unwrap_or(Vec::new(&env));
+        match branches.first_index_of(&branch) {
+            Some(index) => branches.remove(index),
+            None => panic_with_error!(&env, &errors::ContractErrors::NoHashFound),
+        };
+        env.storage().persistent().set(&branches_key, &branches);
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::BranchHead(
+                project_key.clone(),
+                branch.clone(),
+            ));
+
+        events::BranchDeleted {
+            project_key,
+            branch,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get a page of the commit history of a project.
+    ///
+    /// Commits are ordered from oldest to newest.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `page` - The page number (0-based)
+    ///
+    /// # Returns
+    /// * `Vec<types::CommitRecord>` - List of commits on the requested page,
+    ///   empty if the page does not exist
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_commit_history(env: Env, project_key: Bytes, page: u32) -> Vec<types::CommitRecord> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        if !env.storage().persistent().has(&key_) {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+        }
+
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::CommitHistory(project_key, page))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a single entry of the commit history of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `index` - The position of the commit in the history (0-based)
+    ///
+    /// # Returns
+    /// * `types::CommitRecord` - The commit anchored at that position
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If there is no commit at this index
+    fn get_commit_at(env: Env, project_key: Bytes, index: u32) -> types::CommitRecord {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let page = index / MAX_COMMITS_PER_PAGE;
+        let sub_id = index % MAX_COMMITS_PER_PAGE;
+        let commits = Self::get_commit_history(env.clone(), project_key, page);
+        match commits.try_get(sub_id) {
+            Ok(Some(commit)) => commit,
+            _ => panic_with_error!(&env, &errors::ContractErrors::NoHashFound),
+        }
+    }
+
+    /// Tag a release of a project.
+    ///
+    /// Releases are named with a semver version and point to a commit hash
+    /// which must already be anchored with `commit`. Tags are immutable:
+    /// once a version is published it cannot be moved to another commit.
+    /// Build metadata is ignored when comparing versions, so `1.0.0+a` and
+    /// `1.0.0+b` are the same release.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `version` - The semver version of the release
+    /// * `commit_hash` - The anchored commit hash of the release
+    /// * `ipfs_notes` - CID of the release notes
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the version is not a valid semver version
+    /// * If the version was already released
+    /// * If the hash does not match the project's hash algorithm
+    /// * If the commit hash was never anchored
+    /// * If the CID is malformed
+    fn tag_release(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        version: String,
+        commit_hash: String,
+        ipfs_notes: String,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let version_buf = parse_version(&env, &version);
+        let version_ = Version::parse(version_buf.as_slice()).unwrap();
+        let canonical_version = canonical_version(&env, &version_buf);
+
+        let release_key = types::ProjectKey::Release(project_key.clone(), canonical_version);
+        if env.storage().persistent().has(&release_key) {
+            panic_with_error!(&env, &errors::ContractErrors::ReleaseAlreadyExist);
+        }
+
+        let commit_hash = hash::parse(&env, &commit_hash, &project.config.hash_algorithm);
+        require_anchored(&env, &project_key, &commit_hash);
+
+        let release = types::Release {
+            version: version.clone(),
+            commit_hash: commit_hash.clone(),
+            ipfs: cid::parse(&env, &ipfs_notes),
+            maintainer: maintainer.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&release_key, &release);
+
+        let commit_releases_key =
+            types::ProjectKey::CommitReleases(project_key.clone(), commit_hash.clone());
+        let mut commit_releases: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&commit_releases_key)
+            .unwrap_or(Vec::new(&env));
+        commit_releases.push_back(version.clone());
+        env.storage()
+            .persistent()
+            .set(&commit_releases_key, &commit_releases);
+
+        // Add to release list
+        let total_releases_key = types::ProjectKey::TotalReleases(project_key.clone());
+        let total_releases: u32 = env
+            .storage()
+            .persistent()
+            .get(&total_releases_key)
+            .unwrap_or(0);
+        let page = total_releases / MAX_RELEASES_PER_PAGE;
+        let releases_key = types::ProjectKey::Releases(project_key.clone(), page);
+        let mut versions: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&releases_key)
+            .unwrap_or(Vec::new(&env));
+        versions.push_back(version.clone());
+        env.storage().persistent().set(&releases_key, &versions);
+        env.storage()
+            .persistent()
+            .set(&total_releases_key, &(total_releases + 1));
+
+        // Pre-releases never become the latest release
+        if !version_.is_prerelease() {
+            let latest_key = types::ProjectKey::LatestRelease(project_key.clone());
+            let is_latest = match env.storage().persistent().get::<_, String>(&latest_key) {
+                Some(latest) => {
+                    let latest_buf = parse_version(&env, &latest);
+                    version_ > Version::parse(latest_buf.as_slice()).unwrap()
+                }
+                None => true,
+            };
+            if is_latest {
+                env.storage().persistent().set(&latest_key, &version);
+            }
+        }
+
+        events::ReleaseTagged {
+            project_key,
+            version,
+            commit_hash: hash::to_hex(&env, &commit_hash),
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get a release of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `version` - The semver version of the release
+    ///
+    /// # Returns
+    /// * `types::Release` - The release
+    ///
+    /// # Panics
+    /// * If the version is not a valid semver version
+    /// * If there is no such release
+    fn get_release(env: Env, project_key: Bytes, version: String) -> types::Release {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let version_buf = parse_version(&env, &version);
+        let canonical_version = canonical_version(&env, &version_buf);
+
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Release(project_key, canonical_version))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoReleaseFound);
+            })
+    }
+
+    /// Get a page of releases of a project, in publication order.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `page` - The page number (0-based)
+    ///
+    /// # Returns
+    /// * `Vec<types::Release>` - List of releases on the requested page,
+    ///   empty if the page does not exist
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn list_releases(env: Env, project_key: Bytes, page: u32) -> Vec<types::Release> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        if !env.storage().persistent().has(&key_) {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+        }
+
+        let versions: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::Releases(project_key.clone(), page))
+            .unwrap_or(Vec::new(&env));
+
+        let mut releases = Vec::new(&env);
+        for version in versions {
+            releases.push_back(Self::get_release(env.clone(), project_key.clone(), version));
+        }
+        releases
+    }
+
+    /// Get the latest release of a project.
+    ///
+    /// The latest release is the one with the highest semver precedence,
+    /// pre-releases are not considered.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::Release` - The latest release
+    ///
+    /// # Panics
+    /// * If the project has no stable release
+    fn latest_release(env: Env, project_key: Bytes) -> types::Release {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let version: String = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::LatestRelease(project_key.clone()))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoReleaseFound);
+            });
+        Self::get_release(env, project_key, version)
+    }
+
+    /// Publish the Merkle root over the files or artifacts of a commit.
+    ///
+    /// A manifest is immutable once published.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `commit_hash` - The anchored commit hash
+    /// * `root` - The Merkle root
+    /// * `hash_function` - The hash function used to build the tree
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the hash does not match the project's hash algorithm
+    /// * If the commit hash was never anchored
+    /// * If a manifest was already published for the commit
+    fn publish_manifest(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        commit_hash: String,
+        root: BytesN<32>,
+        hash_function: types::MerkleHash,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let commit_hash = hash::parse(&env, &commit_hash, &project.config.hash_algorithm);
+        require_anchored(&env, &project_key, &commit_hash);
+
+        let manifest_key = types::ProjectKey::Manifest(project_key.clone(), commit_hash.clone());
+        if env.storage().persistent().has(&manifest_key) {
+            panic_with_error!(&env, &errors::ContractErrors::ManifestAlreadyExist);
+        }
+
+        let manifest = types::Manifest {
+            root: root.clone(),
+            hash_function,
+            maintainer: maintainer.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&manifest_key, &manifest);
+
+        events::ManifestPublished {
+            project_key,
+            commit_hash: hash::to_hex(&env, &commit_hash),
+            root,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get the manifest published for a commit.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `commit_hash` - The commit hash
+    ///
+    /// # Returns
+    /// * `types::Manifest` - The Merkle root and how it was built
+    ///
+    /// # Panics
+    /// * If the hash is not a valid hex hash
+    /// * If no manifest was published for the commit
+    fn get_manifest(env: Env, project_key: Bytes, commit_hash: String) -> types::Manifest {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let commit_hash = hash::parse(&env, &commit_hash, &types::HashAlgorithm::Any);
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Manifest(project_key, commit_hash))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoManifestFound);
+            })
+    }
+
+    /// Prove that a file or an artifact belongs to an anchored commit.
+    ///
+    /// The leaf is the hash of the file and the proof lists the sibling
+    /// nodes from the leaf up to the root. Pairs of nodes are sorted before
+    /// being hashed together, so the proof does not need to tell on which
+    /// side each sibling is.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `commit_hash` - The commit hash
+    /// * `leaf` - The hash of the file or artifact
+    /// * `proof` - The sibling nodes from the leaf to the root
+    ///
+    /// # Returns
+    /// * `bool` - True if the proof leads to the published root
+    ///
+    /// # Panics
+    /// * If the hash is not a valid hex hash
+    /// * If no manifest was published for the commit
+    fn verify_inclusion(
+        env: Env,
+        project_key: Bytes,
+        commit_hash: String,
+        leaf: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let manifest = Self::get_manifest(env.clone(), project_key, commit_hash);
+
+        let mut node = leaf;
+        for sibling in proof.iter() {
+            node = merkle_node(&env, &manifest.hash_function, &node, &sibling);
+        }
+        node == manifest.root
+    }
+
+    /// Get project information including configuration and maintainers.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::Project` - Project information including name, config, and maintainers
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_project(env: Env, project_key: Bytes) -> types::Project {
+        let key_ = types::ProjectKey::Key(crate::resolve_key(&env, &project_key));
+
+        env.storage()
+            .persistent()
+            .get::<types::ProjectKey, types::Project>(&key_)
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+            })
+    }
+
+    /// Get a summary of a project.
+    ///
+    /// Lighter than `get_project` as the maintainers are only counted.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::ProjectSummary` - Name, links, status and metadata of the project
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_project_summary(env: Env, project_key: Bytes) -> types::ProjectSummary {
+        let project = Self::get_project(env.clone(), project_key.clone());
+        let project_key = crate::resolve_key(&env, &project_key);
+        let metadata = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::Metadata(project_key.clone()))
+            .unwrap_or(types::Metadata {
+                license: String::from_str(&env, ""),
+                description: String::from_str(&env, ""),
+                tags: Vec::new(&env),
+                mirrors: Vec::new(&env),
+                homepage: String::from_str(&env, ""),
+            });
+
+        types::ProjectSummary {
+            name: project.name,
+            url: project.config.url,
+            ipfs: project.config.ipfs,
+            status: Self::get_project_status(env.clone(), project_key),
+            maintainers_count: project.maintainers.len(),
+            metadata,
+        }
+    }
+
+    /// Extend the TTL of the entries of a project, a batch at a time.
+    ///
+    /// Anyone can call this to keep a project from being archived, even while
+    /// the contract is paused. Start with a cursor of 0 and call it again
+    /// with the returned cursor until it returns None. Entries keyed by a
+    /// commit hash, delegates and former aliases are only extended when they
+    /// are used.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `cursor` - The position of the first entry to extend
+    /// * `limit` - The maximum number of entries to extend (1 to 30)
+    ///
+    /// # Returns
+    /// * `Option<u32>` - The cursor of the next batch, None once done
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn extend_project_ttl(env: Env, project_key: Bytes, cursor: u32, limit: u32) -> Option<u32> {
+        Self::get_project(env.clone(), project_key.clone());
+        let project_key = crate::resolve_key(&env, &project_key);
+
+        let fixed = fixed_entries(&env, &project_key);
+        let counts = paged_entry_counts(&env, &project_key);
+        let end = fixed.len() + counts.iter().sum::<u32>();
+        let stop = end.min(cursor.saturating_add(limit.clamp(1, MAX_TTL_BATCH)));
+
+        for position in cursor..stop {
+            if let Some(key) = project_entry(&env, &project_key, &fixed, &counts, position)
+                && env.storage().persistent().has(&key)
+            {
+                ttl::extend(&env, &key);
+            }
+        }
+        ttl::extend_instance(&env);
+
+        if stop < end {
+            return Some(stop);
+        }
+        let extended_key = types::ProjectKey::TtlExtended(project_key);
+        env.storage()
+            .persistent()
+            .set(&extended_key, &env.ledger().sequence());
+        ttl::extend(&env, &extended_key);
+        None
+    }
+
+    /// Get the TTL policy applied to a project.
+    ///
+    /// Contracts cannot read the TTL of an entry, the report is based on the
+    /// last completed run of `extend_project_ttl`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::TtlReport` - The TTL report
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_ttl_report(env: Env, project_key: Bytes) -> types::TtlReport {
+        Self::get_project(env.clone(), project_key.clone());
+        let project_key = crate::resolve_key(&env, &project_key);
+
+        let extended_at: u32 = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::TtlExtended(project_key.clone()))
+            .unwrap_or(0);
+        let live_until = if extended_at == 0 {
+            0
+        } else {
+            extended_at + ttl::THRESHOLD
+        };
+
+        types::TtlReport {
+            entries: fixed_entries(&env, &project_key).len()
+                + paged_entry_counts(&env, &project_key).iter().sum::<u32>(),
+            extended_at,
+            live_until,
+            threshold: ttl::THRESHOLD,
+            extend_to: ttl::EXTEND_TO,
+        }
+    }
+
+    /// Get a page of projects.
+    ///
+    /// Pages list 10 projects, deregistered projects are left out. See
+    /// `list_projects` for lighter results.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `page` - The page number (0-based)
+    ///
+    /// # Returns
+    /// * `Vec<types::Project>` - List of projects on the requested page
+    ///
+    /// # Panics
+    /// * If the page doesn't exist
+    fn get_projects(env: Env, page: u32) -> Vec<types::Project> {
+        let project_keys = index::page(&env, &Index::Projects, page);
+        if project_keys.is_empty() {
+            panic_with_error!(&env, &errors::ContractErrors::NoProjectPageFound);
+        }
+
+        let mut projects = Vec::new(&env);
+        for key in project_keys.iter() {
+            if Self::get_project_status(env.clone(), key.clone())
+                != types::ProjectStatus::Deregistered
+            {
+                projects.push_back(Self::get_project(env.clone(), key));
+            }
+        }
+        projects
+    }
+
+    /// List projects from a cursor.
+    ///
+    /// Deregistered projects are left out.
+    /// At most 50 projects are looked at per call, so that a filtered result
+    /// can be empty while `next_cursor` is set.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `cursor` - The position to start from, 0 or a previous `next_cursor`
+    /// * `limit` - The maximum number of projects (1 to 20)
+    /// * `filter` - The projects to keep
+    ///
+    /// # Returns
+    /// * `types::ProjectList` - The projects and the cursor of the next call
+    fn list_projects(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+        filter: types::ProjectFilter,
+    ) -> types::ProjectList {
+        let limit = limit.clamp(1, MAX_LIST_LIMIT);
+        let index_ = match &filter {
+            types::ProjectFilter::Maintainer(maintainer) => Index::Maintainer(maintainer.clone()),
+            _ => Index::Projects,
+        };
+
+        let mut items = Vec::new(&env);
+        let mut position = cursor;
+        for key in index::range(&env, &index_, cursor, MAX_LIST_SCAN).iter() {
+            if items.len() == limit {
+                break;
+            }
+            position += 1;
+
+            let status = Self::get_project_status(env.clone(), key.clone());
+            let storage = env.storage().persistent();
+            let selected = match &filter {
+                _ if status == types::ProjectStatus::Deregistered => false,
+                types::ProjectFilter::All | types::ProjectFilter::Maintainer(_) => true,
+                types::ProjectFilter::HasDao => {
+                    storage.has(&types::ProjectKey::DaoTotalProposals(key.clone()))
+                }
+                types::ProjectFilter::HasAnonymousVoting => {
+                    storage.has(&types::ProjectKey::AnonymousVoteConfig(key.clone()))
+                }
+                types::ProjectFilter::Archived => status == types::ProjectStatus::Archived,
+            };
+            if !selected {
+                continue;
+            }
+
+            let project: types::Project = storage
+                .get(&types::ProjectKey::Key(key.clone()))
+                .expect("Invalid project key");
+            items.push_back(types::ProjectListItem {
+                key,
+                name: project.name,
+                url: project.config.url,
+                status,
+                maintainers_count: project.maintainers.len(),
+            });
+        }
+
+        let next_cursor = if position < index::total(&env, &index_) {
+            Some(position)
+        } else {
+            None
+        };
+        types::ProjectList { items, next_cursor }
+    }
+
+    /// Get a page of the projects maintained by an address.
+    ///
+    /// Archived projects are included, deregistered ones are not.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The maintainer address
+    /// * `page` - The page number (0-based)
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - Project keys on the requested page, empty if none
+    fn get_projects_by_maintainer(env: Env, maintainer: Address, page: u32) -> Vec<Bytes> {
+        index::page(&env, &Index::Maintainer(maintainer), page)
+    }
+
+    /// Get sub-projects for a project (if it's an organization).
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - List of sub-project keys, empty if not an organization
+    fn get_sub_projects(env: Env, project_key: Bytes) -> Vec<Bytes> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        let project = env
+            .storage()
+            .persistent()
+            .get::<types::ProjectKey, types::Project>(&key_)
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+            });
+
+        project.sub_projects.unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Set sub-projects for a project (making it an organization).
+    ///
+    /// Sub-projects must be registered and belong to no other organization.
+    /// Their maintainers must first agree to join with `accept_parent`.
+    /// Sub-projects missing from the new list are detached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The maintainer address calling this function
+    /// * `project_key` - The project key identifier
+    /// * `sub_projects` - List of sub-project keys to associate
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If more than 10 sub-projects are provided
+    /// * If a sub-project doesn't exist
+    /// * If a sub-project is listed twice or belongs to another organization
+    /// * If a sub-project is the project itself or one of its parents
+    /// * If a sub-project did not agree to join
+    fn set_sub_projects(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        sub_projects: Vec<Bytes>,
+    ) {
+        Tansu::require_not_paused(env.clone());
+        let project_key = crate::resolve_key(&env, &project_key);
+        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        if sub_projects.len() > 10 {
+            panic_with_error!(&env, &errors::ContractErrors::TooManySubProjects);
+        }
+
+        let mut children = Vec::new(&env);
+        for sub_project in sub_projects.iter() {
+            let child = crate::resolve_key(&env, &sub_project);
+            if !env
+                .storage()
+                .persistent()
+                .has(&types::ProjectKey::Key(child.clone()))
+            {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+            }
+            if children.contains(&child) {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidSubProject);
+            }
+            if is_ancestor(&env, &child, &project_key) {
+                panic_with_error!(&env, &errors::ContractErrors::SubProjectCycle);
+            }
+            match Self::get_parent(env.clone(), child.clone()) {
+                Some(parent) if parent == project_key => {}
+                Some(_) => panic_with_error!(&env, &errors::ContractErrors::InvalidSubProject),
+                None => {
+                    let consent: Option<Bytes> = env
+                        .storage()
+                        .persistent()
+                        .get(&types::ProjectKey::ParentConsent(child.clone()));
+                    if consent != Some(project_key.clone()) {
+                        panic_with_error!(&env, &errors::ContractErrors::NoParentConsent);
+                    }
+                }
+            }
+            children.push_back(child);
+        }
+
+        for previous in project
+            .sub_projects
+            .clone()
+            .unwrap_or_else(|| Vec::new(&env))
+            .iter()
+        {
+            if !children.contains(&previous)
+                && Self::get_parent(env.clone(), previous.clone()) == Some(project_key.clone())
+            {
+                env.storage()
+                    .persistent()
+                    .remove(&types::ProjectKey::Parent(previous));
+            }
+        }
+        for child in children.iter() {
+            env.storage()
+                .persistent()
+                .set(&types::ProjectKey::Parent(child.clone()), &project_key);
+            env.storage()
+                .persistent()
+                .remove(&types::ProjectKey::ParentConsent(child));
+        }
+
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        let mut updated_project = project;
+        updated_project.sub_projects = Some(children.clone());
+
+        env.storage().persistent().set(&key_, &updated_project);
+
+        events::SubProjectsUpdated {
+            project_key,
+            sub_projects: children,
+        }
+        .publish(&env);
+    }
+
+    /// Agree to join an organization as one of its sub-projects.
+    ///
+    /// The organization then adds the project with `set_sub_projects`. A new
+    /// call replaces the previous agreement.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The maintainer address calling this function
+    /// * `project_key` - The project key identifier
+    /// * `parent_key` - The key of the organization
+    ///
+    /// # Panics
+    /// * If the project or the organization doesn't exist
+    /// * If the maintainer is not authorized
+    fn accept_parent(env: Env, maintainer: Address, project_key: Bytes, parent_key: Bytes) {
+        Tansu::require_not_paused(env.clone());
+        let project_key = crate::resolve_key(&env, &project_key);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let parent_key = Self::resolve_project_key(env.clone(), parent_key);
+        env.storage().persistent().set(
+            &types::ProjectKey::ParentConsent(project_key.clone()),
+            &parent_key,
+        );
+
+        events::ParentAccepted {
+            project_key,
+            parent_key,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get the organization a project belongs to.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Option<Bytes>` - The key of the organization, if any
+    fn get_parent(env: Env, project_key: Bytes) -> Option<Bytes> {
+        let project_key = crate::resolve_key(&env, &project_key);
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Parent(project_key))
+    }
+
+    /// Get a project and its sub-projects, recursively.
+    ///
+    /// The nodes are listed depth first, each sub-project after its parent.
+    /// The depth is capped at 5 levels below the project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `depth` - Levels of sub-projects to include, 0 for the project alone
+    ///
+    /// # Returns
+    /// * `Vec<types::ProjectNode>` - The project followed by its sub-projects
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_project_tree(env: Env, project_key: Bytes, depth: u32) -> Vec<types::ProjectNode> {
+        let project = Self::get_project(env.clone(), project_key.clone());
+        let project_key = crate::resolve_key(&env, &project_key);
+
+        let mut nodes = vec![
+            &env,
+            types::ProjectNode {
+                key: project_key.clone(),
+                name: project.name.clone(),
+                parent: Self::get_parent(env.clone(), project_key.clone()),
+                depth: 0,
+            },
+        ];
+        append_sub_projects(
+            &env,
+            &mut nodes,
+            &project_key,
+            &project,
+            1,
+            depth.min(MAX_PROJECT_TREE_DEPTH),
+        );
+        nodes
+    }
+}
+
+/// Get how many maintainer approvals are needed to change the maintainers.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `project` - The project
+///
+/// # Returns
+/// * `u32` - The quorum, a majority of the maintainers if not set
+fn maintainers_quorum(env: &Env, project_key: &Bytes, project: &types::Project) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&types::ProjectKey::MaintainersQuorum(project_key.clone()))
+        .unwrap_or(project.maintainers.len() / 2 + 1)
+}
+
+/// Record an approval of an action on a project.
+///
+/// Maintainers need a quorum, admins act on their own if allowed.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `caller` - An admin or a maintainer of the project
+/// * `project_key` - The project key identifier
+/// * `action` - The action to approve
+/// * `admin_allowed` - Whether an admin can take the action alone
+///
+/// # Returns
+/// * `bool` - True if the action can be taken
+///
+/// # Panics
+/// * If the project doesn't exist
+/// * If the caller is neither an allowed admin nor a maintainer
+/// * If the maintainer already approved
+fn approve_action(
+    env: &Env,
+    caller: &Address,
+    project_key: &Bytes,
+    action: &types::ProjectAction,
+    admin_allowed: bool,
+) -> bool {
+    let approvals_key = types::ProjectKey::ActionApprovals(project_key.clone(), action.clone());
+    if admin_allowed
+        && Tansu::get_admins_config(env.clone())
+            .admins
+            .contains(caller)
+    {
+        caller.require_auth();
+        if !env
+            .storage()
+            .persistent()
+            .has(&types::ProjectKey::Key(project_key.clone()))
+        {
+            panic_with_error!(env, &errors::ContractErrors::InvalidKey);
+        }
+        env.storage().persistent().remove(&approvals_key);
+        return true;
+    }
+
+    let project = crate::auth_maintainers_any_status(env, caller, project_key);
+
+    let approvals: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&approvals_key)
+        .unwrap_or(Vec::new(env));
+    if approvals.contains(caller) {
+        panic_with_error!(env, &errors::ContractErrors::AlreadyVoted);
+    }
+
+    // approvals of removed maintainers do not count anymore
+    let mut current_approvals = Vec::new(env);
+    for approval in approvals.iter() {
+        if project.maintainers.contains(&approval) {
+            current_approvals.push_back(approval);
+        }
+    }
+    current_approvals.push_back(caller.clone());
+
+    let approvals_count = current_approvals.len();
+    let quorum_reached = approvals_count >= maintainers_quorum(env, project_key, &project);
+    if quorum_reached {
+        env.storage().persistent().remove(&approvals_key);
+    } else {
+        env.storage()
+            .persistent()
+            .set(&approvals_key, &current_approvals);
+    }
+
+    events::ProjectActionApproved {
+        project_key: project_key.clone(),
+        action: action.clone(),
+        maintainer: caller.clone(),
+        approvals_count,
+        quorum_reached,
+    }
+    .publish(env);
+
+    quorum_reached
+}
+
+/// Check if a project is the given project or one of its parents.
+fn is_ancestor(env: &Env, ancestor: &Bytes, project_key: &Bytes) -> bool {
+    let mut current = Some(project_key.clone());
+    while let Some(key) = current {
+        if key == *ancestor {
+            return true;
+        }
+        current = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::Parent(key));
+    }
+    false
+}
+
+/// Append the sub-projects of a project to a tree, depth first.
+///
+/// Sub-projects which are not registered are skipped.
+fn append_sub_projects(
+    env: &Env,
+    nodes: &mut Vec<types::ProjectNode>,
+    project_key: &Bytes,
+    project: &types::Project,
+    depth: u32,
+    max_depth: u32,
+) {
+    if depth > max_depth {
+        return;
+    }
+    for child in project
+        .sub_projects
+        .clone()
+        .unwrap_or_else(|| Vec::new(env))
+    {
+        let Some(child_project) = env
+            .storage()
+            .persistent()
+            .get::<types::ProjectKey, types::Project>(&types::ProjectKey::Key(child.clone()))
+        else {
+            continue;
+        };
+        nodes.push_back(types::ProjectNode {
+            key: child.clone(),
+            name: child_project.name.clone(),
+            parent: Some(project_key.clone()),
+            depth,
+        });
+        append_sub_projects(env, nodes, &child, &child_project, depth + 1, max_depth);
+    }
+}
+
+/// Store a new project, registering its domain.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `maintainer` - The address of the maintainer registering the project
+/// * `project` - The new project, its name is normalized
+///
+/// # Returns
+/// * `Bytes` - The project key (keccak256 hash of the name)
+///
+/// # Panics
+/// * If the project name is empty or longer than 15 characters
+/// * If the project name contains anything else than ASCII letters
+/// * If the project already exists
+/// * If the project name is reserved
+/// * If the maintainer is not authorized
+/// * If the domain registration fails
+/// * If the maintainer doesn't own an existing domain
+fn register_project(env: &Env, maintainer: &Address, mut project: types::Project) -> Bytes {
+    project.name = name::parse(env, &project.name);
+    let name_b = project.name.to_bytes();
+    let key = available_key(env, &project.name);
+
+    maintainer.require_auth();
+    if !project.maintainers.contains(maintainer) {
+        panic_with_error!(env, &errors::ContractErrors::UnauthorizedSigner);
+    }
+
+    claim_domain(env, &name_b, &key, maintainer);
+    env.storage()
+        .persistent()
+        .set(&types::ProjectKey::Key(key.clone()), &project);
+
+    // Add to project list
+    index::push(env, &Index::Projects, &key);
+    index::set_maintainers(env, &key, &Vec::new(env), &project.maintainers);
+
+    events::ProjectRegistered {
+        project_key: key.clone(),
+        name: project.name.clone(),
+        maintainer: maintainer.clone(),
+    }
+    .publish(env);
+
+    key
+}
+
+/// Get the key of a normalized project name if it can be registered.
+///
+/// # Panics
+/// * If the name is used by a project
+/// * If the name is reserved
+fn available_key(env: &Env, name: &String) -> Bytes {
+    let key = name::key(env, name);
+    if name_taken(env, &key) {
+        panic_with_error!(env, &errors::ContractErrors::ProjectAlreadyExist);
+    }
+    if env
+        .storage()
+        .persistent()
+        .has(&types::ProjectKey::Reserved(key.clone()))
+    {
+        panic_with_error!(env, &errors::ContractErrors::ReservedDomain);
+    }
+    key
+}
+
+/// List the entries of a project which are not paginated, existing or
+/// not, see `extend_project_ttl`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The registered project key
+///
+/// # Returns
+/// * `Vec<types::ProjectKey>` - The keys of the entries
+fn fixed_entries(env: &Env, project_key: &Bytes) -> Vec<types::ProjectKey> {
+    let key = project_key.clone();
+    let mut entries = vec![
+        env,
+        types::ProjectKey::Key(key.clone()),
+        types::ProjectKey::Badges(key.clone()),
+        types::ProjectKey::LastHash(key.clone()),
+        types::ProjectKey::DaoTotalProposals(key.clone()),
+        types::ProjectKey::AnonymousVoteConfig(key.clone()),
+        types::ProjectKey::TotalCommits(key.clone()),
+        types::ProjectKey::TotalReleases(key.clone()),
+        types::ProjectKey::LatestRelease(key.clone()),
+        types::ProjectKey::TotalAdvisories(key.clone()),
+        types::ProjectKey::Branches(key.clone()),
+        types::ProjectKey::PendingCommits(key.clone()),
+        types::ProjectKey::AttestationThreshold(key.clone()),
+        types::ProjectKey::MaintainersChange(key.clone()),
+        types::ProjectKey::MaintainersQuorum(key.clone()),
+        types::ProjectKey::Status(key.clone()),
+        types::ProjectKey::ActionApprovals(key.clone(), types::ProjectAction::Archive),
+        types::ProjectKey::ActionApprovals(key.clone(), types::ProjectAction::Deregister),
+        types::ProjectKey::TransferOffer(key.clone()),
+        types::ProjectKey::Parent(key.clone()),
+        types::ProjectKey::ParentConsent(key.clone()),
+        types::ProjectKey::Dependencies(key.clone()),
+        types::ProjectKey::TotalDependents(key.clone()),
+        types::ProjectKey::TotalForks(key.clone()),
+        types::ProjectKey::Metadata(key.clone()),
+        types::ProjectKey::TtlExtended(key.clone()),
+    ];
+
+    // the alias of the current name
+    if let Some(project) = env
+        .storage()
+        .persistent()
+        .get::<_, types::Project>(&types::ProjectKey::Key(key))
+    {
+        entries.push_back(types::ProjectKey::Alias(name::key(env, &project.name)));
+    }
+    entries
+}
+
+/// Count the entries of each paginated list of a project, in the order of
+/// `project_entry`: DAO pages, commit history pages, dependents pages, forks
+/// pages, releases pages, releases, advisories and branch heads.
+fn paged_entry_counts(env: &Env, project_key: &Bytes) -> [u32; 8] {
+    let storage = env.storage().persistent();
+    let total = |key: types::ProjectKey| -> u32 { storage.get(&key).unwrap_or(0) };
+    let key = project_key.clone();
+    let total_releases = total(types::ProjectKey::TotalReleases(key.clone()));
+    let branches: Vec<String> = storage
+        .get(&types::ProjectKey::Branches(key.clone()))
+        .unwrap_or(Vec::new(env));
+
+    [
+        total(types::ProjectKey::DaoTotalProposals(key.clone()))
+            .div_ceil(crate::contract_dao::MAX_PROPOSALS_PER_PAGE),
+        total(types::ProjectKey::TotalCommits(key.clone())).div_ceil(MAX_COMMITS_PER_PAGE),
+        index::total(env, &Index::Dependents(key.clone())).div_ceil(index::MAX_KEYS_PER_PAGE),
+        index::total(env, &Index::Forks(key.clone())).div_ceil(index::MAX_KEYS_PER_PAGE),
+        total_releases.div_ceil(MAX_RELEASES_PER_PAGE),
+        total_releases,
+        total(types::ProjectKey::TotalAdvisories(key)),
+        branches.len(),
+    ]
+}
+
+/// Get the entry of a project at a position of `extend_project_ttl`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The registered project key
+/// * `fixed` - The entries which are not paginated, see `fixed_entries`
+/// * `counts` - The sizes of the paginated lists, see `paged_entry_counts`
+/// * `position` - The position of the entry
+///
+/// # Returns
+/// * `Option<types::ProjectKey>` - The key of the entry, None past the end
+fn project_entry(
+    env: &Env,
+    project_key: &Bytes,
+    fixed: &Vec<types::ProjectKey>,
+    counts: &[u32; 8],
+    position: u32,
+) -> Option<types::ProjectKey> {
+    if position < fixed.len() {
+        return fixed.get(position);
+    }
+    let mut offset = position - fixed.len();
+    let mut list = 0;
+    while offset >= *counts.get(list)? {
+        offset -= counts[list];
+        list += 1;
+    }
+
+    let key = project_key.clone();
+    let storage = env.storage().persistent();
+    Some(match list {
+        0 => types::ProjectKey::Dao(key, offset),
+        1 => types::ProjectKey::CommitHistory(key, offset),
+        2 => types::ProjectKey::Dependents(key, offset),
+        3 => types::ProjectKey::Forks(key, offset),
+        4 => types::ProjectKey::Releases(key, offset),
+        5 => {
+            let versions: Vec<String> = storage.get(&types::ProjectKey::Releases(
+                key.clone(),
+                offset / MAX_RELEASES_PER_PAGE,
+            ))?;
+            let version = versions.get(offset % MAX_RELEASES_PER_PAGE)?;
+            types::ProjectKey::Release(key, canonical_version(env, &parse_version(env, &version)))
+        }
+        6 => types::ProjectKey::Advisory(key, offset),
+        _ => {
+            let branches: Vec<String> = storage.get(&types::ProjectKey::Branches(key.clone()))?;
+            types::ProjectKey::BranchHead(key, branches.get(offset)?)
+        }
+    })
+}
+
+/// Check if a project key is used, by a project or as an alias.
+fn name_taken(env: &Env, key: &Bytes) -> bool {
+    env.storage()
+        .persistent()
+        .has(&types::ProjectKey::Key(key.clone()))
+        || env
+            .storage()
+            .persistent()
+            .has(&types::ProjectKey::Alias(key.clone()))
+}
+
+/// Register the Soroban domain of a project name.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `name` - The project name
+/// * `key` - The key of the name
+/// * `maintainer` - The maintainer owning the domain
+///
+/// # Panics
+/// * If the domain registration fails
+/// * If the maintainer doesn't own an existing domain
+fn claim_domain(env: &Env, name: &Bytes, key: &Bytes, maintainer: &Address) {
+    let domain_contract = crate::retrieve_contract(env, types::ContractKey::DomainContract);
+
+    let node = domain_node(env, key);
+    let record_keys = domain_contract::RecordKeys::Record(node);
+
+    let domain_client = domain_contract::Client::new(env, &domain_contract.address);
+    match domain_client.try_record(&record_keys) {
+        Ok(Ok(None)) => domain_register(env, name, maintainer, domain_contract.address),
+        Ok(Ok(Some(domain_contract::Record::Domain(domain)))) => {
+            if domain.owner != *maintainer {
+                panic_with_error!(env, &errors::ContractErrors::MaintainerNotDomainOwner)
+            }
+        }
+        _ => panic_with_error!(env, &errors::ContractErrors::InvalidDomainError),
+    }
+}
+
+/// Count the approvals of a change given by current maintainers.
+fn count_approvals(project: &types::Project, change: &types::MaintainersChange) -> u32 {
+    change
+        .approvals
+        .iter()
+        .filter(|approval| project.maintainers.contains(approval))
+        .count() as u32
+}
+
+/// Compute the maintainers resulting from a change.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `project` - The project
+/// * `change` - The change to apply
+///
+/// # Returns
+/// * `Vec<Address>` - The new maintainers
+///
+/// # Panics
+/// * If the change is empty, adds a maintainer or removes a non-maintainer
+/// * If the resulting maintainers are fewer than the quorum or the commit
+///   threshold
+fn apply_maintainers_change(
+    env: &Env,
+    project_key: &Bytes,
+    project: &types::Project,
+    change: &types::MaintainersChange,
+) -> Vec<Address> {
+    if change.add.is_empty() && change.remove.is_empty() && change.quorum.is_none() {
+        panic_with_error!(env, &errors::ContractErrors::InvalidMaintainersChange);
+    }
+
+    let mut maintainers = Vec::new(env);
+    for maintainer in project.maintainers.iter() {
+        if !change.remove.contains(&maintainer) {
+            maintainers.push_back(maintainer);
+        }
+    }
+    if maintainers.len() + change.remove.len() != project.maintainers.len() {
+        // removing a non-maintainer or the same maintainer twice
+        panic_with_error!(env, &errors::ContractErrors::InvalidMaintainersChange);
+    }
+    for maintainer in change.add.iter() {
+        if maintainers.contains(&maintainer) || change.remove.contains(&maintainer) {
+            panic_with_error!(env, &errors::ContractErrors::InvalidMaintainersChange);
+        }
+        maintainers.push_back(maintainer);
+    }
+
+    let quorum = match change.quorum {
+        Some(quorum) => quorum,
+        None => env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::MaintainersQuorum(project_key.clone()))
+            .unwrap_or(maintainers.len() / 2 + 1),
+    };
+    if quorum == 0 || quorum > maintainers.len() {
+        panic_with_error!(env, &errors::ContractErrors::InvalidMaintainersChange);
+    }
+    if project.config.commit_threshold > maintainers.len() {
+        panic_with_error!(env, &errors::ContractErrors::InvalidThreshold);
+    }
+    maintainers
+}
+
+/// Validate the name of a branch.
+///
+/// # Panics
+/// * If the branch name is empty or too long
+fn validate_branch_name(env: &Env, branch: &String) {
+    if branch.is_empty() || branch.len() > MAX_BRANCH_NAME_LENGTH {
+        panic_with_error!(env, &errors::ContractErrors::InvalidBranch);
+    }
+}
+
+/// Validate the structured metadata of a project.
+///
+/// # Panics
+/// * If a field is too long or has invalid characters, see `set_metadata`
+fn validate_metadata(env: &Env, metadata: &types::Metadata) {
+    let spdx_char = |c: u8| c.is_ascii_alphanumeric() || b"-.+:() ".contains(&c);
+    let tag_char = |c: u8| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-';
+
+    let mut valid = has_chars(&metadata.license, MAX_LICENSE_LENGTH, spdx_char)
+        && is_text(&metadata.description, MAX_DESCRIPTION_LENGTH)
+        && metadata.tags.len() <= MAX_TAGS
+        && metadata.mirrors.len() <= MAX_MIRRORS
+        && (metadata.homepage.is_empty() || is_url(&metadata.homepage));
+    for tag in metadata.tags.iter() {
+        valid &= !tag.is_empty() && has_chars(&tag, MAX_TAG_LENGTH, tag_char);
+    }
+    for mirror in metadata.mirrors.iter() {
+        valid &= is_url(&mirror);
+    }
+    if !valid {
+        panic_with_error!(env, &errors::ContractErrors::InvalidMetadata);
+    }
+}
+
+/// Check that a string is not too long and only has allowed characters.
+fn has_chars(value: &String, max_length: u32, allowed: impl Fn(u8) -> bool) -> bool {
+    let mut buf = [0u8; MAX_DESCRIPTION_LENGTH as usize];
+    let len = value.len() as usize;
+    if value.len() > max_length || len > buf.len() {
+        return false;
+    }
+    value.copy_into_slice(&mut buf[..len]);
+    buf[..len].iter().all(|c| allowed(*c))
+}
+
+/// Check that a string is not too long and has no control characters,
+/// neither ASCII ones nor UTF-8 encoded C1 ones.
+fn is_text(value: &String, max_length: u32) -> bool {
+    let mut buf = [0u8; MAX_DESCRIPTION_LENGTH as usize];
+    let len = value.len() as usize;
+    if value.len() > max_length || len > buf.len() {
+        return false;
+    }
+    value.copy_into_slice(&mut buf[..len]);
+    let buf = &buf[..len];
+    !buf.iter().any(|c| c.is_ascii_control())
+        && !buf
+            .windows(2)
+            .any(|pair| pair[0] == 0xc2 && (0x80..0xa0).contains(&pair[1]))
+}
+
+/// Check that a string is an `https://` URL of at most 128 characters,
+/// without spaces or control characters.
+fn is_url(value: &String) -> bool {
+    const SCHEME: &[u8] = b"https://";
+    let mut scheme = [0u8; SCHEME.len()];
+    if value.len() <= SCHEME.len() as u32
+        || !has_chars(value, MAX_URL_LENGTH, |c| c.is_ascii_graphic())
+    {
+        return false;
+    }
+    value
+        .to_bytes()
+        .slice(..SCHEME.len() as u32)
+        .copy_into_slice(&mut scheme);
+    scheme == SCHEME
+}
+
+/// Get the head of the default branch as a hex string.
+///
+/// Hashes anchored before they were stored in binary form are kept as
+/// strings, both representations are handled here.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Returns
+/// * `Option<String>` - The head commit hash, None if there is no commit
+fn last_hash(env: &Env, project_key: &Bytes) -> Option<String> {
+    let value: Val = env
+        .storage()
+        .persistent()
+        .get(&types::ProjectKey::LastHash(project_key.clone()))?;
+    match Bytes::try_from_val(env, &value) {
+        Ok(hash) => Some(hash::to_hex(env, &hash)),
+        Err(_) => String::try_from_val(env, &value).ok(),
+    }
+}
+
+/// Check that a hash was anchored on a project.
+///
+/// Heads anchored before the commit log existed are not in `CommitIndex`,
+/// the head of the default branch is accepted as well.
+///
+/// # Panics
+/// * If the hash was never anchored
+pub fn require_anchored(env: &Env, project_key: &Bytes, hash: &Bytes) {
+    let indexed = env
+        .storage()
+        .persistent()
+        .has(&types::ProjectKey::CommitIndex(
+            project_key.clone(),
+            hash.clone(),
+        ));
+    if !indexed && last_hash(env, project_key) != Some(hash::to_hex(env, hash)) {
+        panic_with_error!(env, &errors::ContractErrors::NoHashFound);
+    }
+}
+
+/// Get the head of the default branch as stored.
+///
+/// # Returns
+/// * `Option<Bytes>` - The raw head commit hash, or the bytes of a legacy
+///   string hash, None if there is no commit
+fn raw_last_hash(env: &Env, project_key: &Bytes) -> Option<Bytes> {
+    let value: Val = env
+        .storage()
+        .persistent()
+        .get(&types::ProjectKey::LastHash(project_key.clone()))?;
+    match Bytes::try_from_val(env, &value) {
+        Ok(hash) => Some(hash),
+        Err(_) => String::try_from_val(env, &value)
+            .ok()
+            .map(|hash| hash.to_bytes()),
+    }
+}
+
+/// Anchor a commit or record an approval for it.
+///
+/// With a commit threshold of 1 the hash is anchored right away. Otherwise
+/// the committer's approval is added to the pending commit, which is
+/// anchored once the threshold is reached. A delegate only creates the
+/// pending commit, its approval does not count.
+///
+/// The parent hash is stored with the pending commit and checked against
+/// the head on each approval, so that a commit approved after the head
+/// moved cannot fork the history.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project` - The project, committer already authenticated
+/// * `committer` - The address approving the commit
+/// * `project_key` - The project key identifier
+/// * `branch` - The branch name
+/// * `hash` - The raw commit hash
+/// * `parent_hash` - The expected head, raw, None to keep the pending one
+///
+/// # Panics
+/// * If the committer already approved or proposed this commit
+/// * If there are too many pending commits
+/// * If the parent hash does not match the head or the pending commit
+fn submit_commit(
+    env: &Env,
+    project: &types::Project,
+    committer: &Address,
+    project_key: &Bytes,
+    branch: &String,
+    hash: &Bytes,
+    parent_hash: Option<Bytes>,
+) {
+    let threshold = project.config.commit_threshold;
+    if threshold <= 1 {
+        check_parent_hash(env, project_key, &parent_hash);
+        anchor_commit(env, project_key, branch, hash, committer);
+        return;
+    }
+
+    let pending_key = types::ProjectKey::PendingCommits(project_key.clone());
+    let mut pending_commits =
+        <Tansu as VersioningTrait>::get_pending_commits(env.clone(), project_key.clone());
+
+    let (index, mut pending_commit) = match pending_commits
+        .iter()
+        .enumerate()
+        .find(|(_, pending)| &pending.branch == branch && &pending.hash == hash)
+    {
+        Some((index, pending)) => (Some(index as u32), pending),
+        None => (
+            None,
+            types::PendingCommit {
+                branch: branch.clone(),
+                hash: hash.clone(),
+                parent_hash: parent_hash.clone(),
+                approvals: Vec::new(env),
+                expires_at: env.ledger().timestamp() + project.config.commit_approval_window,
+            },
+        ),
+    };
+
+    if parent_hash.is_some() && parent_hash != pending_commit.parent_hash {
+        panic_with_error!(env, &errors::ContractErrors::ParentHashMismatch);
+    }
+    // the head may have moved since the commit was proposed
+    check_parent_hash(env, project_key, &pending_commit.parent_hash);
+
+    if project.maintainers.contains(committer) {
+        if pending_commit.approvals.contains(committer) {
+            panic_with_error!(env, &errors::ContractErrors::AlreadyVoted);
+        }
+        pending_commit.approvals.push_back(committer.clone());
+    } else if index.is_some() {
+        panic_with_error!(env, &errors::ContractErrors::AlreadyVoted);
+    }
+
+    // approvals of removed maintainers do not count
+    let approvals_count = pending_commit
+        .approvals
+        .iter()
+        .filter(|approval| project.maintainers.contains(approval))
+        .count() as u32;
+    let threshold_reached = approvals_count >= threshold;
+    match (index, threshold_reached) {
+        (Some(index), true) => {
+            pending_commits.remove(index);
+        }
+        (Some(index), false) => pending_commits.set(index, pending_commit),
+        (None, true) => (),
+        (None, false) => {
+            if pending_commits.len() >= MAX_PENDING_COMMITS {
+                panic_with_error!(env, &errors::ContractErrors::TooManyPendingCommits);
+            }
+            pending_commits.push_back(pending_commit);
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&pending_key, &pending_commits);
+
+    events::CommitApproved {
+        project_key: project_key.clone(),
+        branch: branch.clone(),
+        hash: hash::to_hex(env, hash),
+        maintainer: committer.clone(),
+        approvals_count,
+        threshold_reached,
+    }
+    .publish(env);
+
+    if threshold_reached {
+        anchor_commit(env, project_key, branch, hash, committer);
+    }
+}
+
+/// Check that the head of the default branch is the expected parent hash.
+///
+/// # Panics
+/// * If the parent hash is set and does not match the head
+fn check_parent_hash(env: &Env, project_key: &Bytes, parent_hash: &Option<Bytes>) {
+    if let Some(parent_hash) = parent_hash
+        && last_hash(env, project_key) != Some(hash::to_hex(env, parent_hash))
+    {
+        panic_with_error!(env, &errors::ContractErrors::ParentHashMismatch);
+    }
+}
+
+/// Set the head of a branch and record the commit in the history.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `branch` - The branch name
+/// * `hash` - The raw commit hash
+/// * `committer` - The address which anchored the commit
+///
+/// # Panics
+/// * If the project already tracks too many branches
+fn anchor_commit(
+    env: &Env,
+    project_key: &Bytes,
+    branch: &String,
+    hash: &Bytes,
+    committer: &Address,
+) {
+    if branch == &String::from_str(env, types::DEFAULT_BRANCH) {
+        let last_hash_key = types::ProjectKey::LastHash(project_key.clone());
+        env.storage().persistent().set(&last_hash_key, hash);
+        ttl::extend(env, &last_hash_key);
+
+        append_commit_history(env, project_key, branch, hash, committer);
+
+        events::Commit {
+            project_key: project_key.clone(),
+            hash: hash::to_hex(env, hash),
+        }
+        .publish(env);
+        return;
+    }
+
+    let branches_key = types::ProjectKey::Branches(project_key.clone());
+    let mut branches: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&branches_key)
+        .unwrap_or(Vec::new(env));
+    if !branches.contains(branch) {
+        // the default branch counts towards the limit
+        if branches.len() + 1 >= MAX_BRANCHES {
+            panic_with_error!(env, &errors::ContractErrors::TooManyBranches);
+        }
+        branches.push_back(branch.clone());
+        env.storage().persistent().set(&branches_key, &branches);
+    }
+
+    env.storage().persistent().set(
+        &types::ProjectKey::BranchHead(project_key.clone(), branch.clone()),
+        hash,
+    );
+
+    append_commit_history(env, project_key, branch, hash, committer);
+
+    events::BranchCommit {
+        project_key: project_key.clone(),
+        branch: branch.clone(),
+        hash: hash::to_hex(env, hash),
+    }
+    .publish(env);
+}
+
+/// Append a commit to the history of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `branch` - The branch the commit was anchored on
+/// * `hash` - The raw commit hash being anchored
+/// * `committer` - The address which anchored the commit
+pub fn append_commit_history(
+    env: &Env,
+    project_key: &Bytes,
+    branch: &String,
+    hash: &Bytes,
+    committer: &Address,
+) {
+    let total_commits_key = types::ProjectKey::TotalCommits(project_key.clone());
+    let total_commits: u32 = env
+        .storage()
+        .persistent()
+        .get(&total_commits_key)
+        .unwrap_or(0);
+
+    let page = total_commits / MAX_COMMITS_PER_PAGE;
+    let history_key = types::ProjectKey::CommitHistory(project_key.clone(), page);
+    let mut commits: Vec<types::CommitRecord> = env
+        .storage()
+        .persistent()
+        .get(&history_key)
+        .unwrap_or(Vec::new(env));
+
+    commits.push_back(types::CommitRecord {
+        hash: hash.clone(),
+        branch: branch.clone(),
+        committer: committer.clone(),
+        timestamp: env.ledger().timestamp(),
+        sequence: env.ledger().sequence(),
+    });
+
+    env.storage().persistent().set(&history_key, &commits);
+    ttl::extend(env, &history_key);
+
+    // Only keep the first time a hash was anchored
+    let index_key = types::ProjectKey::CommitIndex(project_key.clone(), hash.clone());
+    if !env.storage().persistent().has(&index_key) {
+        env.storage().persistent().set(&index_key, &total_commits);
+    }
+    env.storage()
+        .persistent()
+        .set(&total_commits_key, &(total_commits + 1));
+    ttl::extend(env, &total_commits_key);
+}
+
+/// Hash two Merkle nodes together, the smallest one first.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `hash_function` - The hash function of the tree
+/// * `left` - A node
+/// * `right` - Its sibling
+///
+/// # Returns
+/// * `BytesN<32>` - The parent node
+fn merkle_node(
+    env: &Env,
+    hash_function: &types::MerkleHash,
+    left: &BytesN<32>,
+    right: &BytesN<32>,
+) -> BytesN<32> {
+    let (first, second) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    let mut node_builder = Bytes::from_array(env, &first.to_array());
+    node_builder.append(&Bytes::from_array(env, &second.to_array()));
+
+    match hash_function {
+        types::MerkleHash::Keccak256 => env.crypto().keccak256(&node_builder).into(),
+        types::MerkleHash::Sha256 => env.crypto().sha256(&node_builder).into(),
+    }
+}
+
+/// Copy a version and check that it is valid semver.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `version` - The version to validate
+///
+/// # Returns
+/// * `VersionBuf` - The bytes of the version
+///
+/// # Panics
+/// * If the version is not a valid semver version
+pub fn parse_version(env: &Env, version: &String) -> VersionBuf {
+    match VersionBuf::from_string(version) {
+        Some(version_buf) if Version::parse(version_buf.as_slice()).is_some() => version_buf,
+        _ => panic_with_error!(env, &errors::ContractErrors::InvalidVersion),
+    }
+}
+
+/// Version without build metadata, used to key releases.
+fn canonical_version(env: &Env, version_buf: &VersionBuf) -> String {
+    String::from_bytes(env, semver::canonical(version_buf.as_slice()))
 }
 
 /// Register a Soroban Domain: https://sorobandomains.org
@@ -11,6 +11,8 @@ pub enum ContractErrors {
     UnauthorizedSigner = 100,
     WrongVoter = 101,
     MaintainerNotDomainOwner = 102,
+    CommitApprovalRequired = 103,
+    QuorumNotReached = 104,
 
     // Validation (200-299)
     InvalidKey = 200,
@@ -24,18 +26,52 @@ pub enum ContractErrors {
     BadCommitment = 208,
     VoterWeight = 209,
     VoteLimitExceeded = 210,
+    ParentHashMismatch = 211,
+    InvalidVersion = 212,
+    ReleaseAlreadyExist = 213,
+    HashAlreadyYanked = 214,
+    TooManyBranches = 215,
+    InvalidBranch = 216,
+    InvalidThreshold = 217,
+    TooManyPendingCommits = 218,
+    InvalidHashLength = 219,
+    InvalidHashEncoding = 220,
+    ManifestAlreadyExist = 221,
+    AlreadyAttested = 222,
+    TooManyAttestations = 223,
+    InvalidMaintainersChange = 224,
+    InvalidSubProject = 225,
+    SubProjectCycle = 226,
+    TooManyDependencies = 227,
+    InvalidMetadata = 228,
+    InvalidCid = 229,
+    InvalidDomainLength = 230,
+    InvalidDomainCharacter = 231,
+    ReservedDomain = 232,
 
     // State (300-399)
     NoHashFound = 300,
     NoProposalorPageFound = 301,
     NoProjectPageFound = 302,
     NoAnonymousVotingConfig = 303,
+    NoReleaseFound = 304,
+    NoAdvisoryFound = 305,
+    NoManifestFound = 306,
+    UnknownDelegate = 307,
+    NoMaintainersChangeFound = 308,
+    MaintainersChangePending = 309,
+    ProjectReadOnly = 310,
+    NoTransferFound = 311,
+    NoParentConsent = 312,
+    NoDependencyFound = 313,
+    NoApprovalFound = 314,
 
     // Execution/Timing (400-499)
     AlreadyVoted = 400,
     ProposalVotingTime = 401,
     ProposalActive = 402,
     OutcomeError = 403,
+    InvalidExpiry = 404,
 
     // Voting/Cryptographic (500-599)
     TallySeedError = 500,
@@ -46,4 +82,5 @@ pub enum ContractErrors {
     UpgradeError = 601,
     ContractValidation = 602,
     CollateralError = 603,
+    MigrationPending = 604,
 }
@@ -2,6 +2,8 @@
 
 use soroban_sdk::{Address, Bytes, BytesN, String, Vec, contractevent};
 
+use crate::types::ProjectAction;
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectRegistered {
@@ -11,6 +13,23 @@ pub struct ProjectRegistered {
     pub maintainer: Address,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectForked {
+    #[topic]
+    pub project_key: Bytes,
+    pub upstream_key: Bytes,
+    pub commit_hash: Option<String>,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectConfigUpdated {
@@ -27,6 +46,249 @@ pub struct Commit {
     pub hash: String,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitApproved {
+    #[topic]
+    pub project_key: Bytes,
+    pub branch: String,
+    pub hash: String,
+    pub maintainer: Address,
+    pub approvals_count: u32,
+    pub threshold_reached: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BranchCommit {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub branch: String,
+    pub hash: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BranchDeleted {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub branch: String,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryRewritten {
+    #[topic]
+    pub project_key: Bytes,
+    pub previous_hash: Option<String>,
+    pub hash: String,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectActionApproved {
+    #[topic]
+    pub project_key: Bytes,
+    pub action: ProjectAction,
+    pub maintainer: Address,
+    pub approvals_count: u32,
+    pub quorum_reached: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectArchived {
+    #[topic]
+    pub project_key: Bytes,
+    pub caller: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectDeregistered {
+    #[topic]
+    pub project_key: Bytes,
+    pub caller: Address,
+    pub domain_released: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectRenamed {
+    #[topic]
+    pub project_key: Bytes,
+    pub previous_name: String,
+    pub name: String,
+    pub alias: Bytes,
+    pub caller: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectTransferOffered {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainers: Vec<Address>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectTransferCancelled {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectTransferred {
+    #[topic]
+    pub project_key: Bytes,
+    pub previous_maintainers: Vec<Address>,
+    pub maintainers: Vec<Address>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintainersChangeProposed {
+    #[topic]
+    pub project_key: Bytes,
+    pub add: Vec<Address>,
+    pub remove: Vec<Address>,
+    pub executable_at: u64,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintainersChangeApproved {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub approvals_count: u32,
+    pub quorum_reached: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintainersChangeCancelled {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintainerAdded {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub executor: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintainerRemoved {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub executor: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateAdded {
+    #[topic]
+    pub project_key: Bytes,
+    pub delegate: Address,
+    pub branches: Vec<String>,
+    pub expires_at: u64,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateRevoked {
+    #[topic]
+    pub project_key: Bytes,
+    pub delegate: Address,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseTagged {
+    #[topic]
+    pub project_key: Bytes,
+    pub version: String,
+    pub commit_hash: String,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManifestPublished {
+    #[topic]
+    pub project_key: Bytes,
+    pub commit_hash: String,
+    pub root: BytesN<32>,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashYanked {
+    #[topic]
+    pub project_key: Bytes,
+    pub hash: String,
+    pub reason: String,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdvisoryPublished {
+    #[topic]
+    pub project_key: Bytes,
+    pub advisory_id: u32,
+    pub affected: String,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DependencyDeclared {
+    #[topic]
+    pub project_key: Bytes,
+    pub dependency_key: Bytes,
+    pub requirement: String,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DependencyRemoved {
+    #[topic]
+    pub project_key: Bytes,
+    pub dependency_key: Bytes,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArtifactAttested {
+    #[topic]
+    pub project_key: Bytes,
+    pub commit_hash: String,
+    pub digest: BytesN<32>,
+    pub attester: Address,
+    pub verified: bool,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MemberAdded {
@@ -99,12 +361,22 @@ pub struct ContractUpdated {
     pub wasm_hash: Option<BytesN<32>>,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NameReserved {
+    #[topic]
+    pub name: String,
+    pub reserved: bool,
+    pub admin: Address,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UpgradeProposed {
     pub admin: Address,
     pub wasm_hash: Bytes,
     pub executable_at: u64,
+    pub expires_at: u64,
 }
 
 #[contractevent]
@@ -115,6 +387,13 @@ pub struct UpgradeApproved {
     pub threshold_reached: bool,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeApprovalRevoked {
+    pub admin: Address,
+    pub approvals_count: u32,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UpgradeStatus {
@@ -123,6 +402,13 @@ pub struct UpgradeStatus {
     pub status: String,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub admin: Address,
+    pub schema_version: u32,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SubProjectsUpdated {
@@ -130,3 +416,12 @@ pub struct SubProjectsUpdated {
     pub project_key: Bytes,
     pub sub_projects: Vec<Bytes>,
 }
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParentAccepted {
+    #[topic]
+    pub project_key: Bytes,
+    pub parent_key: Bytes,
+    pub maintainer: Address,
+}
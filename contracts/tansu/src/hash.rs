@@ -0,0 +1,95 @@
+//! Commit hash validation and binary encoding.
+//!
+//! Hashes enter the contract as hex strings and are validated against the
+//! algorithm declared by the project. They are stored as raw bytes: 20 bytes
+//! for SHA-1 based hashes and 32 bytes for SHA-256 based ones.
+//!
+//! Raw hashes are `Bytes` rather than `BytesN<20>` or `BytesN<32>`: projects
+//! declaring `Any` or `Fossil` mix both lengths, and the hash is part of
+//! storage keys such as `CommitIndex` which need a single type. Both types
+//! are stored as the same `ScBytes` value, so the fixed-size types would not
+//! save any storage; the length is enforced by `decode` instead.
+
+use soroban_sdk::{Bytes, Env, String, panic_with_error};
+
+use crate::errors::ContractErrors;
+use crate::types::HashAlgorithm;
+
+const SHA1_HEX_LENGTH: u32 = 40;
+const SHA256_HEX_LENGTH: u32 = 64;
+const MAX_HEX_LENGTH: usize = SHA256_HEX_LENGTH as usize;
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Decode a hex encoded hash, checking it against the hash algorithm.
+///
+/// Without a declared algorithm, both SHA-1 and SHA-256 lengths are
+/// accepted. Upper and lower case hex digits are accepted.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `hash` - The hex encoded hash
+/// * `algorithm` - The hash algorithm declared by the project
+///
+/// # Returns
+/// * `Result<Bytes, ContractErrors>` - The raw hash or why it is invalid
+pub fn decode(
+    env: &Env,
+    hash: &String,
+    algorithm: &HashAlgorithm,
+) -> Result<Bytes, ContractErrors> {
+    let len = hash.len();
+    let valid_length = match algorithm {
+        HashAlgorithm::GitSha1 | HashAlgorithm::Mercurial => len == SHA1_HEX_LENGTH,
+        HashAlgorithm::GitSha256 | HashAlgorithm::Sha256 => len == SHA256_HEX_LENGTH,
+        // Fossil uses SHA-1 or SHA3-256 depending on the repository
+        HashAlgorithm::Fossil | HashAlgorithm::Any => {
+            len == SHA1_HEX_LENGTH || len == SHA256_HEX_LENGTH
+        }
+    };
+    if !valid_length {
+        return Err(ContractErrors::InvalidHashLength);
+    }
+
+    let mut hex = [0u8; MAX_HEX_LENGTH];
+    let hex = &mut hex[..len as usize];
+    hash.copy_into_slice(hex);
+
+    let mut raw = [0u8; MAX_HEX_LENGTH / 2];
+    for (byte, pair) in raw.iter_mut().zip(hex.chunks(2)) {
+        match (hex_value(pair[0]), hex_value(pair[1])) {
+            (Some(high), Some(low)) => *byte = (high << 4) | low,
+            _ => return Err(ContractErrors::InvalidHashEncoding),
+        }
+    }
+
+    Ok(Bytes::from_slice(env, &raw[..len as usize / 2]))
+}
+
+/// Decode a hex encoded hash, see `decode`.
+///
+/// # Panics
+/// * If the length does not match the hash algorithm
+/// * If the hash is not hex encoded
+pub fn parse(env: &Env, hash: &String, algorithm: &HashAlgorithm) -> Bytes {
+    decode(env, hash, algorithm).unwrap_or_else(|error| panic_with_error!(env, error))
+}
+
+/// Encode a raw hash as a lower case hex string.
+pub fn to_hex(env: &Env, hash: &Bytes) -> String {
+    let mut hex = [0u8; MAX_HEX_LENGTH];
+    let len = (hash.len() as usize * 2).min(MAX_HEX_LENGTH);
+    for (pair, byte) in hex[..len].chunks_mut(2).zip(hash.iter()) {
+        pair[0] = HEX_DIGITS[(byte >> 4) as usize];
+        pair[1] = HEX_DIGITS[(byte & 0x0f) as usize];
+    }
+    String::from_bytes(env, &hex[..len])
+}
+
+fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
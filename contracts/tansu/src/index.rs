@@ -0,0 +1,251 @@
+//! Paginated lists of project keys.
+//!
+//! The projects listing and the reverse indexes, from an address to its
+//! projects or from a project to its dependents and forks, are stored as pages of
+//! project keys along with their number of entries. Removing an entry moves the last one in its place so that pages
+//! stay full. The projects listing also stores the position of each key, so
+//! that it is found without walking the pages.
+
+use soroban_sdk::{Address, Bytes, Env, IntoVal, Val, Vec};
+
+use crate::types;
+
+/// Maximum number of project keys per page.
+pub const MAX_KEYS_PER_PAGE: u32 = 10;
+
+/// A paginated list of project keys.
+pub enum Index {
+    Projects,            // every listed project
+    Maintainer(Address), // projects maintained by an address
+    Member(Address),     // projects where an address holds badges
+    Dependents(Bytes),   // projects depending on a project
+    Forks(Bytes),        // forks of a project
+}
+
+impl Index {
+    fn page_key(&self, env: &Env, page: u32) -> Val {
+        match self {
+            Index::Projects => types::ProjectKey::ProjectKeys(page).into_val(env),
+            Index::Maintainer(address) => {
+                types::DataKey::MaintainerProjects(address.clone(), page).into_val(env)
+            }
+            Index::Member(address) => {
+                types::DataKey::MemberProjects(address.clone(), page).into_val(env)
+            }
+            Index::Dependents(project_key) => {
+                types::ProjectKey::Dependents(project_key.clone(), page).into_val(env)
+            }
+            Index::Forks(project_key) => {
+                types::ProjectKey::Forks(project_key.clone(), page).into_val(env)
+            }
+        }
+    }
+
+    fn total_key(&self, env: &Env) -> Val {
+        match self {
+            Index::Projects => types::ProjectKey::TotalProjects.into_val(env),
+            Index::Maintainer(address) => {
+                types::DataKey::TotalMaintainerProjects(address.clone()).into_val(env)
+            }
+            Index::Member(address) => {
+                types::DataKey::TotalMemberProjects(address.clone()).into_val(env)
+            }
+            Index::Dependents(project_key) => {
+                types::ProjectKey::TotalDependents(project_key.clone()).into_val(env)
+            }
+            Index::Forks(project_key) => {
+                types::ProjectKey::TotalForks(project_key.clone()).into_val(env)
+            }
+        }
+    }
+
+    fn position_key(&self, project_key: &Bytes) -> Option<types::ProjectKey> {
+        match self {
+            Index::Projects => Some(types::ProjectKey::Listed(project_key.clone())),
+            _ => None,
+        }
+    }
+}
+
+/// Get the number of project keys of an index.
+pub fn total(env: &Env, index: &Index) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&index.total_key(env))
+        .unwrap_or(0)
+}
+
+/// Get a page of an index, empty if the page does not exist.
+pub fn page(env: &Env, index: &Index, page: u32) -> Vec<Bytes> {
+    env.storage()
+        .persistent()
+        .get(&index.page_key(env, page))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Get the project keys of an index from a position.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `index` - The index
+/// * `start` - The position of the first key
+/// * `count` - The maximum number of keys
+///
+/// # Returns
+/// * `Vec<Bytes>` - The keys, fewer than `count` at the end of the index
+pub fn range(env: &Env, index: &Index, start: u32, count: u32) -> Vec<Bytes> {
+    let end = total(env, index).min(start.saturating_add(count));
+    let mut project_keys = Vec::new(env);
+    let mut position = start;
+    while position < end {
+        let keys = page(env, index, position / MAX_KEYS_PER_PAGE);
+        let offset = position % MAX_KEYS_PER_PAGE;
+        if offset >= keys.len() {
+            break;
+        }
+        for key in keys.slice(offset..).iter().take((end - position) as usize) {
+            project_keys.push_back(key);
+            position += 1;
+        }
+    }
+    project_keys
+}
+
+/// Append a project key to an index.
+pub fn push(env: &Env, index: &Index, project_key: &Bytes) {
+    let total = total(env, index);
+    let last_page = total / MAX_KEYS_PER_PAGE;
+
+    let mut project_keys = page(env, index, last_page);
+    project_keys.push_back(project_key.clone());
+    set_page(env, index, last_page, &project_keys);
+    set_position(env, index, project_key, total);
+
+    env.storage()
+        .persistent()
+        .set(&index.total_key(env), &(total + 1));
+}
+
+/// Append a project key to an index if it is not there yet.
+pub fn insert(env: &Env, index: &Index, project_key: &Bytes) {
+    if find(env, index, project_key).is_none() {
+        push(env, index, project_key);
+    }
+}
+
+/// Remove a project key from an index, missing keys are ignored.
+///
+/// Note: except for the projects listing, the cost of this call grows with
+/// the size of the index.
+pub fn remove(env: &Env, index: &Index, project_key: &Bytes) {
+    if let Some((page_, position)) = find(env, index, project_key) {
+        remove_at(env, index, page_, position);
+    }
+}
+
+/// Remove the project key at a position of an index, the last key of the
+/// index takes its place.
+pub fn remove_at(env: &Env, index: &Index, page_: u32, position: u32) {
+    let total = total(env, index);
+    let last_page = (total - 1) / MAX_KEYS_PER_PAGE;
+
+    let mut project_keys = page(env, index, page_);
+    let removed_key = project_keys.get_unchecked(position);
+    let last_key = if page_ == last_page {
+        let last_key = project_keys.pop_back_unchecked();
+        if position < project_keys.len() {
+            project_keys.set(position, last_key.clone());
+        }
+        last_key
+    } else {
+        let mut last_keys = page(env, index, last_page);
+        let last_key = last_keys.pop_back_unchecked();
+        project_keys.set(position, last_key.clone());
+        set_page(env, index, last_page, &last_keys);
+        last_key
+    };
+    set_page(env, index, page_, &project_keys);
+
+    // a duplicate keeps the position of the key it duplicates
+    let removed_at = page_ * MAX_KEYS_PER_PAGE + position;
+    if let Some(position_key) = index.position_key(&removed_key)
+        && self::position(env, index, &removed_key) == Some(removed_at)
+    {
+        env.storage().persistent().remove(&position_key);
+    }
+    if removed_at < total - 1 && self::position(env, index, &last_key) == Some(total - 1) {
+        set_position(env, index, &last_key, removed_at);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&index.total_key(env), &(total - 1));
+}
+
+/// Update the maintainer indexes after the maintainers of a project changed.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `previous` - The former maintainers, empty for a new project
+/// * `maintainers` - The current maintainers
+pub fn set_maintainers(
+    env: &Env,
+    project_key: &Bytes,
+    previous: &Vec<Address>,
+    maintainers: &Vec<Address>,
+) {
+    for maintainer in previous.iter() {
+        if !maintainers.contains(&maintainer) {
+            remove(env, &Index::Maintainer(maintainer), project_key);
+        }
+    }
+    for maintainer in maintainers.iter() {
+        if !previous.contains(&maintainer) {
+            insert(env, &Index::Maintainer(maintainer), project_key);
+        }
+    }
+}
+
+/// Find the page and the position of a project key in an index, its first
+/// occurrence unless the index stores positions.
+pub fn find(env: &Env, index: &Index, project_key: &Bytes) -> Option<(u32, u32)> {
+    if index.position_key(project_key).is_some() {
+        return position(env, index, project_key)
+            .map(|position| (position / MAX_KEYS_PER_PAGE, position % MAX_KEYS_PER_PAGE));
+    }
+    let total = total(env, index);
+    if total == 0 {
+        return None;
+    }
+    for page_ in 0..=(total - 1) / MAX_KEYS_PER_PAGE {
+        if let Some(position) = page(env, index, page_).first_index_of(project_key) {
+            return Some((page_, position));
+        }
+    }
+    None
+}
+
+/// Get the stored position of a project key in an index, None if the key is
+/// not listed or if the index does not store positions.
+pub fn position(env: &Env, index: &Index, project_key: &Bytes) -> Option<u32> {
+    let position_key = index.position_key(project_key)?;
+    env.storage().persistent().get(&position_key)
+}
+
+/// Store the position of a project key, if the index stores positions.
+pub fn set_position(env: &Env, index: &Index, project_key: &Bytes, position: u32) {
+    if let Some(position_key) = index.position_key(project_key) {
+        env.storage().persistent().set(&position_key, &position);
+    }
+}
+
+/// Store a page of an index, removing empty pages.
+fn set_page(env: &Env, index: &Index, page: u32, project_keys: &Vec<Bytes>) {
+    let page_key = index.page_key(env, page);
+    if project_keys.is_empty() {
+        env.storage().persistent().remove(&page_key);
+    } else {
+        env.storage().persistent().set(&page_key, project_keys);
+    }
+}
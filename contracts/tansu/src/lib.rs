@@ -7,15 +7,22 @@ mod domain_contract {
     soroban_sdk::contractimport!(file = "../domain_current.wasm");
 }
 
+mod cid;
 mod contract_dao;
 mod contract_membership;
 mod contract_migration;
+mod contract_security;
 mod contract_tansu;
 mod contract_versioning;
 mod errors;
 mod events;
+mod hash;
+mod index;
+mod name;
+mod semver;
 #[cfg(test)]
 mod tests;
+mod ttl;
 mod types;
 
 contractmeta!(key = "Description", val = "Tansu - Soroban Versioning");
@@ -36,15 +43,22 @@ pub trait TansuTrait {
 
     fn set_collateral_contract(env: Env, admin: Address, collateral_contract: types::Contract);
 
+    fn set_reserved_name(env: Env, admin: Address, name: String, reserved: bool);
+
+    fn is_name_reserved(env: Env, name: String) -> bool;
+
     fn propose_upgrade(
         env: Env,
         caller: Address,
         new_wasm_hash: BytesN<32>,
         new_admins_config: Option<types::AdminsConfig>,
+        migration: Option<types::MigrationCall>,
     );
 
     fn approve_upgrade(env: Env, signer: Address);
 
+    fn revoke_upgrade_approval(env: Env, signer: Address);
+
     fn finalize_upgrade(env: Env, executor: Address, accept: bool);
 
     fn get_upgrade_proposal(env: Env) -> types::UpgradeProposal;
@@ -67,6 +81,8 @@ pub trait MembershipTrait {
 
     fn get_badges(env: Env, key: Bytes) -> types::Badges;
 
+    fn get_projects_by_member(env: Env, member: Address, page: u32) -> Vec<Bytes>;
+
     fn get_max_weight(env: Env, key: Bytes, member_address: Address) -> u32;
 }
 
@@ -80,25 +96,191 @@ pub trait VersioningTrait {
         ipfs: String,
     ) -> Bytes;
 
-    fn update_config(
+    fn register_fork(
         env: Env,
         maintainer: Address,
-        key: Bytes,
+        name: String,
+        upstream_key: Bytes,
         maintainers: Vec<Address>,
         url: String,
+        ipfs: String,
+    ) -> Bytes;
+
+    fn get_forks(env: Env, upstream_key: Bytes, page: u32) -> Vec<Bytes>;
+
+    fn update_config(env: Env, maintainer: Address, key: Bytes, url: String, hash: String);
+
+    fn set_metadata(env: Env, maintainer: Address, project_key: Bytes, metadata: types::Metadata);
+
+    fn propose_maintainers_change(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        add: Vec<Address>,
+        remove: Vec<Address>,
+        quorum: Option<u32>,
+    );
+
+    fn approve_maintainers_change(env: Env, maintainer: Address, project_key: Bytes);
+
+    fn finalize_maintainers_change(env: Env, maintainer: Address, project_key: Bytes, accept: bool);
+
+    fn get_maintainers_change(env: Env, project_key: Bytes) -> types::MaintainersChange;
+
+    fn get_maintainers_quorum(env: Env, project_key: Bytes) -> u32;
+
+    fn archive_project(env: Env, caller: Address, project_key: Bytes);
+
+    fn deregister_project(env: Env, caller: Address, project_key: Bytes, release_domain: bool);
+
+    fn get_project_status(env: Env, project_key: Bytes) -> types::ProjectStatus;
+
+    fn rename_project(env: Env, maintainer: Address, project_key: Bytes, name: String) -> Bytes;
+
+    fn resolve_project_key(env: Env, project_key: Bytes) -> Bytes;
+
+    fn transfer_project(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        maintainers: Vec<Address>,
+    );
+
+    fn accept_transfer(env: Env, project_key: Bytes);
+
+    fn cancel_transfer(env: Env, maintainer: Address, project_key: Bytes);
+
+    fn get_transfer_offer(env: Env, project_key: Bytes) -> Vec<Address>;
+
+    fn commit(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
         hash: String,
+        parent_hash: Option<String>,
     );
 
-    fn commit(env: Env, maintainer: Address, project_key: Bytes, hash: String);
+    fn force_commit(env: Env, maintainer: Address, project_key: Bytes, hash: String);
+
+    fn approve_commit(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        branch: String,
+        hash: String,
+    );
+
+    fn get_pending_commits(env: Env, project_key: Bytes) -> Vec<types::PendingCommit>;
+
+    fn set_hash_algorithm(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        hash_algorithm: types::HashAlgorithm,
+    );
+
+    fn set_commit_threshold(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        threshold: u32,
+        approval_window: u64,
+    );
+
+    fn add_delegate(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        delegate: Address,
+        branches: Vec<String>,
+        expires_at: u64,
+    );
+
+    fn revoke_delegate(env: Env, maintainer: Address, project_key: Bytes, delegate: Address);
+
+    fn get_delegate(env: Env, project_key: Bytes, delegate: Address) -> Option<types::Delegate>;
 
     fn get_commit(env: Env, project_key: Bytes) -> String;
 
+    fn commit_branch(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        branch: String,
+        hash: String,
+    );
+
+    fn get_branch_head(env: Env, project_key: Bytes, branch: String) -> String;
+
+    fn list_branches(env: Env, project_key: Bytes) -> Vec<String>;
+
+    fn delete_branch(env: Env, maintainer: Address, project_key: Bytes, branch: String);
+
+    fn get_commit_history(env: Env, project_key: Bytes, page: u32) -> Vec<types::CommitRecord>;
+
+    fn get_commit_at(env: Env, project_key: Bytes, index: u32) -> types::CommitRecord;
+
+    fn tag_release(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        version: String,
+        commit_hash: String,
+        ipfs_notes: String,
+    );
+
+    fn get_release(env: Env, project_key: Bytes, version: String) -> types::Release;
+
+    fn list_releases(env: Env, project_key: Bytes, page: u32) -> Vec<types::Release>;
+
+    fn latest_release(env: Env, project_key: Bytes) -> types::Release;
+
+    fn publish_manifest(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        commit_hash: String,
+        root: BytesN<32>,
+        hash_function: types::MerkleHash,
+    );
+
+    fn get_manifest(env: Env, project_key: Bytes, commit_hash: String) -> types::Manifest;
+
+    fn verify_inclusion(
+        env: Env,
+        project_key: Bytes,
+        commit_hash: String,
+        leaf: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> bool;
+
     fn get_project(env: Env, project_key: Bytes) -> types::Project;
 
+    fn get_project_summary(env: Env, project_key: Bytes) -> types::ProjectSummary;
+
+    fn extend_project_ttl(env: Env, project_key: Bytes, cursor: u32, limit: u32) -> Option<u32>;
+
+    fn get_ttl_report(env: Env, project_key: Bytes) -> types::TtlReport;
+
     fn get_projects(env: Env, page: u32) -> Vec<types::Project>;
 
+    fn list_projects(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+        filter: types::ProjectFilter,
+    ) -> types::ProjectList;
+
+    fn get_projects_by_maintainer(env: Env, maintainer: Address, page: u32) -> Vec<Bytes>;
+
     fn get_sub_projects(env: Env, project_key: Bytes) -> Vec<Bytes>;
 
+    fn accept_parent(env: Env, maintainer: Address, project_key: Bytes, parent_key: Bytes);
+
+    fn get_parent(env: Env, project_key: Bytes) -> Option<Bytes>;
+
+    fn get_project_tree(env: Env, project_key: Bytes, depth: u32) -> Vec<types::ProjectNode>;
+
     fn set_sub_projects(
         env: Env,
         maintainer: Address,
@@ -107,6 +289,56 @@ pub trait VersioningTrait {
     );
 }
 
+pub trait SecurityTrait {
+    fn yank(env: Env, maintainer: Address, project_key: Bytes, hash: String, reason: String);
+
+    fn get_yank(env: Env, project_key: Bytes, hash: String) -> Option<types::Yank>;
+
+    fn publish_advisory(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        severity: types::Severity,
+        affected: String,
+        fixed_version: Option<String>,
+        ipfs: String,
+    ) -> u32;
+
+    fn get_advisory(env: Env, project_key: Bytes, advisory_id: u32) -> types::Advisory;
+
+    fn is_version_affected(env: Env, project_key: Bytes, version_or_hash: String) -> Vec<u32>;
+
+    fn attest_build(
+        env: Env,
+        attester: Address,
+        project_key: Bytes,
+        commit_hash: String,
+        digest: BytesN<32>,
+    );
+
+    fn set_attestation_threshold(env: Env, maintainer: Address, project_key: Bytes, threshold: u32);
+
+    fn get_build_verification(
+        env: Env,
+        project_key: Bytes,
+        commit_hash: String,
+    ) -> types::BuildVerification;
+
+    fn add_dependency(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        dependency_key: Bytes,
+        requirement: String,
+    );
+
+    fn remove_dependency(env: Env, maintainer: Address, project_key: Bytes, dependency_key: Bytes);
+
+    fn get_dependencies(env: Env, project_key: Bytes) -> Vec<types::Dependency>;
+
+    fn get_dependents(env: Env, project_key: Bytes, page: u32) -> Vec<Bytes>;
+}
+
 pub trait DaoTrait {
     fn anonymous_voting_setup(
         env: Env,
@@ -165,23 +397,121 @@ pub trait DaoTrait {
 
 pub trait MigrationTrait {
     fn add_projects_to_pagination(env: Env, admin: Address, names: Vec<String>);
+
+    fn migrate(env: Env, admin: Address, limit: u32) -> types::MigrationStatus;
+
+    fn get_migration_status(env: Env) -> types::MigrationStatus;
+
+    fn verify_project_index(env: Env, start_page: u32, pages: u32) -> types::IndexReport;
 }
 
 fn auth_maintainers(env: &Env, maintainer: &Address, project_key: &Bytes) -> types::Project {
-    maintainer.require_auth();
+    auth_role(env, maintainer, project_key, &types::Role::Maintainer)
+}
+
+/// Same as `auth_maintainers`, but also allowed on read-only projects.
+fn auth_maintainers_any_status(
+    env: &Env,
+    maintainer: &Address,
+    project_key: &Bytes,
+) -> types::Project {
+    authenticate(env, maintainer, project_key, &types::Role::Maintainer)
+}
+
+/// Authenticate an address acting on a project with a given role.
+///
+/// Maintainers have every role. Delegates only have the `Committer` role,
+/// on the branches they are scoped to and until they expire. A delegate
+/// added by a former maintainer is no longer valid.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `signer` - The address to authenticate
+/// * `project_key` - The project key identifier
+/// * `role` - The role needed for the action
+///
+/// # Returns
+/// * `types::Project` - The project
+///
+/// # Panics
+/// * If the project doesn't exist
+/// * If the signer does not have the role
+/// * If the project is archived or deregistered
+fn auth_role(
+    env: &Env,
+    signer: &Address,
+    project_key: &Bytes,
+    role: &types::Role,
+) -> types::Project {
+    let project = authenticate(env, signer, project_key, role);
+    require_active(env, project_key);
+    project
+}
+
+/// Check that a project is neither archived nor deregistered.
+///
+/// # Panics
+/// * If the project is archived or deregistered
+fn require_active(env: &Env, project_key: &Bytes) {
+    let status = env
+        .storage()
+        .persistent()
+        .get(&types::ProjectKey::Status(project_key.clone()))
+        .unwrap_or(types::ProjectStatus::Active);
+    if status != types::ProjectStatus::Active {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectReadOnly);
+    }
+}
+
+/// Resolve the alias of a renamed project.
+///
+/// A renamed project keeps the key it was registered with, the key of its
+/// new name is an alias.
+///
+/// # Returns
+/// * `Bytes` - The registered key of the project, or the given key
+fn resolve_key(env: &Env, project_key: &Bytes) -> Bytes {
+    env.storage()
+        .persistent()
+        .get(&types::ProjectKey::Alias(project_key.clone()))
+        .unwrap_or(project_key.clone())
+}
+
+fn authenticate(
+    env: &Env,
+    signer: &Address,
+    project_key: &Bytes,
+    role: &types::Role,
+) -> types::Project {
+    signer.require_auth();
     let project_key_ = types::ProjectKey::Key(project_key.clone());
-    if let Some(project) = env
+    let Some(project) = env
         .storage()
         .persistent()
         .get::<types::ProjectKey, types::Project>(&project_key_)
-    {
-        if !project.maintainers.contains(maintainer) {
-            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
-        }
-        project
-    } else {
+    else {
         panic_with_error!(&env, &errors::ContractErrors::InvalidKey)
+    };
+    ttl::extend(env, &project_key_);
+    ttl::extend_instance(env);
+
+    if project.maintainers.contains(signer) {
+        return project;
+    }
+    let delegate_key = types::ProjectKey::Delegate(project_key.clone(), signer.clone());
+    if let types::Role::Committer(branch) = role
+        && let Some(delegate) = env
+            .storage()
+            .persistent()
+            .get::<types::ProjectKey, types::Delegate>(&delegate_key)
+        && delegate.expires_at > env.ledger().timestamp()
+        && project.maintainers.contains(&delegate.maintainer)
+        && (delegate.branches.is_empty() || delegate.branches.contains(branch))
+    {
+        ttl::extend(env, &delegate_key);
+        return project;
     }
+    panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner)
 }
 
 /// Retrieve a contract address and WASM hash.
@@ -0,0 +1,53 @@
+//! Project name normalization.
+//!
+//! Project names are Soroban domains: at most 15 lowercase ASCII letters.
+//! Upper case letters are folded to lower case before the name is hashed into
+//! the project key, so that `Tansu` and `tansu` are the same project. Any other
+//! character, including digits, hyphens and non-ASCII look-alikes, is rejected.
+
+use soroban_sdk::{Bytes, Env, String, panic_with_error};
+
+use crate::errors::ContractErrors;
+
+/// Maximum length of a project name.
+pub const MAX_NAME_LENGTH: u32 = 15;
+
+/// Normalize a project name.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `name` - The project name
+///
+/// # Returns
+/// * `Result<String, ContractErrors>` - The canonical name, `InvalidDomainLength`
+///   or `InvalidDomainCharacter`
+pub fn normalize(env: &Env, name: &String) -> Result<String, ContractErrors> {
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+        return Err(ContractErrors::InvalidDomainLength);
+    }
+    let mut buf = [0u8; MAX_NAME_LENGTH as usize];
+    let buf = &mut buf[..name.len() as usize];
+    name.copy_into_slice(buf);
+
+    for c in buf.iter_mut() {
+        if !c.is_ascii_alphabetic() {
+            return Err(ContractErrors::InvalidDomainCharacter);
+        }
+        c.make_ascii_lowercase();
+    }
+    Ok(String::from_bytes(env, buf))
+}
+
+/// Get the project key of a normalized name, its keccak256 hash.
+pub fn key(env: &Env, name: &String) -> Bytes {
+    env.crypto().keccak256(&name.to_bytes()).into()
+}
+
+/// Normalize a project name, see `normalize`.
+///
+/// # Panics
+/// * If the name is empty or longer than 15 characters
+/// * If the name contains anything else than ASCII letters
+pub fn parse(env: &Env, name: &String) -> String {
+    normalize(env, name).unwrap_or_else(|error| panic_with_error!(env, error))
+}
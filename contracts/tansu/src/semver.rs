@@ -0,0 +1,248 @@
+//! Minimal Semantic Versioning 2.0.0 support.
+//!
+//! Parses `MAJOR.MINOR.PATCH[-PRERELEASE][+BUILD]` versions and compares
+//! them following the precedence rules of https://semver.org. Everything
+//! works on borrowed byte slices so that no allocation is needed.
+
+use core::cmp::Ordering;
+use soroban_sdk::String;
+
+/// Maximum length of a version string, larger inputs are rejected.
+pub const MAX_VERSION_LENGTH: usize = 64;
+/// Maximum length of a version range string, larger inputs are rejected.
+pub const MAX_RANGE_LENGTH: usize = 256;
+
+pub type VersionBuf = StringBuf<MAX_VERSION_LENGTH>;
+pub type RangeBuf = StringBuf<MAX_RANGE_LENGTH>;
+
+/// Fixed size buffer holding the bytes of a Soroban `String`.
+pub struct StringBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StringBuf<N> {
+    /// Copy a Soroban `String`, `None` if it is empty or too long.
+    pub fn from_string(value: &String) -> Option<Self> {
+        let len = value.len() as usize;
+        if len == 0 || len > N {
+            return None;
+        }
+        let mut buf = [0u8; N];
+        value.copy_into_slice(&mut buf[..len]);
+        Some(Self { buf, len })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Version<'a> {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: &'a [u8],
+    pub build: &'a [u8],
+}
+
+impl<'a> Version<'a> {
+    /// Parse a version, `None` if it is not valid semver.
+    pub fn parse(input: &'a [u8]) -> Option<Self> {
+        let (rest, build) = match input.iter().position(|c| *c == b'+') {
+            Some(i) => (&input[..i], &input[i + 1..]),
+            None => (input, &input[input.len()..]),
+        };
+        let (core, pre) = match rest.iter().position(|c| *c == b'-') {
+            Some(i) => (&rest[..i], &rest[i + 1..]),
+            None => (rest, &rest[rest.len()..]),
+        };
+
+        let mut numbers = core.split(|c| *c == b'.');
+        let major = parse_numeric(numbers.next()?)?;
+        let minor = parse_numeric(numbers.next()?)?;
+        let patch = parse_numeric(numbers.next()?)?;
+        if numbers.next().is_some() {
+            return None;
+        }
+
+        if rest.len() != core.len() && !valid_identifiers(pre, true) {
+            return None;
+        }
+        if input.len() != rest.len() && !valid_identifiers(build, false) {
+            return None;
+        }
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        })
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+}
+
+/// The version without its build metadata.
+///
+/// Build metadata does not take part in precedence, so `1.0.0+a` and
+/// `1.0.0+b` share the same canonical form.
+pub fn canonical(input: &[u8]) -> &[u8] {
+    match input.iter().position(|c| *c == b'+') {
+        Some(i) => &input[..i],
+        None => input,
+    }
+}
+
+/// Check if a version satisfies a range, `None` if the range is invalid.
+///
+/// A range is a list of alternatives separated by `||`. An alternative is a
+/// space separated list of comparators which must all match. A comparator
+/// is a version prefixed by one of `<`, `<=`, `>`, `>=`, `=` (exact match
+/// when omitted), or `*` to match any version. For example:
+/// `>=1.0.0 <1.4.2 || >=2.0.0-alpha <2.1.1`.
+pub fn satisfies(range: &[u8], version: &Version) -> Option<bool> {
+    let mut matched = false;
+    for alternative in split_alternatives(range) {
+        let mut comparators = alternative
+            .split(|c| *c == b' ')
+            .filter(|comparator| !comparator.is_empty())
+            .peekable();
+        // an empty alternative is invalid
+        comparators.peek()?;
+        let mut all = true;
+        for comparator in comparators {
+            all &= compare(comparator, version)?;
+        }
+        matched |= all;
+    }
+    Some(matched)
+}
+
+/// Check that a range is valid, see `satisfies`.
+pub fn valid_range(range: &[u8]) -> bool {
+    satisfies(range, &Version::parse(b"0.0.0").unwrap()).is_some()
+}
+
+fn split_alternatives(range: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut rest = Some(range);
+    core::iter::from_fn(move || {
+        let current = rest?;
+        match current.windows(2).position(|pair| pair == b"||") {
+            Some(i) => {
+                rest = Some(&current[i + 2..]);
+                Some(&current[..i])
+            }
+            None => {
+                rest = None;
+                Some(current)
+            }
+        }
+    })
+}
+
+fn compare(comparator: &[u8], version: &Version) -> Option<bool> {
+    if comparator == b"*" {
+        return Some(true);
+    }
+    let (operator, bound) = match comparator {
+        [b'<', b'=', bound @ ..] => (b"<=".as_slice(), bound),
+        [b'>', b'=', bound @ ..] => (b">=".as_slice(), bound),
+        [b'<', bound @ ..] => (b"<".as_slice(), bound),
+        [b'>', bound @ ..] => (b">".as_slice(), bound),
+        [b'=', bound @ ..] => (b"=".as_slice(), bound),
+        bound => (b"=".as_slice(), bound),
+    };
+    let ordering = version.cmp(&Version::parse(bound)?);
+    Some(match operator {
+        b"<=" => ordering != Ordering::Greater,
+        b">=" => ordering != Ordering::Less,
+        b"<" => ordering == Ordering::Less,
+        b">" => ordering == Ordering::Greater,
+        _ => ordering == Ordering::Equal,
+    })
+}
+
+impl Ord for Version<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| compare_prerelease(self.pre, other.pre))
+    }
+}
+
+impl PartialOrd for Version<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parse a numeric identifier: digits only and no leading zero.
+fn parse_numeric(value: &[u8]) -> Option<u64> {
+    if value.is_empty() || (value.len() > 1 && value[0] == b'0') {
+        return None;
+    }
+    let mut number: u64 = 0;
+    for c in value {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        number = number.checked_mul(10)?.checked_add((c - b'0') as u64)?;
+    }
+    Some(number)
+}
+
+/// Validate dot separated pre-release or build identifiers.
+fn valid_identifiers(value: &[u8], prerelease: bool) -> bool {
+    value.split(|c| *c == b'.').all(|identifier| {
+        if identifier.is_empty()
+            || !identifier
+                .iter()
+                .all(|c| c.is_ascii_alphanumeric() || *c == b'-')
+        {
+            return false;
+        }
+        // numeric pre-release identifiers must not have leading zeros
+        !(prerelease
+            && identifier.iter().all(u8::is_ascii_digit)
+            && parse_numeric(identifier).is_none())
+    })
+}
+
+/// Compare pre-release parts, a version without pre-release has precedence.
+fn compare_prerelease(left: &[u8], right: &[u8]) -> Ordering {
+    match (left.is_empty(), right.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    let mut left_ids = left.split(|c| *c == b'.');
+    let mut right_ids = right.split(|c| *c == b'.');
+    loop {
+        match (left_ids.next(), right_ids.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) => {
+                let ordering = match (parse_numeric(l), parse_numeric(r)) {
+                    (Some(l), Some(r)) => l.cmp(&r),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => l.cmp(r),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
@@ -3,8 +3,16 @@ pub mod test_commit;
 pub mod test_cost_estimates;
 pub mod test_dao;
 pub mod test_domain;
+pub mod test_maintainers;
+pub mod test_manifest;
 pub mod test_membership;
 pub mod test_migration;
 pub mod test_pause_upgrade;
+pub mod test_project_status;
 pub mod test_register;
+pub mod test_releases;
+pub mod test_security;
+pub mod test_semver;
+pub mod test_transfer;
+pub mod test_ttl;
 pub mod test_utils;
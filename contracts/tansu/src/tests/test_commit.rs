@@ -1,8 +1,11 @@
+extern crate std;
 use super::test_utils::{create_test_data, init_contract};
 use crate::errors::ContractErrors;
-use crate::events::Commit;
-use soroban_sdk::testutils::{Address as _, Events};
-use soroban_sdk::{Address, Event, String, vec};
+use crate::events::{BranchCommit, Commit, HistoryRewritten};
+use crate::hash;
+use crate::types::{HashAlgorithm, MAINTAINERS_CHANGE_DELAY};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Bytes, Event, String, vec};
 
 #[test]
 fn commit_flow() {
@@ -10,19 +13,83 @@ fn commit_flow() {
     let id = init_contract(&setup);
 
     let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
-    setup.contract.commit(&setup.mando, &id, &hash);
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
 
     let stored = setup.contract.get_commit(&id);
     assert_eq!(stored, hash);
 }
 
+#[test]
+fn commit_history() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    // empty history
+    assert_eq!(setup.contract.get_commit_history(&id, &0).len(), 0);
+    let err = setup
+        .contract
+        .try_get_commit_at(&id, &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    // fill more than a page
+    for i in 0u32..12 {
+        setup.env.ledger().set_timestamp(1000 + i as u64);
+        setup.env.ledger().set_sequence_number(100 + i);
+        let hash = String::from_str(&setup.env, &std::format!("{i:040x}"));
+        let committer = if i % 2 == 0 {
+            &setup.mando
+        } else {
+            &setup.grogu
+        };
+        setup.contract.commit(committer, &id, &hash, &None);
+    }
+
+    let page_0 = setup.contract.get_commit_history(&id, &0);
+    assert_eq!(page_0.len(), 10);
+    let page_1 = setup.contract.get_commit_history(&id, &1);
+    assert_eq!(page_1.len(), 2);
+    assert_eq!(setup.contract.get_commit_history(&id, &2).len(), 0);
+
+    let record = setup.contract.get_commit_at(&id, &11);
+    assert_eq!(record, page_1.get(1).unwrap());
+    assert_eq!(
+        hash::to_hex(&setup.env, &record.hash),
+        String::from_str(&setup.env, &std::format!("{:040x}", 11))
+    );
+    assert_eq!(record.committer, setup.grogu);
+    assert_eq!(record.timestamp, 1011);
+    assert_eq!(record.sequence, 111);
+
+    let record = setup.contract.get_commit_at(&id, &0);
+    assert_eq!(record.committer, setup.mando);
+    assert_eq!(record.timestamp, 1000);
+
+    // head is still the latest commit
+    assert_eq!(
+        setup.contract.get_commit(&id),
+        hash::to_hex(&setup.env, &page_1.last().unwrap().hash)
+    );
+
+    // unknown project
+    let err = setup
+        .contract
+        .try_get_commit_history(&Bytes::from_slice(&setup.env, b"unknown"), &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+}
+
 #[test]
 fn commit_events() {
     let setup = create_test_data();
     let id = init_contract(&setup);
 
     let hash_commit = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
-    setup.contract.commit(&setup.mando, &id, &hash_commit);
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash_commit, &None);
 
     let event = Commit {
         project_key: id.clone(),
@@ -39,6 +106,577 @@ fn commit_events() {
     );
 }
 
+#[test]
+fn commit_parent_hash_chain() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash_1 = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let hash_2 = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let hash_3 = String::from_str(&setup.env, "e8b5a1f7a0c0d6e4c1bbd5ba2a4fb1c06a1d2c3e");
+
+    // nothing anchored yet, any parent is a mismatch
+    let err = setup
+        .contract
+        .try_commit(&setup.mando, &id, &hash_1, &Some(hash_2.clone()))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ParentHashMismatch.into());
+
+    setup.contract.commit(&setup.mando, &id, &hash_1, &None);
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash_2, &Some(hash_1.clone()));
+    assert_eq!(setup.contract.get_commit(&id), hash_2);
+
+    // stale parent
+    let err = setup
+        .contract
+        .try_commit(&setup.mando, &id, &hash_3, &Some(hash_1.clone()))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ParentHashMismatch.into());
+    assert_eq!(setup.contract.get_commit(&id), hash_2);
+}
+
+#[test]
+fn force_commit_events() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash_1 = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let hash_2 = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    setup.contract.commit(&setup.mando, &id, &hash_1, &None);
+
+    setup.contract.force_commit(&setup.grogu, &id, &hash_2);
+
+    let event = HistoryRewritten {
+        project_key: id.clone(),
+        previous_hash: Some(hash_1.clone()),
+        hash: hash_2.clone(),
+        maintainer: setup.grogu.clone(),
+    };
+    assert_eq!(
+        setup
+            .env
+            .events()
+            .all()
+            .filter_by_contract(&setup.contract_id),
+        [event.to_xdr(&setup.env, &setup.contract_id)]
+    );
+
+    assert_eq!(setup.contract.get_commit(&id), hash_2);
+    // the rewrite is recorded, not erased
+    assert_eq!(setup.contract.get_commit_history(&id, &0).len(), 2);
+    assert_eq!(
+        hash::to_hex(&setup.env, &setup.contract.get_commit_at(&id, &0).hash),
+        hash_1
+    );
+}
+
+#[test]
+fn commit_branches() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let main = String::from_str(env, "main");
+    let next = String::from_str(env, "next");
+    let release = String::from_str(env, "release-1.x");
+    let hash_1 = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let hash_2 = String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let hash_3 = String::from_str(env, "e8b5a1f7a0c0d6e4c1bbd5ba2a4fb1c06a1d2c3e");
+
+    assert_eq!(setup.contract.list_branches(&id).len(), 0);
+
+    setup
+        .contract
+        .commit_branch(&setup.mando, &id, &main, &hash_1);
+    setup
+        .contract
+        .commit_branch(&setup.mando, &id, &release, &hash_2);
+    setup
+        .contract
+        .commit_branch(&setup.grogu, &id, &next, &hash_3);
+
+    let event = BranchCommit {
+        project_key: id.clone(),
+        branch: next.clone(),
+        hash: hash_3.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+
+    // the default branch is what get_commit returns
+    assert_eq!(setup.contract.get_commit(&id), hash_1);
+    assert_eq!(setup.contract.get_branch_head(&id, &main), hash_1);
+    assert_eq!(setup.contract.get_branch_head(&id, &release), hash_2);
+    assert_eq!(setup.contract.get_branch_head(&id, &next), hash_3);
+    assert_eq!(
+        setup.contract.list_branches(&id),
+        vec![env, main.clone(), release.clone(), next.clone()]
+    );
+    assert_eq!(setup.contract.get_commit_at(&id, &1).branch, release);
+
+    setup.contract.delete_branch(&setup.grogu, &id, &release);
+    assert_eq!(
+        setup.contract.list_branches(&id),
+        vec![env, main.clone(), next.clone()]
+    );
+    let err = setup
+        .contract
+        .try_get_branch_head(&id, &release)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+    // history is kept
+    assert_eq!(
+        hash::to_hex(env, &setup.contract.get_commit_at(&id, &1).hash),
+        hash_2
+    );
+
+    let err = setup
+        .contract
+        .try_delete_branch(&setup.grogu, &id, &release)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    let err = setup
+        .contract
+        .try_delete_branch(&setup.grogu, &id, &main)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidBranch.into());
+}
+
+#[test]
+fn commit_branches_limit() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    for i in 0..19 {
+        let branch = String::from_str(env, &std::format!("feature-{i}"));
+        setup
+            .contract
+            .commit_branch(&setup.mando, &id, &branch, &hash);
+    }
+
+    let err = setup
+        .contract
+        .try_commit_branch(&setup.mando, &id, &String::from_str(env, "one-more"), &hash)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::TooManyBranches.into());
+
+    let err = setup
+        .contract
+        .try_commit_branch(&setup.mando, &id, &String::from_str(env, ""), &hash)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidBranch.into());
+}
+
+#[test]
+fn commit_threshold_approvals() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let main = String::from_str(env, "main");
+    let hash_1 = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let hash_2 = String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+
+    // threshold cannot exceed the number of maintainers
+    let err = setup
+        .contract
+        .try_set_commit_threshold(&setup.grogu, &id, &3, &3600)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidThreshold.into());
+
+    setup
+        .contract
+        .set_commit_threshold(&setup.grogu, &id, &2, &3600);
+    let config = setup.contract.get_project(&id).config;
+    assert_eq!(
+        (config.commit_threshold, config.commit_approval_window),
+        (2, 3600)
+    );
+
+    // first approval only creates a pending commit
+    setup.contract.commit(&setup.mando, &id, &hash_1, &None);
+    let err = setup.contract.try_get_commit(&id).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    let pending = setup.contract.get_pending_commits(&id);
+    assert_eq!(pending.len(), 1);
+    let pending = pending.get(0).unwrap();
+    assert_eq!(hash::to_hex(env, &pending.hash), hash_1);
+    assert_eq!(pending.approvals, vec![env, setup.mando.clone()]);
+
+    let err = setup
+        .contract
+        .try_approve_commit(&setup.mando, &id, &main, &hash_1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::AlreadyVoted.into());
+
+    let err = setup
+        .contract
+        .try_force_commit(&setup.mando, &id, &hash_1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::CommitApprovalRequired.into());
+
+    // second distinct approval anchors the commit
+    setup
+        .contract
+        .approve_commit(&setup.grogu, &id, &main, &hash_1);
+    assert_eq!(setup.contract.get_commit(&id), hash_1);
+    assert_eq!(setup.contract.get_pending_commits(&id).len(), 0);
+    assert_eq!(setup.contract.get_commit_at(&id, &0).committer, setup.grogu);
+
+    // approvals expire after the window
+    setup.contract.commit(&setup.mando, &id, &hash_2, &None);
+    assert_eq!(setup.contract.get_pending_commits(&id).len(), 1);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(env.ledger().timestamp() + 3600);
+    assert_eq!(setup.contract.get_pending_commits(&id).len(), 0);
+
+    setup
+        .contract
+        .approve_commit(&setup.grogu, &id, &main, &hash_2);
+    assert_eq!(setup.contract.get_commit(&id), hash_1);
+    assert_eq!(setup.contract.get_pending_commits(&id).len(), 1);
+
+    // cannot remove maintainers below the threshold
+    let err = setup
+        .contract
+        .try_propose_maintainers_change(
+            &setup.grogu,
+            &id,
+            &vec![env],
+            &vec![env, setup.mando.clone()],
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidThreshold.into());
+}
+
+#[test]
+fn commit_threshold_removed_approver() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+    env.ledger().set_timestamp(1000);
+
+    let main = String::from_str(env, "main");
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let kuiil = Address::generate(env);
+    let change_maintainers = |add: &Address, remove: Option<&Address>, approver: &Address| {
+        let remove = match remove {
+            Some(remove) => vec![env, remove.clone()],
+            None => vec![env],
+        };
+        setup.contract.propose_maintainers_change(
+            &setup.grogu,
+            &id,
+            &vec![env, add.clone()],
+            &remove,
+            &None,
+        );
+        setup.contract.approve_maintainers_change(approver, &id);
+        env.ledger()
+            .set_timestamp(env.ledger().timestamp() + MAINTAINERS_CHANGE_DELAY);
+        setup
+            .contract
+            .finalize_maintainers_change(&setup.grogu, &id, &true);
+    };
+
+    change_maintainers(&kuiil, None, &setup.mando);
+    setup
+        .contract
+        .set_commit_threshold(&setup.grogu, &id, &2, &(7 * 24 * 3600));
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+
+    // the approval of a removed maintainer no longer counts
+    let din = Address::generate(env);
+    change_maintainers(&din, Some(&setup.mando), &kuiil);
+    setup
+        .contract
+        .approve_commit(&setup.grogu, &id, &main, &hash);
+    let err = setup.contract.try_get_commit(&id).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    setup.contract.approve_commit(&kuiil, &id, &main, &hash);
+    assert_eq!(setup.contract.get_commit(&id), hash);
+}
+
+#[test]
+fn commit_threshold_parent_hash() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let main = String::from_str(env, "main");
+    let hash_1 = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let hash_2 = String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let hash_3 = String::from_str(env, "9ffa6c1cc3eb4a5c21b4e2b2f3a1e0f9e0cba3d1");
+
+    setup
+        .contract
+        .set_commit_threshold(&setup.grogu, &id, &2, &3600);
+    setup.contract.commit(&setup.mando, &id, &hash_1, &None);
+    setup
+        .contract
+        .approve_commit(&setup.grogu, &id, &main, &hash_1);
+
+    // two competing commits on top of the head
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash_2, &Some(hash_1.clone()));
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash_3, &Some(hash_1.clone()));
+    let pending = setup.contract.get_pending_commits(&id).get(0).unwrap();
+    assert_eq!(
+        pending
+            .parent_hash
+            .map(|parent_hash| hash::to_hex(env, &parent_hash)),
+        Some(hash_1.clone())
+    );
+
+    // the parent of a pending commit cannot be changed
+    let err = setup
+        .contract
+        .try_commit(&setup.grogu, &id, &hash_2, &Some(hash_3.clone()))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ParentHashMismatch.into());
+
+    setup
+        .contract
+        .approve_commit(&setup.grogu, &id, &main, &hash_3);
+    assert_eq!(setup.contract.get_commit(&id), hash_3);
+
+    // the head moved, the other commit would fork the history
+    let err = setup
+        .contract
+        .try_approve_commit(&setup.grogu, &id, &main, &hash_2)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ParentHashMismatch.into());
+    assert_eq!(setup.contract.get_commit(&id), hash_3);
+}
+
+#[test]
+fn commit_delegates() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+    env.ledger().set_timestamp(1000);
+
+    let ci = Address::generate(env);
+    let main = String::from_str(env, "main");
+    let dev = String::from_str(env, "dev");
+    let hash_1 = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let hash_2 = String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+
+    let err = setup
+        .contract
+        .try_commit(&ci, &id, &hash_1, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    let err = setup
+        .contract
+        .try_add_delegate(&setup.grogu, &id, &ci, &vec![env, main.clone()], &1000)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidExpiry.into());
+
+    setup
+        .contract
+        .add_delegate(&setup.grogu, &id, &ci, &vec![env, main.clone()], &2000);
+    let delegate = setup.contract.get_delegate(&id, &ci).unwrap();
+    assert_eq!(delegate.branches, vec![env, main.clone()]);
+    assert_eq!(delegate.expires_at, 2000);
+    assert_eq!(delegate.maintainer, setup.grogu);
+
+    setup.contract.commit(&ci, &id, &hash_1, &None);
+    assert_eq!(setup.contract.get_commit(&id), hash_1);
+    assert_eq!(setup.contract.get_commit_at(&id, &0).committer, ci);
+
+    // only on the allowed branches
+    let err = setup
+        .contract
+        .try_commit_branch(&ci, &id, &dev, &hash_2)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    // only commits
+    let err = setup
+        .contract
+        .try_force_commit(&ci, &id, &hash_2)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    let err = setup
+        .contract
+        .try_update_config(
+            &ci,
+            &id,
+            &String::from_str(env, "github.com/tansu"),
+            &String::from_str(
+                env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    let err = setup
+        .contract
+        .try_add_delegate(&ci, &id, &ci, &vec![env], &3000)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    // delegates propose, maintainers approve
+    setup
+        .contract
+        .set_commit_threshold(&setup.grogu, &id, &2, &3600);
+    setup.contract.commit(&ci, &id, &hash_2, &None);
+    let pending = setup.contract.get_pending_commits(&id);
+    assert_eq!(pending.len(), 1);
+    assert!(pending.get(0).unwrap().approvals.is_empty());
+    let err = setup
+        .contract
+        .try_commit(&ci, &id, &hash_2, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::AlreadyVoted.into());
+    setup
+        .contract
+        .approve_commit(&setup.mando, &id, &main, &hash_2);
+    setup
+        .contract
+        .approve_commit(&setup.grogu, &id, &main, &hash_2);
+    assert_eq!(setup.contract.get_commit(&id), hash_2);
+
+    // expired
+    env.ledger().set_timestamp(2000);
+    let err = setup
+        .contract
+        .try_commit(&ci, &id, &hash_1, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    setup.contract.revoke_delegate(&setup.mando, &id, &ci);
+    assert_eq!(setup.contract.get_delegate(&id, &ci), None);
+    let err = setup
+        .contract
+        .try_revoke_delegate(&setup.mando, &id, &ci)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnknownDelegate.into());
+}
+
+#[test]
+fn commit_hash_algorithm() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let sha1 = String::from_str(env, "6663520BD9E6EDE248FEF8157B2AF0B6B6B41046");
+    let sha256 = String::from_str(
+        env,
+        "a3f1c2d4e5b60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90",
+    );
+
+    // without a declared algorithm, both lengths are accepted
+    assert_eq!(
+        setup.contract.get_project(&id).config.hash_algorithm,
+        HashAlgorithm::Any
+    );
+    setup.contract.commit(&setup.mando, &id, &sha1, &None);
+    // hashes are stored in binary and returned as lower case hex
+    let record = setup.contract.get_commit_at(&id, &0);
+    assert_eq!(record.hash.len(), 20);
+    assert_eq!(
+        setup.contract.get_commit(&id),
+        String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046")
+    );
+    setup.contract.commit(&setup.mando, &id, &sha256, &None);
+    assert_eq!(setup.contract.get_commit_at(&id, &1).hash.len(), 32);
+    assert_eq!(setup.contract.get_commit(&id), sha256);
+
+    for hash in ["", "deadbeef", &std::format!("{:041x}", 1)] {
+        let err = setup
+            .contract
+            .try_commit(&setup.mando, &id, &String::from_str(env, hash), &None)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidHashLength.into());
+    }
+    let err = setup
+        .contract
+        .try_commit(
+            &setup.mando,
+            &id,
+            &String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b4104g"),
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidHashEncoding.into());
+
+    // only maintainers can declare the algorithm
+    let err = setup
+        .contract
+        .try_set_hash_algorithm(&Address::generate(env), &id, &HashAlgorithm::GitSha1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    setup
+        .contract
+        .set_hash_algorithm(&setup.grogu, &id, &HashAlgorithm::GitSha1);
+    assert_eq!(
+        setup.contract.get_project(&id).config.hash_algorithm,
+        HashAlgorithm::GitSha1
+    );
+    let err = setup
+        .contract
+        .try_commit(&setup.mando, &id, &sha256, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidHashLength.into());
+    setup.contract.commit(&setup.mando, &id, &sha1, &None);
+
+    setup
+        .contract
+        .set_hash_algorithm(&setup.grogu, &id, &HashAlgorithm::GitSha256);
+    let err = setup
+        .contract
+        .try_commit(&setup.mando, &id, &sha1, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidHashLength.into());
+    setup.contract.commit(&setup.mando, &id, &sha256, &None);
+}
+
 #[test]
 fn commit_unregistered_maintainer_error() {
     let setup = create_test_data();
@@ -49,7 +687,7 @@ fn commit_unregistered_maintainer_error() {
     let hash_commit = String::from_str(&setup.env, "deadbeef");
     let err = setup
         .contract
-        .try_commit(&bob, &id, &hash_commit)
+        .try_commit(&bob, &id, &hash_commit, &None)
         .unwrap_err()
         .unwrap();
     assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
@@ -8,7 +8,7 @@ use crate::{
 };
 use soroban_sdk::testutils::{Address as _, Events, Ledger};
 use soroban_sdk::{
-    Address, BytesN, Env, Event, IntoVal, String, Symbol, contract, contractimpl, vec,
+    Address, Bytes, BytesN, Env, Event, IntoVal, String, Symbol, contract, contractimpl, vec,
 };
 
 #[contract]
@@ -174,7 +174,7 @@ fn dao_basic_functionality() {
     let proposal = setup.contract.get_proposal(&id, &proposal_id);
     assert_eq!(proposal.id, 0);
     assert_eq!(proposal.title, title);
-    assert_eq!(proposal.ipfs, ipfs);
+    assert_eq!(proposal.ipfs, crate::cid::parse(&setup.env, &ipfs));
     assert_eq!(proposal.vote_data.voting_ends_at, voting_ends_at);
     assert_eq!(
         proposal.vote_data.votes,
@@ -665,7 +665,7 @@ fn proposal_revoke() {
 
     let proposal = setup.contract.get_proposal(&id, &proposal_id);
     assert_eq!(proposal.title, String::from_str(&setup.env, "REDACTED"));
-    assert_eq!(proposal.ipfs, String::from_str(&setup.env, "NONE"));
+    assert_eq!(proposal.ipfs, Bytes::new(&setup.env));
     assert_eq!(proposal.status, ProposalStatus::Malicious);
 
     // already revoked and also try to call as an admin should go through the
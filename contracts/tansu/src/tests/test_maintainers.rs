@@ -0,0 +1,273 @@
+extern crate std;
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::ContractErrors;
+use crate::events::{MaintainerAdded, MaintainerRemoved};
+use crate::types;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Event, String, vec};
+
+#[test]
+fn update_config_keeps_maintainers() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let url = String::from_str(env, "github.com/tansu-next");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    setup.contract.update_config(&setup.mando, &id, &url, &ipfs);
+
+    let project = setup.contract.get_project(&id);
+    assert_eq!(project.config.url, url);
+    assert_eq!(project.config.ipfs, crate::cid::parse(env, &ipfs));
+    assert_eq!(
+        project.maintainers,
+        vec![env, setup.grogu.clone(), setup.mando.clone()]
+    );
+}
+
+#[test]
+fn maintainers_change_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+    env.ledger().set_timestamp(1000);
+
+    let kuiil = Address::generate(env);
+    let din = Address::generate(env);
+
+    // 2 maintainers, a majority is both of them
+    assert_eq!(setup.contract.get_maintainers_quorum(&id), 2);
+
+    setup.contract.propose_maintainers_change(
+        &setup.grogu,
+        &id,
+        &vec![env, kuiil.clone(), din.clone()],
+        &vec![env, setup.mando.clone()],
+        &Some(2),
+    );
+    let change = setup.contract.get_maintainers_change(&id);
+    assert_eq!(change.executable_at, 1000 + types::MAINTAINERS_CHANGE_DELAY);
+    assert_eq!(change.approvals, vec![env, setup.grogu.clone()]);
+
+    let err = setup
+        .contract
+        .try_propose_maintainers_change(&setup.mando, &id, &vec![env], &vec![env], &Some(1))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::MaintainersChangePending.into());
+
+    // a single maintainer cannot change the maintainers
+    let err = setup
+        .contract
+        .try_finalize_maintainers_change(&setup.grogu, &id, &true)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::QuorumNotReached.into());
+
+    let err = setup
+        .contract
+        .try_approve_maintainers_change(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::AlreadyVoted.into());
+    setup.contract.approve_maintainers_change(&setup.mando, &id);
+
+    let err = setup
+        .contract
+        .try_finalize_maintainers_change(&setup.grogu, &id, &true)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    env.ledger()
+        .set_timestamp(1000 + types::MAINTAINERS_CHANGE_DELAY);
+    setup
+        .contract
+        .finalize_maintainers_change(&setup.mando, &id, &true);
+
+    let added = |maintainer: &Address| MaintainerAdded {
+        project_key: id.clone(),
+        maintainer: maintainer.clone(),
+        executor: setup.mando.clone(),
+    };
+    let removed = MaintainerRemoved {
+        project_key: id.clone(),
+        maintainer: setup.mando.clone(),
+        executor: setup.mando.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [
+            added(&kuiil).to_xdr(env, &setup.contract_id),
+            added(&din).to_xdr(env, &setup.contract_id),
+            removed.to_xdr(env, &setup.contract_id),
+        ]
+    );
+
+    assert_eq!(
+        setup.contract.get_project(&id).maintainers,
+        vec![env, setup.grogu.clone(), kuiil.clone(), din.clone()]
+    );
+    assert_eq!(setup.contract.get_maintainers_quorum(&id), 2);
+    let err = setup
+        .contract
+        .try_get_maintainers_change(&id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoMaintainersChangeFound.into());
+
+    // removed maintainers lost their rights
+    let err = setup
+        .contract
+        .try_commit(
+            &setup.mando,
+            &id,
+            &String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046"),
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    // any maintainer can cancel
+    setup.contract.propose_maintainers_change(
+        &din,
+        &id,
+        &vec![env],
+        &vec![env, kuiil.clone()],
+        &None,
+    );
+    setup
+        .contract
+        .finalize_maintainers_change(&setup.grogu, &id, &false);
+    let err = setup
+        .contract
+        .try_get_maintainers_change(&id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoMaintainersChangeFound.into());
+}
+
+#[test]
+fn maintainers_change_validation_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let kuiil = Address::generate(env);
+    let invalid_changes = [
+        (vec![env], vec![env], None),
+        (vec![env, setup.mando.clone()], vec![env], None),
+        (vec![env], vec![env, kuiil.clone()], None),
+        (vec![env, kuiil.clone(), kuiil.clone()], vec![env], None),
+        (
+            vec![env],
+            vec![env, setup.mando.clone(), setup.mando.clone()],
+            None,
+        ),
+        (
+            vec![env],
+            vec![env, setup.grogu.clone(), setup.mando.clone()],
+            None,
+        ),
+        (vec![env, kuiil.clone()], vec![env], Some(0)),
+        (vec![env, kuiil.clone()], vec![env], Some(4)),
+    ];
+    for (add, remove, quorum) in invalid_changes {
+        let err = setup
+            .contract
+            .try_propose_maintainers_change(&setup.grogu, &id, &add, &remove, &quorum)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidMaintainersChange.into());
+    }
+
+    let err = setup
+        .contract
+        .try_propose_maintainers_change(&kuiil, &id, &vec![env, kuiil.clone()], &vec![env], &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    let err = setup
+        .contract
+        .try_approve_maintainers_change(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoMaintainersChangeFound.into());
+}
+
+#[test]
+fn projects_by_maintainer() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+    env.ledger().set_timestamp(1000);
+
+    let url = String::from_str(env, "github.com/tansu");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let mut keys = vec![env, id.clone()];
+    for i in 0..11 {
+        let name = String::from_str(env, &std::format!("project{}", (b'a' + i) as char));
+        keys.push_back(setup.contract.register(
+            &setup.grogu,
+            &name,
+            &vec![env, setup.grogu.clone()],
+            &url,
+            &ipfs,
+        ));
+    }
+
+    assert_eq!(
+        setup.contract.get_projects_by_maintainer(&setup.grogu, &0),
+        keys.slice(0..10)
+    );
+    assert_eq!(
+        setup.contract.get_projects_by_maintainer(&setup.grogu, &1),
+        keys.slice(10..12)
+    );
+    assert_eq!(
+        setup.contract.get_projects_by_maintainer(&setup.grogu, &2),
+        vec![env]
+    );
+    assert_eq!(
+        setup.contract.get_projects_by_maintainer(&setup.mando, &0),
+        vec![env, id.clone()]
+    );
+
+    setup.contract.propose_maintainers_change(
+        &setup.grogu,
+        &id,
+        &vec![env],
+        &vec![env, setup.grogu.clone()],
+        &Some(1),
+    );
+    setup.contract.approve_maintainers_change(&setup.mando, &id);
+    env.ledger()
+        .set_timestamp(1000 + types::MAINTAINERS_CHANGE_DELAY);
+    setup
+        .contract
+        .finalize_maintainers_change(&setup.mando, &id, &true);
+
+    // the last project takes the place of the removed one
+    let mut page = keys.slice(1..10);
+    page.push_front(keys.get_unchecked(11));
+    assert_eq!(
+        setup.contract.get_projects_by_maintainer(&setup.grogu, &0),
+        page
+    );
+    assert_eq!(
+        setup.contract.get_projects_by_maintainer(&setup.grogu, &1),
+        vec![env, keys.get_unchecked(10)]
+    );
+    assert_eq!(
+        setup.contract.get_projects_by_maintainer(&setup.mando, &0),
+        vec![env, id]
+    );
+}
@@ -0,0 +1,135 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::ContractErrors;
+use crate::events::ManifestPublished;
+use crate::types::MerkleHash;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{Bytes, BytesN, Env, Event, String, vec};
+
+fn leaf(env: &Env, content: &[u8]) -> BytesN<32> {
+    env.crypto().sha256(&Bytes::from_slice(env, content)).into()
+}
+
+fn node(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    let mut node = Bytes::from_array(env, &first.to_array());
+    node.append(&Bytes::from_array(env, &second.to_array()));
+    env.crypto().sha256(&node).into()
+}
+
+#[test]
+fn manifest_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+
+    // tree over 3 files, the last one is promoted to the next level
+    let readme = leaf(env, b"README.md");
+    let lib = leaf(env, b"src/lib.rs");
+    let tarball = leaf(env, b"tansu-1.0.0.tar.gz");
+    let left = node(env, &readme, &lib);
+    let root = node(env, &left, &tarball);
+
+    setup
+        .contract
+        .publish_manifest(&setup.grogu, &id, &hash, &root, &MerkleHash::Sha256);
+
+    let event = ManifestPublished {
+        project_key: id.clone(),
+        commit_hash: hash.clone(),
+        root: root.clone(),
+        maintainer: setup.grogu.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+
+    let manifest = setup.contract.get_manifest(&id, &hash);
+    assert_eq!(manifest.root, root);
+    assert_eq!(manifest.hash_function, MerkleHash::Sha256);
+    assert_eq!(manifest.maintainer, setup.grogu);
+
+    assert!(setup.contract.verify_inclusion(
+        &id,
+        &hash,
+        &readme,
+        &vec![env, lib.clone(), tarball.clone()]
+    ));
+    assert!(setup.contract.verify_inclusion(
+        &id,
+        &hash,
+        &lib,
+        &vec![env, readme.clone(), tarball.clone()]
+    ));
+    assert!(
+        setup
+            .contract
+            .verify_inclusion(&id, &hash, &tarball, &vec![env, left.clone()])
+    );
+
+    // wrong leaf or wrong proof
+    let other = leaf(env, b"src/main.rs");
+    assert!(!setup.contract.verify_inclusion(
+        &id,
+        &hash,
+        &other,
+        &vec![env, lib.clone(), tarball.clone()]
+    ));
+    assert!(
+        !setup
+            .contract
+            .verify_inclusion(&id, &hash, &readme, &vec![env, lib.clone()])
+    );
+}
+
+#[test]
+fn manifest_validation_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let root = leaf(env, b"README.md");
+
+    // hash must be anchored
+    let err = setup
+        .contract
+        .try_publish_manifest(&setup.grogu, &id, &hash, &root, &MerkleHash::Keccak256)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    let err = setup
+        .contract
+        .try_verify_inclusion(&id, &hash, &root, &vec![env])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoManifestFound.into());
+
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+    setup
+        .contract
+        .publish_manifest(&setup.grogu, &id, &hash, &root, &MerkleHash::Keccak256);
+
+    // single file tree, the leaf is the root
+    assert!(
+        setup
+            .contract
+            .verify_inclusion(&id, &hash, &root, &vec![env])
+    );
+
+    // manifests are immutable
+    let err = setup
+        .contract
+        .try_publish_manifest(&setup.mando, &id, &hash, &root, &MerkleHash::Sha256)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ManifestAlreadyExist.into());
+}
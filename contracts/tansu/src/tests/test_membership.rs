@@ -218,3 +218,53 @@ fn membership_errors() {
         .unwrap();
     assert_eq!(error, ContractErrors::UnknownMember.into());
 }
+
+#[test]
+fn projects_by_member() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let member = Address::generate(env);
+    setup
+        .contract
+        .add_member(&member, &String::from_str(env, "abcd"));
+    assert_eq!(
+        setup.contract.get_projects_by_member(&member, &0),
+        vec![env]
+    );
+
+    let other = setup.contract.register(
+        &setup.mando,
+        &String::from_str(env, "other"),
+        &vec![env, setup.mando.clone()],
+        &String::from_str(env, "github.com/other"),
+        &String::from_str(
+            env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+    );
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &member, &vec![env, Badge::Community]);
+    setup
+        .contract
+        .set_badges(&setup.mando, &other, &member, &vec![env, Badge::Developer]);
+    // updating the badges does not duplicate the project
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &member, &vec![env, Badge::Triage]);
+    assert_eq!(
+        setup.contract.get_projects_by_member(&member, &0),
+        vec![env, id.clone(), other.clone()]
+    );
+
+    // removing all badges removes the project
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &member, &vec![env]);
+    assert_eq!(
+        setup.contract.get_projects_by_member(&member, &0),
+        vec![env, other]
+    );
+}
@@ -1,10 +1,19 @@
 extern crate std;
 use super::test_utils::TestSetup;
-use super::test_utils::create_test_data;
-use crate::contract_migration::MAX_PROJECTS_PER_PAGE;
+use super::test_utils::{create_test_data, init_contract};
 use crate::errors::ContractErrors;
-use crate::types::{Config, Project, ProjectKey};
-use soroban_sdk::{Bytes, String, vec};
+use crate::events::SchemaMigrated;
+use crate::index::MAX_KEYS_PER_PAGE;
+use crate::types::{
+    AdminsConfig, Badge, Config, DEFAULT_COMMIT_APPROVAL_WINDOW, DataKey, HashAlgorithm,
+    MigrationCall, Origin, Project, ProjectKey, ProposalStatus, SCHEMA_VERSION, TIMELOCK_DELAY,
+    UpgradeMigration, VoteData,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{
+    Address, Bytes, BytesN, Event, IntoVal, Map, String, Symbol, Val, Vec, bytesn, contracttype,
+    vec,
+};
 
 #[test]
 fn test_add_projects_to_pagination_previously_registered_project() {
@@ -88,7 +97,7 @@ fn test_add_projects_to_pagination_multi_page_allows() {
     let env = &setup.env;
 
     // Seed projects until we fill the first page and start the second
-    // The max projects per page is MAX_PROJECTS_PER_PAGE, so we seed MAX_PROJECTS_PER_PAGE + 1 projects
+    // The max projects per page is MAX_KEYS_PER_PAGE, so we seed MAX_KEYS_PER_PAGE + 1 projects
     let name_strs = [
         "tansua", "tansub", "tansuc", "tansud", "tansue", "tansuf", "tansug", "tansuh", "tansui",
         "tansuj", "tansuk",
@@ -103,11 +112,11 @@ fn test_add_projects_to_pagination_multi_page_allows() {
     // Run migration for these projects
     client.add_projects_to_pagination(&setup.contract_admin, &names);
 
-    // Verify we have MAX_PROJECTS_PER_PAGE + 1 projects in total
-    // Page 0 should have MAX_PROJECTS_PER_PAGE, Page 1 should have 1
+    // Verify we have MAX_KEYS_PER_PAGE + 1 projects in total
+    // Page 0 should have MAX_KEYS_PER_PAGE, Page 1 should have 1
     let page_0_projects = client.get_projects(&0);
-    assert_eq!(page_0_projects.len(), MAX_PROJECTS_PER_PAGE);
-    for i in 0..MAX_PROJECTS_PER_PAGE {
+    assert_eq!(page_0_projects.len(), MAX_KEYS_PER_PAGE);
+    for i in 0..MAX_KEYS_PER_PAGE {
         assert_eq!(
             page_0_projects.get(i).unwrap().name,
             String::from_str(env, name_strs[i as usize])
@@ -121,16 +130,13 @@ fn test_add_projects_to_pagination_multi_page_allows() {
         String::from_str(env, "tansuk")
     );
 
-    // NOTE: This is one off run, so if the migration is run multiple times, the last page will have duplicates.
-    // Try to migrate AGAIN "tansua" which is on Page 0
+    // Migrating AGAIN "tansua" which is on Page 0 does not duplicate it
     let project_0_name = names.get(0).unwrap();
     client.add_projects_to_pagination(&setup.contract_admin, &vec![env, project_0_name.clone()]);
 
-    // Verify that "tansua" WAS added again to the current last page (Page 1)
-    // Total projects should now be MAX_PROJECTS_PER_PAGE + 2
     let page_0_projects = client.get_projects(&0);
-    assert_eq!(page_0_projects.len(), MAX_PROJECTS_PER_PAGE);
-    for i in 0..MAX_PROJECTS_PER_PAGE {
+    assert_eq!(page_0_projects.len(), MAX_KEYS_PER_PAGE);
+    for i in 0..MAX_KEYS_PER_PAGE {
         assert_eq!(
             page_0_projects.get(i).unwrap().name,
             String::from_str(env, name_strs[i as usize])
@@ -138,19 +144,16 @@ fn test_add_projects_to_pagination_multi_page_allows() {
     }
 
     let page_1_projects = client.get_projects(&1);
-    assert_eq!(page_1_projects.len(), 2);
+    assert_eq!(page_1_projects.len(), 1);
     assert_eq!(
         page_1_projects.get(0).unwrap().name,
         String::from_str(env, "tansuk")
     );
-    assert_eq!(
-        page_1_projects.get(1).unwrap().name,
-        String::from_str(env, "tansua")
-    );
+    assert!(client.verify_project_index(&0, &5).consistent);
 }
 
 #[test]
-fn test_add_projects_to_pagination_duplication_allows() {
+fn test_add_projects_to_pagination_duplication_skipped() {
     let setup = create_test_data();
     let client = &setup.contract;
     let env = &setup.env;
@@ -176,23 +179,182 @@ fn test_add_projects_to_pagination_duplication_allows() {
         String::from_str(env, name_strs[0])
     );
 
-    // NOTE: This is one off run, so if the migration is run multiple times, the last page will have duplicates.
-    // Try to migrate AGAIN "tansua" which is on Page 0
+    // Migrating AGAIN "tansua" does not duplicate it
     let project_0_name = names.get(0).unwrap();
     client.add_projects_to_pagination(&setup.contract_admin, &vec![env, project_0_name.clone()]);
 
-    // Verify that "tansua" WAS added again to the current last page (Page 1)
-    // Total projects should now be 2
     let page_0_projects = client.get_projects(&0);
-    assert_eq!(page_0_projects.len(), 2);
+    assert_eq!(page_0_projects.len(), 1);
     assert_eq!(
         page_0_projects.get(0).unwrap().name,
         String::from_str(env, name_strs[0])
     );
+}
+
+#[test]
+fn test_migrate_project_index() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+
+    // a new deployment has nothing to migrate
+    let status = client.get_migration_status();
+    assert_eq!(status.schema_version, SCHEMA_VERSION);
+    assert!(status.done);
+
+    // Seed a listing corrupted by repeated migrations, from a version 0 storage
+    let name_strs = ["tansua", "tansub", "tansuc", "tansud", "tansue"];
+    let names = seed_projects(&setup, &name_strs);
+    let keys: Vec<Bytes> = Vec::from_iter(
+        env,
+        names
+            .iter()
+            .map(|name| env.crypto().keccak256(&name.to_bytes()).into()),
+    );
+    let unknown: Bytes = env
+        .crypto()
+        .keccak256(&Bytes::from_slice(env, b"ghost"))
+        .into();
+    let page_0 = vec![
+        env,
+        keys.get(0).unwrap(),
+        keys.get(1).unwrap(),
+        keys.get(0).unwrap(),
+        keys.get(2).unwrap(),
+        unknown.clone(),
+        keys.get(3).unwrap(),
+        keys.get(1).unwrap(),
+        keys.get(4).unwrap(),
+        keys.get(0).unwrap(),
+        keys.get(2).unwrap(),
+    ];
+    let page_1 = vec![env, keys.get(4).unwrap()];
+    env.as_contract(&setup.contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&ProjectKey::ProjectKeys(0), &page_0);
+        storage.set(&ProjectKey::ProjectKeys(1), &page_1);
+        storage.set(&ProjectKey::TotalProjects, &11u32);
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+
+    let report = client.verify_project_index(&0, &5);
+    assert_eq!(report.total, 11);
+    assert_eq!(report.listed, 11);
     assert_eq!(
-        page_0_projects.get(1).unwrap().name,
-        String::from_str(env, name_strs[0])
+        report.duplicates,
+        vec![
+            env,
+            keys.get(0).unwrap(),
+            keys.get(1).unwrap(),
+            keys.get(2).unwrap(),
+            keys.get(4).unwrap()
+        ]
     );
+    assert_eq!(report.unknown, vec![env, unknown]);
+    assert!(report.partial_pages.is_empty());
+    assert!(!report.consistent);
+
+    let err = client.try_migrate(&setup.grogu, &3).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    // run in small batches until done
+    let mut status = client.get_migration_status();
+    assert_eq!(status.schema_version, 0);
+    assert!(!status.done);
+    let mut batches = 0;
+    let mut events = std::vec::Vec::new();
+    while !status.done {
+        status = client.migrate(&setup.contract_admin, &3);
+        batches += 1;
+        events.extend(
+            env.events()
+                .all()
+                .filter_by_contract(&setup.contract_id)
+                .events()
+                .iter()
+                .cloned(),
+        );
+    }
+    assert!(batches > 1);
+    assert_eq!(status.schema_version, SCHEMA_VERSION);
+    assert_eq!(status.cursor, 0);
+    // a batch also runs the following versions once one is done
+    let expected: std::vec::Vec<_> = (1..=SCHEMA_VERSION)
+        .map(|schema_version| {
+            SchemaMigrated {
+                admin: setup.contract_admin.clone(),
+                schema_version,
+            }
+            .to_xdr(env, &setup.contract_id)
+        })
+        .collect();
+    assert_eq!(events, expected);
+
+    let report = client.verify_project_index(&0, &5);
+    assert!(report.consistent);
+    assert_eq!(report.total, 5);
+    let projects = client.get_projects(&0);
+    assert_eq!(projects.len(), 5);
+    for name in names.iter() {
+        assert!(projects.iter().any(|project| project.name == name));
+    }
+
+    // running it again is a no-op
+    assert_eq!(client.migrate(&setup.contract_admin, &3), status);
+}
+
+#[test]
+fn test_verify_project_index_pages() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+
+    let name_strs = [
+        "tansua", "tansub", "tansuc", "tansud", "tansue", "tansuf", "tansug", "tansuh", "tansui",
+        "tansuj", "tansuk", "tansul",
+    ];
+    let names = seed_projects(&setup, &name_strs);
+    client.add_projects_to_pagination(&setup.contract_admin, &names);
+    // listed projects are skipped
+    client.add_projects_to_pagination(&setup.contract_admin, &names);
+
+    let report = client.verify_project_index(&0, &1);
+    assert_eq!(report.total, 12);
+    assert_eq!(report.listed, 10);
+    assert_eq!(report.next_page, Some(1));
+    assert!(report.consistent);
+    let report = client.verify_project_index(&1, &5);
+    assert_eq!(report.listed, 2);
+    assert_eq!(report.next_page, None);
+    assert!(report.consistent);
+
+    // a duplicate of a key listed on another page
+    let first: Bytes = env
+        .crypto()
+        .keccak256(&names.get(0).unwrap().to_bytes())
+        .into();
+    env.as_contract(&setup.contract_id, || {
+        let storage = env.storage().persistent();
+        let mut page_1: soroban_sdk::Vec<Bytes> = storage.get(&ProjectKey::ProjectKeys(1)).unwrap();
+        page_1.set(1, first.clone());
+        storage.set(&ProjectKey::ProjectKeys(1), &page_1);
+    });
+    let report = client.verify_project_index(&1, &1);
+    assert_eq!(report.duplicates, vec![env, first]);
+    assert!(!report.consistent);
+
+    // a page past the total
+    env.as_contract(&setup.contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&ProjectKey::TotalProjects, &10u32);
+    });
+    let report = client.verify_project_index(&1, &5);
+    assert_eq!(report.total, 10);
+    assert_eq!(report.listed, 2);
+    assert_eq!(report.partial_pages, vec![env, 1]);
+    assert_eq!(report.next_page, None);
+    assert!(!report.consistent);
 }
 
 fn seed_projects(setup: &TestSetup, name_strs: &[&str]) -> soroban_sdk::Vec<soroban_sdk::String> {
@@ -210,10 +372,14 @@ fn seed_projects(setup: &TestSetup, name_strs: &[&str]) -> soroban_sdk::Vec<soro
             name: name.clone(),
             config: Config {
                 url: String::from_str(env, "url"),
-                ipfs: String::from_str(env, "ipfs"),
+                ipfs: Bytes::new(env),
+                commit_threshold: 1,
+                commit_approval_window: DEFAULT_COMMIT_APPROVAL_WINDOW,
+                hash_algorithm: HashAlgorithm::Any,
             },
             maintainers: vec![env, setup.grogu.clone()],
             sub_projects: None,
+            origin: Origin::Original,
         };
 
         env.as_contract(&setup.contract_id, || {
@@ -222,3 +388,414 @@ fn seed_projects(setup: &TestSetup, name_strs: &[&str]) -> soroban_sdk::Vec<soro
     }
     names
 }
+
+/// Propose, approve and execute an upgrade running `migrate` with `limit`.
+fn upgrade_with_migration(setup: &TestSetup, limit: u32) {
+    let env = &setup.env;
+    let wasm_hash = bytesn!(
+        env,
+        0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+    );
+    let migration = MigrationCall {
+        function: Symbol::new(env, "migrate"),
+        args: vec![env, limit.into_val(env)],
+    };
+    setup.contract.propose_upgrade(
+        &setup.contract_admin,
+        &wasm_hash,
+        &None,
+        &Some(migration.clone()),
+    );
+    assert_eq!(
+        setup.contract.get_upgrade_proposal().migration,
+        UpgradeMigration::Call(migration)
+    );
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + TIMELOCK_DELAY + 1);
+    setup
+        .contract
+        .finalize_upgrade(&setup.contract_admin, &true);
+}
+
+/// Seed a duplicated projects listing from a version 0 storage.
+fn seed_version_0(setup: &TestSetup) {
+    let env = &setup.env;
+    let names = seed_projects(setup, &["tansua", "tansub", "tansuc"]);
+    let keys: Vec<Bytes> = Vec::from_iter(
+        env,
+        names
+            .iter()
+            .map(|name| env.crypto().keccak256(&name.to_bytes()).into()),
+    );
+    let page_0 = vec![
+        env,
+        keys.get(0).unwrap(),
+        keys.get(1).unwrap(),
+        keys.get(0).unwrap(),
+        keys.get(2).unwrap(),
+        keys.get(1).unwrap(),
+    ];
+    env.as_contract(&setup.contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&ProjectKey::ProjectKeys(0), &page_0);
+        storage.set(&ProjectKey::TotalProjects, &5u32);
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+}
+
+#[test]
+fn test_upgrade_migration() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+    seed_version_0(&setup);
+
+    // calls changing the ledger wait for the migration
+    let member = Address::generate(env);
+    let meta = String::from_str(env, "abcd");
+    let err = client.try_add_member(&member, &meta).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::MigrationPending.into());
+
+    // only `migrate(limit)` can be scheduled
+    let wasm_hash = BytesN::from_array(env, &[2u8; 32]);
+    for migration in [
+        MigrationCall {
+            function: Symbol::new(env, "pause"),
+            args: vec![env, 20u32.into_val(env)],
+        },
+        MigrationCall {
+            function: Symbol::new(env, "migrate"),
+            args: vec![env],
+        },
+        MigrationCall {
+            function: Symbol::new(env, "migrate"),
+            args: vec![env, String::from_str(env, "20").into_val(env)],
+        },
+    ] {
+        let err = client
+            .try_propose_upgrade(&setup.contract_admin, &wasm_hash, &None, &Some(migration))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::UpgradeError.into());
+    }
+
+    // the first call after the upgrade runs the migration
+    upgrade_with_migration(&setup, 20);
+    assert!(!client.get_migration_status().done);
+    client.add_member(&member, &meta);
+
+    let event = SchemaMigrated {
+        admin: setup.contract_admin.clone(),
+        schema_version: SCHEMA_VERSION,
+    };
+    assert!(
+        env.events()
+            .all()
+            .filter_by_contract(&setup.contract_id)
+            .events()
+            .contains(&event.to_xdr(env, &setup.contract_id))
+    );
+    let status = client.get_migration_status();
+    assert_eq!(status.schema_version, SCHEMA_VERSION);
+    assert!(status.done);
+    assert!(client.verify_project_index(&0, &5).consistent);
+}
+
+#[test]
+fn test_upgrade_migration_unfinished() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+    seed_version_0(&setup);
+
+    // a single batch is too small, the calls keep failing
+    upgrade_with_migration(&setup, 1);
+    let member = Address::generate(env);
+    let meta = String::from_str(env, "abcd");
+    let err = client.try_add_member(&member, &meta).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::MigrationPending.into());
+
+    // admins finish the migration, also while the contract is paused
+    client.pause(&setup.contract_admin, &true);
+    let status = client.migrate(&setup.contract_admin, &20);
+    assert!(status.done);
+    client.pause(&setup.contract_admin, &false);
+    assert!(client.verify_project_index(&0, &5).consistent);
+    client.add_member(&member, &meta);
+}
+
+#[test]
+fn test_migrate_admins_config() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+
+    // version 1 layout, without timelock
+    let mut legacy = Map::<Symbol, Val>::new(env);
+    legacy.set(Symbol::new(env, "threshold"), 1u32.into_val(env));
+    legacy.set(
+        Symbol::new(env, "admins"),
+        vec![env, setup.contract_admin.clone()].into_val(env),
+    );
+    env.as_contract(&setup.contract_id, || {
+        let storage = env.storage().instance();
+        storage.set(&DataKey::AdminsConfig, &legacy);
+        storage.set(&DataKey::SchemaVersion, &1u32);
+    });
+
+    let expected = AdminsConfig {
+        threshold: 1,
+        admins: vec![env, setup.contract_admin.clone()],
+        timelock: TIMELOCK_DELAY,
+    };
+    assert_eq!(client.get_admins_config(), expected);
+
+    let member = Address::generate(env);
+    let meta = String::from_str(env, "abcd");
+    let err = client.try_add_member(&member, &meta).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::MigrationPending.into());
+
+    let status = client.migrate(&setup.contract_admin, &1);
+    assert_eq!(status.schema_version, SCHEMA_VERSION);
+    assert!(status.done);
+    env.as_contract(&setup.contract_id, || {
+        let stored: AdminsConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminsConfig)
+            .unwrap();
+        assert_eq!(stored, expected);
+    });
+    client.add_member(&member, &meta);
+}
+
+// Layouts of the baseline contract, CIDs stored as strings
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyConfig {
+    pub url: String,
+    pub ipfs: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyProject {
+    pub name: String,
+    pub config: LegacyConfig,
+    pub maintainers: Vec<Address>,
+    pub sub_projects: Option<Vec<Bytes>>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyProposal {
+    pub id: u32,
+    pub title: String,
+    pub proposer: Address,
+    pub ipfs: String,
+    pub vote_data: VoteData,
+    pub status: ProposalStatus,
+    pub outcome_contracts: Option<Vec<crate::types::OutcomeContract>>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyDao {
+    pub proposals: Vec<LegacyProposal>,
+}
+
+#[test]
+fn test_migrate_project_layout() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+
+    let cid = "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i";
+    let names = ["tansua", "tansub"];
+    let ipfs = [cid, "not a cid"];
+    let hash = "6663520bd9e6ede248fef8157b2af0b6b6b41046";
+    let mut keys = Vec::<Bytes>::new(env);
+    for (name, ipfs) in names.iter().zip(ipfs) {
+        let key: Bytes = env
+            .crypto()
+            .keccak256(&Bytes::from_slice(env, name.as_bytes()))
+            .into();
+        keys.push_back(key.clone());
+        let project = LegacyProject {
+            name: String::from_str(env, name),
+            config: LegacyConfig {
+                url: String::from_str(env, "url"),
+                ipfs: String::from_str(env, ipfs),
+            },
+            maintainers: vec![env, setup.grogu.clone()],
+            sub_projects: None,
+        };
+        env.as_contract(&setup.contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&ProjectKey::Key(key.clone()), &project);
+        });
+    }
+
+    // 10 proposals of the first project, on two DAO pages
+    let key = keys.get(0).unwrap();
+    let proposal = |id: u32| LegacyProposal {
+        id,
+        title: String::from_str(env, "title"),
+        proposer: setup.grogu.clone(),
+        ipfs: String::from_str(env, cid),
+        vote_data: VoteData {
+            voting_ends_at: 0,
+            public_voting: true,
+            token_contract: None,
+            votes: vec![env],
+        },
+        status: ProposalStatus::Active,
+        outcome_contracts: None,
+    };
+    let mut page_0 = Vec::new(env);
+    for id in 0..9 {
+        page_0.push_back(proposal(id));
+    }
+    let page_1 = vec![env, proposal(9)];
+    env.as_contract(&setup.contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&ProjectKey::ProjectKeys(0), &keys);
+        storage.set(&ProjectKey::TotalProjects, &2u32);
+        storage.set(
+            &ProjectKey::Dao(key.clone(), 0),
+            &LegacyDao { proposals: page_0 },
+        );
+        storage.set(
+            &ProjectKey::Dao(key.clone(), 1),
+            &LegacyDao { proposals: page_1 },
+        );
+        storage.set(&ProjectKey::DaoTotalProposals(key.clone()), &10u32);
+        storage.set(
+            &ProjectKey::LastHash(key.clone()),
+            &String::from_str(env, hash),
+        );
+        env.storage().instance().set(&DataKey::SchemaVersion, &2u32);
+    });
+
+    // one entry at a time: the project, its two DAO pages, the other project
+    let mut batches = 0;
+    let mut status = client.get_migration_status();
+    while status.schema_version < 3 {
+        status = client.migrate(&setup.contract_admin, &1);
+        batches += 1;
+    }
+    assert_eq!(batches, 4);
+    assert!(client.migrate(&setup.contract_admin, &20).done);
+
+    let project = client.get_project(&key);
+    assert_eq!(project.name, String::from_str(env, "tansua"));
+    assert_eq!(
+        project.config,
+        Config {
+            url: String::from_str(env, "url"),
+            ipfs: crate::cid::parse(env, &String::from_str(env, cid)),
+            commit_threshold: 1,
+            commit_approval_window: DEFAULT_COMMIT_APPROVAL_WINDOW,
+            hash_algorithm: HashAlgorithm::Any,
+        }
+    );
+    assert_eq!(project.maintainers, vec![env, setup.grogu.clone()]);
+    assert_eq!(project.origin, Origin::Original);
+
+    // invalid legacy CIDs are kept as they were
+    let project = client.get_project(&keys.get(1).unwrap());
+    assert_eq!(
+        project.config.ipfs,
+        Bytes::from_slice(env, "not a cid".as_bytes())
+    );
+
+    for page in 0..2 {
+        let dao = client.get_dao(&key, &page);
+        for proposal in dao.proposals.iter() {
+            assert_eq!(
+                proposal.ipfs,
+                crate::cid::parse(env, &String::from_str(env, cid))
+            );
+        }
+    }
+    assert_eq!(client.get_dao(&key, &1).proposals.len(), 1);
+    assert_eq!(client.get_proposal(&key, &9).id, 9);
+
+    // a legacy head is not in the commit log but can be released
+    let hash = String::from_str(env, hash);
+    client.tag_release(
+        &setup.grogu,
+        &key,
+        &String::from_str(env, "1.0.0"),
+        &hash,
+        &String::from_str(env, cid),
+    );
+    client.yank(&setup.grogu, &key, &hash, &String::from_str(env, "reason"));
+    let err = client
+        .try_tag_release(
+            &setup.grogu,
+            &key,
+            &String::from_str(env, "1.0.1"),
+            &String::from_str(env, "7773520bd9e6ede248fef8157b2af0b6b6b41046"),
+            &String::from_str(env, cid),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+}
+
+#[test]
+fn test_migrate_reverse_indexes() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let client = &setup.contract;
+    let env = &setup.env;
+
+    let member = Address::generate(env);
+    client.add_member(&member, &String::from_str(env, "meta"));
+    client.set_badges(&setup.mando, &id, &member, &vec![env, Badge::Community]);
+
+    // projects registered before the reverse indexes were not listed
+    env.as_contract(&setup.contract_id, || {
+        let storage = env.storage().persistent();
+        for maintainer in [&setup.grogu, &setup.mando] {
+            storage.remove(&DataKey::MaintainerProjects(maintainer.clone(), 0));
+            storage.remove(&DataKey::TotalMaintainerProjects(maintainer.clone()));
+        }
+        storage.remove(&DataKey::MemberProjects(member.clone(), 0));
+        storage.remove(&DataKey::TotalMemberProjects(member.clone()));
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &(SCHEMA_VERSION - 1));
+    });
+    assert!(
+        client
+            .get_projects_by_maintainer(&setup.grogu, &0)
+            .is_empty()
+    );
+
+    let status = client.migrate(&setup.contract_admin, &20);
+    assert!(status.done);
+    for maintainer in [&setup.grogu, &setup.mando] {
+        assert_eq!(
+            client.get_projects_by_maintainer(maintainer, &0),
+            vec![env, id.clone()]
+        );
+    }
+    assert_eq!(
+        client.get_projects_by_member(&member, &0),
+        vec![env, id.clone()]
+    );
+
+    // running it again does not list a project twice
+    env.as_contract(&setup.contract_id, || {
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &(SCHEMA_VERSION - 1));
+    });
+    client.migrate(&setup.contract_admin, &20);
+    assert_eq!(client.get_projects_by_member(&member, &0), vec![env, id]);
+}
@@ -1,7 +1,8 @@
 use super::test_utils::create_test_data;
 use crate::errors::ContractErrors;
 use crate::events::{
-    ContractPaused, ContractUpdated, UpgradeApproved, UpgradeProposed, UpgradeStatus,
+    ContractPaused, ContractUpdated, UpgradeApprovalRevoked, UpgradeApproved, UpgradeProposed,
+    UpgradeStatus,
 };
 use crate::{domain_contract, types};
 use soroban_sdk::testutils::{Address as _, Events, Ledger};
@@ -84,13 +85,14 @@ fn test_upgrade_flow() {
     // Propose an upgrade
     setup
         .contract
-        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None);
+        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None, &None);
 
     // Verify the upgrade proposal event
     let event = UpgradeProposed {
         admin: setup.contract_admin.clone(),
         wasm_hash: wasm_hash.clone().into(),
         executable_at: setup.env.ledger().timestamp() + 24 * 3600,
+        expires_at: setup.env.ledger().timestamp() + 8 * 24 * 3600,
     };
 
     let events = setup
@@ -159,7 +161,7 @@ fn test_upgrade_cancel() {
     // Propose an upgrade
     setup
         .contract
-        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None);
+        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None, &None);
 
     // Cancel the upgrade
     setup
@@ -202,7 +204,7 @@ fn test_unauthorized_upgrade_approval() {
     // Propose an upgrade with admin
     let err = setup
         .contract
-        .try_propose_upgrade(&non_admin, &wasm_hash, &None)
+        .try_propose_upgrade(&non_admin, &wasm_hash, &None, &None)
         .unwrap_err()
         .unwrap();
     assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
@@ -210,7 +212,7 @@ fn test_unauthorized_upgrade_approval() {
     // Propose an upgrade with admin
     setup
         .contract
-        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None);
+        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None, &None);
 
     // We need to verify what's actually in the admins list
     let current_admins_config = setup.contract.get_admins_config();
@@ -239,6 +241,7 @@ fn test_upgrade_approval() {
             setup.contract_admin.clone(),
             second_admin.clone(),
         ],
+        timelock: crate::types::TIMELOCK_DELAY,
     };
 
     // Directly set the admin config in storage to properly initialize the contract
@@ -258,6 +261,7 @@ fn test_upgrade_approval() {
         &setup.contract_admin, // First admin proposes
         &wasm_hash,
         &None, // Keep the same admin config
+        &None,
     );
 
     // First verification - only one approval so far
@@ -363,6 +367,7 @@ fn test_upgrade_invalid_threshold() {
     let invalid_config = types::AdminsConfig {
         threshold: 0,
         admins: vec![&setup.env, setup.contract_admin.clone()],
+        timelock: types::TIMELOCK_DELAY,
     };
 
     // Compute a dummy wasm hash
@@ -372,7 +377,12 @@ fn test_upgrade_invalid_threshold() {
     // Proposing upgrade with invalid config should fail
     let err = setup
         .contract
-        .try_propose_upgrade(&setup.contract_admin, &new_wasm_hash, &Some(invalid_config))
+        .try_propose_upgrade(
+            &setup.contract_admin,
+            &new_wasm_hash,
+            &Some(invalid_config),
+            &None,
+        )
         .unwrap_err()
         .unwrap();
 
@@ -387,6 +397,7 @@ fn test_upgrade_threshold_exceeds_admins() {
     let invalid_config = types::AdminsConfig {
         threshold: 3, // More than the single admin present
         admins: vec![&setup.env, setup.contract_admin.clone()],
+        timelock: types::TIMELOCK_DELAY,
     };
 
     // Compute a dummy wasm hash
@@ -396,9 +407,222 @@ fn test_upgrade_threshold_exceeds_admins() {
     // Proposing upgrade with invalid threshold should fail
     let err = setup
         .contract
-        .try_propose_upgrade(&setup.contract_admin, &new_wasm_hash, &Some(invalid_config))
+        .try_propose_upgrade(
+            &setup.contract_admin,
+            &new_wasm_hash,
+            &Some(invalid_config),
+            &None,
+        )
         .unwrap_err()
         .unwrap();
 
     assert_eq!(err, ContractErrors::UpgradeError.into());
 }
+
+#[test]
+fn test_upgrade_timelock_config() {
+    let setup = create_test_data();
+    let wasm_hash = bytesn!(
+        &setup.env,
+        0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855
+    );
+
+    // Timelock out of bounds
+    for timelock in [
+        types::MIN_UPGRADE_TIMELOCK - 1,
+        types::MAX_UPGRADE_TIMELOCK + 1,
+    ] {
+        let invalid_config = types::AdminsConfig {
+            threshold: 1,
+            admins: vec![&setup.env, setup.contract_admin.clone()],
+            timelock,
+        };
+        let err = setup
+            .contract
+            .try_propose_upgrade(
+                &setup.contract_admin,
+                &wasm_hash,
+                &Some(invalid_config),
+                &None,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::UpgradeError.into());
+    }
+
+    // The new timelock only applies to the next upgrades
+    let new_config = types::AdminsConfig {
+        threshold: 1,
+        admins: vec![&setup.env, setup.contract_admin.clone()],
+        timelock: 2 * 3600,
+    };
+    setup.contract.propose_upgrade(
+        &setup.contract_admin,
+        &wasm_hash,
+        &Some(new_config.clone()),
+        &None,
+    );
+    let now = setup.env.ledger().timestamp();
+    let proposal = setup.contract.get_upgrade_proposal();
+    assert_eq!(proposal.executable_at, now + types::TIMELOCK_DELAY);
+    assert_eq!(
+        proposal.expires_at,
+        now + types::TIMELOCK_DELAY + types::UPGRADE_EXECUTION_WINDOW
+    );
+
+    setup.env.ledger().set_timestamp(proposal.executable_at);
+    setup
+        .contract
+        .finalize_upgrade(&setup.contract_admin, &true);
+    assert_eq!(setup.contract.get_admins_config(), new_config);
+
+    setup
+        .contract
+        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None, &None);
+    let proposal = setup.contract.get_upgrade_proposal();
+    assert_eq!(
+        proposal.executable_at,
+        setup.env.ledger().timestamp() + 2 * 3600
+    );
+}
+
+#[test]
+fn test_upgrade_expiry() {
+    let setup = create_test_data();
+    let wasm_hash = BytesN::from_array(&setup.env, &[2u8; 32]);
+
+    setup
+        .contract
+        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None, &None);
+    let proposal = setup.contract.get_upgrade_proposal();
+
+    // Still pending until it expires
+    setup.env.ledger().set_timestamp(proposal.expires_at);
+    let err = setup
+        .contract
+        .try_propose_upgrade(&setup.contract_admin, &wasm_hash, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UpgradeError.into());
+
+    setup.env.ledger().set_timestamp(proposal.expires_at + 1);
+    let err = setup
+        .contract
+        .try_get_upgrade_proposal()
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UpgradeError.into());
+    let err = setup
+        .contract
+        .try_finalize_upgrade(&setup.contract_admin, &true)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UpgradeError.into());
+
+    // The next proposal cancels the expired one
+    let new_wasm_hash = BytesN::from_array(&setup.env, &[3u8; 32]);
+    setup
+        .contract
+        .propose_upgrade(&setup.contract_admin, &new_wasm_hash, &None, &None);
+
+    let expired = UpgradeStatus {
+        admin: setup.contract_admin.clone(),
+        wasm_hash: wasm_hash.into(),
+        status: String::from_str(&setup.env, "Expired"),
+    };
+    let now = setup.env.ledger().timestamp();
+    let proposed = UpgradeProposed {
+        admin: setup.contract_admin.clone(),
+        wasm_hash: new_wasm_hash.clone().into(),
+        executable_at: now + types::TIMELOCK_DELAY,
+        expires_at: now + types::TIMELOCK_DELAY + types::UPGRADE_EXECUTION_WINDOW,
+    };
+    let events = setup
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&setup.contract_id);
+    assert_eq!(
+        events,
+        [
+            expired.to_xdr(&setup.env, &setup.contract_id),
+            proposed.to_xdr(&setup.env, &setup.contract_id)
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_upgrade_proposal().wasm_hash,
+        new_wasm_hash
+    );
+}
+
+#[test]
+fn test_revoke_upgrade_approval() {
+    let setup = create_test_data();
+    let second_admin = Address::generate(&setup.env);
+    let admins_config = types::AdminsConfig {
+        threshold: 2,
+        admins: vec![
+            &setup.env,
+            setup.contract_admin.clone(),
+            second_admin.clone(),
+        ],
+        timelock: types::TIMELOCK_DELAY,
+    };
+    setup.env.as_contract(&setup.contract_id, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .set(&types::DataKey::AdminsConfig, &admins_config);
+    });
+
+    // Nothing to revoke
+    let err = setup
+        .contract
+        .try_revoke_upgrade_approval(&second_admin)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UpgradeError.into());
+
+    let wasm_hash = BytesN::from_array(&setup.env, &[3u8; 32]);
+    setup
+        .contract
+        .propose_upgrade(&setup.contract_admin, &wasm_hash, &None, &None);
+    setup.contract.approve_upgrade(&second_admin);
+
+    setup.contract.revoke_upgrade_approval(&second_admin);
+    let event = UpgradeApprovalRevoked {
+        admin: second_admin.clone(),
+        approvals_count: 1,
+    };
+    let events = setup
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&setup.contract_id);
+    assert_eq!(events, [event.to_xdr(&setup.env, &setup.contract_id)]);
+    assert_eq!(
+        setup.contract.get_upgrade_proposal().approvals,
+        vec![&setup.env, setup.contract_admin.clone()]
+    );
+
+    // Only once
+    let err = setup
+        .contract
+        .try_revoke_upgrade_approval(&second_admin)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoApprovalFound.into());
+
+    // Not enough approvals anymore
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + types::TIMELOCK_DELAY);
+    let err = setup
+        .contract
+        .try_finalize_upgrade(&setup.contract_admin, &true)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UpgradeError.into());
+}
@@ -0,0 +1,292 @@
+extern crate std;
+use super::test_utils::{create_test_data, init_contract};
+use crate::contract_versioning::domain_node;
+use crate::domain_contract;
+use crate::errors::ContractErrors;
+use crate::events::{ProjectActionApproved, ProjectArchived, ProjectDeregistered};
+use crate::types::{Badge, ProjectAction, ProjectFilter, ProjectStatus};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Bytes, Event, String, vec};
+
+#[test]
+fn archive_project_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+
+    // both maintainers must approve
+    setup.contract.archive_project(&setup.grogu, &id);
+    let event = ProjectActionApproved {
+        project_key: id.clone(),
+        action: ProjectAction::Archive,
+        maintainer: setup.grogu.clone(),
+        approvals_count: 1,
+        quorum_reached: false,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+    assert_eq!(
+        setup.contract.get_project_status(&id),
+        ProjectStatus::Active
+    );
+
+    let err = setup
+        .contract
+        .try_archive_project(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::AlreadyVoted.into());
+
+    setup.contract.archive_project(&setup.mando, &id);
+    let approved = ProjectActionApproved {
+        project_key: id.clone(),
+        action: ProjectAction::Archive,
+        maintainer: setup.mando.clone(),
+        approvals_count: 2,
+        quorum_reached: true,
+    };
+    let archived = ProjectArchived {
+        project_key: id.clone(),
+        caller: setup.mando.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [
+            approved.to_xdr(env, &setup.contract_id),
+            archived.to_xdr(env, &setup.contract_id)
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_project_status(&id),
+        ProjectStatus::Archived
+    );
+
+    // read-only
+    let err = setup
+        .contract
+        .try_commit(
+            &setup.mando,
+            &id,
+            &String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990"),
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectReadOnly.into());
+    let member = Address::generate(env);
+    setup
+        .contract
+        .add_member(&member, &String::from_str(env, "member"));
+    let err = setup
+        .contract
+        .try_set_badges(&setup.mando, &id, &member, &vec![env, Badge::Developer])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectReadOnly.into());
+    let err = setup
+        .contract
+        .try_create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(env, "Archived proposal"),
+            &String::from_str(
+                env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectReadOnly.into());
+    let err = setup
+        .contract
+        .try_archive_project(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectReadOnly.into());
+
+    // history stays queryable
+    assert_eq!(setup.contract.get_commit(&id), hash);
+    assert_eq!(setup.contract.get_commit_history(&id, &0).len(), 1);
+}
+
+#[test]
+fn deregister_project_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let maintainers = vec![env, setup.grogu.clone()];
+    let url = String::from_str(env, "github.com/tansu");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let mut keys = std::vec![id.clone()];
+    for i in 0u8..11 {
+        let name = std::format!("tansu{}", (b'a' + i) as char);
+        keys.push(setup.contract.register(
+            &setup.grogu,
+            &String::from_str(env, &name),
+            &maintainers,
+            &url,
+            &ipfs,
+        ));
+    }
+    assert_eq!(setup.contract.get_projects(&1).len(), 2);
+    let list = setup.contract.list_projects(&0, &5, &ProjectFilter::All);
+    assert_eq!(list.next_cursor, Some(5));
+
+    // admins act alone, the domain owner authorizes the release
+    env.mock_all_auths_allowing_non_root_auth();
+    setup
+        .contract
+        .deregister_project(&setup.contract_admin, &id, &true);
+    let event = ProjectDeregistered {
+        project_key: id.clone(),
+        caller: setup.contract_admin.clone(),
+        domain_released: true,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+    assert_eq!(
+        setup.contract.get_project_status(&id),
+        ProjectStatus::Deregistered
+    );
+
+    // the project keeps its place in the listing but is left out
+    let page_0 = setup.contract.get_projects(&0);
+    assert_eq!(page_0.len(), 9);
+    assert_eq!(page_0.get(0).unwrap().name, String::from_str(env, "tansua"));
+    assert_eq!(setup.contract.get_projects(&1).len(), 2);
+    let list = setup.contract.list_projects(&0, &20, &ProjectFilter::All);
+    assert_eq!(list.items.len(), 11);
+    assert!(list.items.iter().all(|item| item.key != id));
+    // cursors stay valid
+    let list = setup.contract.list_projects(&5, &5, &ProjectFilter::All);
+    assert_eq!(list.items.get(0).unwrap().key, keys[5]);
+
+    // the domain was released
+    let domain = domain_contract::Client::new(env, &setup.domain_id);
+    let record_keys = domain_contract::RecordKeys::Record(domain_node(env, &id));
+    assert_eq!(domain.record(&record_keys), None);
+
+    // the project stays queryable and its name reserved
+    assert_eq!(
+        setup.contract.get_project(&id).name,
+        String::from_str(env, "tansu")
+    );
+    let err = setup
+        .contract
+        .try_register(
+            &setup.grogu,
+            &String::from_str(env, "tansu"),
+            &maintainers,
+            &url,
+            &ipfs,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectAlreadyExist.into());
+    let err = setup
+        .contract
+        .try_deregister_project(&setup.contract_admin, &id, &false)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectReadOnly.into());
+
+    // a single maintainer is a majority of one
+    setup
+        .contract
+        .deregister_project(&setup.grogu, &keys[11], &false);
+    let page_1 = setup.contract.get_projects(&1);
+    assert_eq!(page_1.len(), 1);
+    assert_eq!(page_1.get(0).unwrap().name, String::from_str(env, "tansuj"));
+    setup
+        .contract
+        .deregister_project(&setup.grogu, &keys[10], &false);
+    assert_eq!(setup.contract.get_projects(&0).len(), 9);
+    assert!(setup.contract.get_projects(&1).is_empty());
+    let err = setup.contract.try_get_projects(&2).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::NoProjectPageFound.into());
+}
+
+#[test]
+fn deregister_renamed_project() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let member = Address::generate(env);
+    setup
+        .contract
+        .add_member(&member, &String::from_str(env, "meta"));
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &member, &vec![env, Badge::Community]);
+
+    let name = String::from_str(env, "tansunext");
+    let alias = setup.contract.rename_project(&setup.grogu, &id, &name);
+    setup.contract.rename_project(&setup.mando, &id, &name);
+
+    env.mock_all_auths_allowing_non_root_auth();
+    setup
+        .contract
+        .deregister_project(&setup.contract_admin, &id, &true);
+
+    // the domain of the current name is released
+    let domain = domain_contract::Client::new(env, &setup.domain_id);
+    let record_keys = domain_contract::RecordKeys::Record(domain_node(env, &alias));
+    assert_eq!(domain.record(&record_keys), None);
+
+    // the project is no longer listed for its maintainers and members
+    for maintainer in [&setup.grogu, &setup.mando] {
+        assert!(
+            setup
+                .contract
+                .get_projects_by_maintainer(maintainer, &0)
+                .is_empty()
+        );
+    }
+    assert!(
+        setup
+            .contract
+            .get_projects_by_member(&member, &0)
+            .is_empty()
+    );
+}
+
+#[test]
+fn project_status_unauthorized_error() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let err = setup
+        .contract
+        .try_archive_project(&Address::generate(env), &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    let err = setup
+        .contract
+        .try_deregister_project(
+            &setup.contract_admin,
+            &Bytes::from_slice(env, b"unknown"),
+            &false,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+}
@@ -1,7 +1,10 @@
 extern crate std;
 use super::test_utils::{create_test_data, init_contract};
-use crate::events::ProjectRegistered;
-use crate::types::Project;
+use crate::events::{MetadataUpdated, NameReserved, ProjectForked, ProjectRegistered};
+use crate::types::{
+    Fork, HashAlgorithm, Metadata, Origin, Project, ProjectFilter, ProjectKey, ProjectNode,
+    ProjectStatus,
+};
 use crate::{contract_versioning::domain_register, errors::ContractErrors};
 use soroban_sdk::testutils::Events;
 use soroban_sdk::{Bytes, Event, String, Vec, vec};
@@ -20,7 +23,10 @@ fn register_events() {
 
     let name = String::from_str(&setup.env, "tansu");
     let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     let genesis_amount: i128 = 1_000_000_000 * 10_000_000;
@@ -60,7 +66,10 @@ fn register_double_registration_error() {
 
     let name = String::from_str(&setup.env, "tansu");
     let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     // double registration
@@ -82,7 +91,10 @@ fn register_name_too_long_error() {
         "soroban-versioningsoroban-versioningsoroban-versioningsoroban-versioning",
     );
     let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     // name too long
@@ -91,7 +103,7 @@ fn register_name_too_long_error() {
         .try_register(&setup.grogu, &name_long, &maintainers, &url, &ipfs)
         .unwrap_err()
         .unwrap();
-    assert_eq!(err, ContractErrors::InvalidDomainError.into());
+    assert_eq!(err, ContractErrors::InvalidDomainLength.into());
 }
 
 #[test]
@@ -101,7 +113,10 @@ fn register_maintainer_not_domain_owner_error() {
 
     let _name = String::from_str(&setup.env, "tansu");
     let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     // maintainer not domain owner
@@ -128,7 +143,6 @@ fn test_project_listing() {
     let maintainer = &setup.grogu;
     let maintainers = vec![env, maintainer.clone()];
     let url_prefix = "github.com/tansu-";
-    let ipfs_prefix = "2ef4f49fdd8fa9dc463f1f06a094c26b8871";
 
     // Let's mint some tokens to register the domain projects
     let genesis_amount: i128 = 1_000_000_000 * 10_000_000;
@@ -144,8 +158,10 @@ fn test_project_listing() {
         let url_str = std::format!("{}{}", url_prefix, suffix);
         let url = String::from_str(env, &url_str);
 
-        let ipfs_str = std::format!("{}{}", ipfs_prefix, i);
-        let ipfs = String::from_str(env, &ipfs_str);
+        let ipfs = String::from_str(
+            env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        );
 
         client.register(maintainer, &name, &maintainers, &url, &ipfs);
     }
@@ -169,6 +185,88 @@ fn test_project_listing() {
     assert_eq!(err, ContractErrors::NoProjectPageFound.into());
 }
 
+#[test]
+fn list_projects_filters() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let client = &setup.contract;
+    let env = &setup.env;
+
+    let maintainers = vec![env, setup.grogu.clone()];
+    let url = String::from_str(env, "github.com/tansu");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let mut keys = vec![env, id.clone()];
+    for i in 0u8..12 {
+        let name = String::from_str(env, &std::format!("project{}", (b'a' + i) as char));
+        keys.push_back(client.register(&setup.grogu, &name, &maintainers, &url, &ipfs));
+    }
+
+    // walk the whole listing
+    let mut listed = Vec::new(env);
+    let mut cursor = Some(0);
+    while let Some(cursor_) = cursor {
+        let list = client.list_projects(&cursor_, &5, &ProjectFilter::All);
+        assert!(list.items.len() <= 5);
+        for item in list.items.iter() {
+            listed.push_back(item.key);
+        }
+        cursor = list.next_cursor;
+    }
+    assert_eq!(listed, keys);
+
+    let list = client.list_projects(&0, &100, &ProjectFilter::All);
+    assert_eq!(list.items.len(), 13);
+    assert_eq!(list.next_cursor, None);
+    let item = list.items.get(0).unwrap();
+    assert_eq!(item.name, String::from_str(env, "tansu"));
+    assert_eq!(item.status, ProjectStatus::Active);
+    assert_eq!(item.maintainers_count, 2);
+
+    // a zero limit still moves the cursor forward
+    let list = client.list_projects(&0, &0, &ProjectFilter::All);
+    assert_eq!(list.items.len(), 1);
+    assert_eq!(list.next_cursor, Some(1));
+
+    // an empty result past the end
+    let list = client.list_projects(&20, &5, &ProjectFilter::All);
+    assert_eq!(list.items.len(), 0);
+    assert_eq!(list.next_cursor, None);
+
+    let archived = keys.get(2).unwrap();
+    client.archive_project(&setup.grogu, &archived);
+    let anonymous = keys.get(3).unwrap();
+    client.anonymous_voting_setup(
+        &setup.grogu,
+        &anonymous,
+        &String::from_str(env, "public key"),
+    );
+    client.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(env, "Test Proposal"),
+        &ipfs,
+        &(env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+        &None,
+    );
+
+    for (filter, expected) in [
+        (ProjectFilter::HasDao, id.clone()),
+        (ProjectFilter::HasAnonymousVoting, anonymous),
+        (ProjectFilter::Archived, archived),
+        (ProjectFilter::Maintainer(setup.mando.clone()), id.clone()),
+    ] {
+        let list = client.list_projects(&0, &20, &filter);
+        assert_eq!(list.items.len(), 1);
+        assert_eq!(list.items.get(0).unwrap().key, expected);
+        assert_eq!(list.next_cursor, None);
+    }
+}
+
 #[test]
 fn test_sub_projects() {
     let setup = create_test_data();
@@ -189,10 +287,13 @@ fn test_sub_projects() {
 
     let name2 = String::from_str(env, "subproject");
     let url2 = String::from_str(env, "github.com/subproject");
-    let ipfs2 = String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710991");
+    let ipfs2 = String::from_str(env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
     let maintainers2 = vec![env, maintainer.clone()];
     let sub_project_id = client.register(maintainer, &name2, &maintainers2, &url2, &ipfs2);
 
+    // The sub-project agrees to join
+    client.accept_parent(maintainer, &sub_project_id, &project_id);
+
     // Set sub-projects
     let sub_projects = vec![env, sub_project_id.clone()];
     client.set_sub_projects(maintainer, &project_id, &sub_projects);
@@ -234,10 +335,11 @@ fn test_sub_projects_limit() {
         let url = String::from_str(env, &std::format!("github.com/{}", name_str));
         let ipfs = String::from_str(
             env,
-            &std::format!("2ef4f49fdd8fa9dc463f1f06a094c26b8871099{}", i),
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
         );
         let maintainers = vec![env, maintainer.clone()];
         let sub_project_id = client.register(maintainer, &name, &maintainers, &url, &ipfs);
+        client.accept_parent(maintainer, &sub_project_id, &project_id);
         sub_project_ids.push_back(sub_project_id);
     }
 
@@ -259,3 +361,509 @@ fn test_sub_projects_limit() {
     let sub_projects_after = client.get_sub_projects(&project_id);
     assert_eq!(sub_projects_after.len(), 10);
 }
+
+#[test]
+fn sub_projects_graph() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+    let maintainer = &setup.grogu;
+    let id = init_contract(&setup);
+
+    let url = String::from_str(env, "github.com/tansu");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let maintainers = vec![env, maintainer.clone()];
+    let [a, b, other] = ["subone", "subtwo", "other"].map(|name| {
+        client.register(
+            maintainer,
+            &String::from_str(env, name),
+            &maintainers,
+            &url,
+            &ipfs,
+        )
+    });
+
+    // the sub-project must agree to join
+    let err = client
+        .try_set_sub_projects(maintainer, &id, &vec![env, a.clone()])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoParentConsent.into());
+
+    client.accept_parent(maintainer, &a, &id);
+    let invalid = [
+        (
+            vec![env, Bytes::from_array(env, &[0; 32])],
+            ContractErrors::InvalidKey,
+        ),
+        (vec![env, id.clone()], ContractErrors::SubProjectCycle),
+        (
+            vec![env, a.clone(), a.clone()],
+            ContractErrors::InvalidSubProject,
+        ),
+    ];
+    for (sub_projects, error) in invalid {
+        let err = client
+            .try_set_sub_projects(maintainer, &id, &sub_projects)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, error.into());
+    }
+
+    client.set_sub_projects(maintainer, &id, &vec![env, a.clone()]);
+    client.accept_parent(maintainer, &b, &a);
+    client.set_sub_projects(maintainer, &a, &vec![env, b.clone()]);
+    assert_eq!(client.get_parent(&a), Some(id.clone()));
+    assert_eq!(client.get_parent(&b), Some(a.clone()));
+    assert_eq!(client.get_parent(&id), None);
+
+    // a parent cannot become a sub-project
+    client.accept_parent(maintainer, &id, &b);
+    let err = client
+        .try_set_sub_projects(maintainer, &b, &vec![env, id.clone()])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::SubProjectCycle.into());
+
+    // a sub-project belongs to a single organization
+    client.accept_parent(maintainer, &a, &other);
+    let err = client
+        .try_set_sub_projects(maintainer, &other, &vec![env, a.clone()])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidSubProject.into());
+
+    let node = |key: &Bytes, name: &str, parent: Option<Bytes>, depth: u32| ProjectNode {
+        key: key.clone(),
+        name: String::from_str(env, name),
+        parent,
+        depth,
+    };
+    assert_eq!(
+        client.get_project_tree(&id, &5),
+        vec![
+            env,
+            node(&id, "tansu", None, 0),
+            node(&a, "subone", Some(id.clone()), 1),
+            node(&b, "subtwo", Some(a.clone()), 2),
+        ]
+    );
+    assert_eq!(
+        client.get_project_tree(&id, &1),
+        vec![
+            env,
+            node(&id, "tansu", None, 0),
+            node(&a, "subone", Some(id.clone()), 1),
+        ]
+    );
+    assert_eq!(
+        client.get_project_tree(&b, &0),
+        vec![env, node(&b, "subtwo", Some(a.clone()), 0)]
+    );
+
+    // removed sub-projects are detached
+    client.set_sub_projects(maintainer, &id, &Vec::new(env));
+    assert_eq!(client.get_parent(&a), None);
+    client.set_sub_projects(maintainer, &other, &vec![env, a.clone()]);
+    assert_eq!(client.get_parent(&a), Some(other));
+}
+
+#[test]
+fn register_fork_flow() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+    let id = init_contract(&setup);
+
+    let url = String::from_str(env, "github.com/tansufork");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let maintainers = vec![env, setup.grogu.clone()];
+    let name = String::from_str(env, "tansufork");
+
+    // a project without a commit can be forked
+    let early_id = client.register_fork(
+        &setup.grogu,
+        &String::from_str(env, "tansuearly"),
+        &id,
+        &maintainers,
+        &url,
+        &ipfs,
+    );
+    let early = client.get_project(&early_id);
+    assert_eq!(
+        early.origin,
+        Origin::Fork(Fork {
+            upstream: id.clone(),
+            commit_hash: None,
+            timestamp: env.ledger().timestamp(),
+        })
+    );
+
+    let err = client
+        .try_register_fork(
+            &setup.grogu,
+            &name,
+            &Bytes::from_array(env, &[0; 32]),
+            &maintainers,
+            &url,
+            &ipfs,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+
+    client.set_hash_algorithm(&setup.grogu, &id, &HashAlgorithm::GitSha1);
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    client.commit(&setup.mando, &id, &hash, &None);
+
+    let fork_id = client.register_fork(&setup.grogu, &name, &id, &maintainers, &url, &ipfs);
+    let registered = ProjectRegistered {
+        project_key: fork_id.clone(),
+        name: name.clone(),
+        maintainer: setup.grogu.clone(),
+    };
+    let forked = ProjectForked {
+        project_key: fork_id.clone(),
+        upstream_key: id.clone(),
+        commit_hash: Some(hash.clone()),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [
+            registered.to_xdr(env, &setup.contract_id),
+            forked.to_xdr(env, &setup.contract_id)
+        ]
+    );
+
+    let fork = client.get_project(&fork_id);
+    assert_eq!(
+        fork.origin,
+        Origin::Fork(Fork {
+            upstream: id.clone(),
+            commit_hash: Some(crate::hash::parse(env, &hash, &HashAlgorithm::GitSha1)),
+            timestamp: env.ledger().timestamp(),
+        })
+    );
+    assert_eq!(fork.config.hash_algorithm, HashAlgorithm::GitSha1);
+    assert_eq!(client.get_project(&id).origin, Origin::Original);
+    assert_eq!(client.get_forks(&id, &0), vec![env, early_id, fork_id]);
+    assert_eq!(client.get_forks(&id, &1), vec![env]);
+
+    // legacy heads are kept as they were stored
+    let legacy = String::from_str(env, "not a hex hash");
+    env.as_contract(&setup.contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&ProjectKey::LastHash(id.clone()), &legacy);
+    });
+    let legacy_id = client.register_fork(
+        &setup.grogu,
+        &String::from_str(env, "tansulegacy"),
+        &id,
+        &maintainers,
+        &url,
+        &ipfs,
+    );
+    let Origin::Fork(fork) = client.get_project(&legacy_id).origin else {
+        panic!("not a fork");
+    };
+    assert_eq!(fork.commit_hash, Some(legacy.to_bytes()));
+}
+
+#[test]
+fn project_metadata() {
+    let setup = create_test_data();
+    let client = &setup.contract;
+    let env = &setup.env;
+    let id = init_contract(&setup);
+
+    let empty = Metadata {
+        license: String::from_str(env, ""),
+        description: String::from_str(env, ""),
+        tags: vec![env],
+        mirrors: vec![env],
+        homepage: String::from_str(env, ""),
+    };
+    let summary = client.get_project_summary(&id);
+    assert_eq!(summary.name, String::from_str(env, "tansu"));
+    assert_eq!(summary.url, String::from_str(env, "github.com/tansu"));
+    assert_eq!(summary.status, ProjectStatus::Active);
+    assert_eq!(summary.maintainers_count, 2);
+    assert_eq!(summary.metadata, empty);
+
+    let metadata = Metadata {
+        license: String::from_str(env, "MIT OR Apache-2.0"),
+        description: String::from_str(env, "Decentralized project governance"),
+        tags: vec![
+            env,
+            String::from_str(env, "soroban"),
+            String::from_str(env, "dao"),
+        ],
+        mirrors: vec![env, String::from_str(env, "https://gitlab.com/tansu")],
+        homepage: String::from_str(env, "https://tansu.dev"),
+    };
+    client.set_metadata(&setup.mando, &id, &metadata);
+    let event = MetadataUpdated {
+        project_key: id.clone(),
+        maintainer: setup.mando.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+    assert_eq!(client.get_project_summary(&id).metadata, metadata);
+
+    // non-ASCII text is allowed
+    let accented = Metadata {
+        description: String::from_str(env, "Gouvernance décentralisée"),
+        ..metadata.clone()
+    };
+    client.set_metadata(&setup.mando, &id, &accented);
+
+    let long = String::from_str(env, &"a".repeat(129));
+    let invalid = [
+        Metadata {
+            license: String::from_str(env, "MIT/GPL"),
+            ..metadata.clone()
+        },
+        Metadata {
+            description: String::from_str(env, &"a".repeat(257)),
+            ..metadata.clone()
+        },
+        Metadata {
+            tags: vec![env, String::from_str(env, "Soroban")],
+            ..metadata.clone()
+        },
+        Metadata {
+            tags: vec![env, String::from_str(env, "")],
+            ..metadata.clone()
+        },
+        Metadata {
+            mirrors: vec![env, long.clone()],
+            ..metadata.clone()
+        },
+        Metadata {
+            homepage: long,
+            ..metadata.clone()
+        },
+        Metadata {
+            description: String::from_str(env, "line\nbreak"),
+            ..metadata.clone()
+        },
+        Metadata {
+            description: String::from_str(env, "next\u{85}line"),
+            ..metadata.clone()
+        },
+        Metadata {
+            tags: vec![env, String::from_str(env, "dao\t")],
+            ..metadata.clone()
+        },
+        Metadata {
+            mirrors: vec![env, String::from_str(env, "gitlab.com/tansu")],
+            ..metadata.clone()
+        },
+        Metadata {
+            mirrors: vec![env, String::from_str(env, "javascript:alert(1)")],
+            ..metadata.clone()
+        },
+        Metadata {
+            mirrors: vec![env, String::from_str(env, "https://")],
+            ..metadata.clone()
+        },
+        Metadata {
+            homepage: String::from_str(env, "http://tansu.dev"),
+            ..metadata.clone()
+        },
+        Metadata {
+            homepage: String::from_str(env, "https://tansu.dev/a b"),
+            ..metadata.clone()
+        },
+    ];
+    for metadata in invalid {
+        let err = client
+            .try_set_metadata(&setup.mando, &id, &metadata)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidMetadata.into());
+    }
+}
+
+#[test]
+fn ipfs_cid_validation() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+    let url = String::from_str(env, "github.com/tansu");
+
+    // CIDv0 is stored as its sha2-256 multihash
+    let cid = String::from_str(env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    setup.contract.update_config(&setup.mando, &id, &url, &cid);
+    let ipfs = setup.contract.get_project(&id).config.ipfs;
+    assert_eq!(ipfs.len(), 34);
+    assert_eq!((ipfs.get(0), ipfs.get(1)), (Some(0x12), Some(0x20)));
+
+    // every multibase encoding of a CIDv1 is stored the same way
+    let mut stored = Vec::new(env);
+    for cid in [
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        "BAFYBEIB6IOUPHO3P3PLIUSX7TGS7DVI6MPU2BWFHAYJ6W6IE44LO3VVC4I",
+        "zdj7WZcxCGhcCC63McdUKstjm4mfjRTnffFfXK2ixrbVsnsAh",
+        "f017012203e43a8f3bb6fdbd68a4aff99a5f1d51e63e9a0d8a70613eb7904e716edd6a2e2",
+    ] {
+        let cid = String::from_str(env, cid);
+        setup.contract.update_config(&setup.mando, &id, &url, &cid);
+        stored.push_back(setup.contract.get_project(&id).config.ipfs);
+    }
+    let ipfs = stored.get(0).unwrap();
+    assert_eq!(ipfs.len(), 36);
+    assert_eq!((ipfs.get(0), ipfs.get(1)), (Some(0x01), Some(0x70)));
+    for other in stored.iter() {
+        assert_eq!(other, ipfs);
+    }
+
+    for cid in [
+        "",
+        "2ef4f49fdd8fa9dc463f1f06a094c26b88710990",
+        // invalid base58 character
+        "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0",
+        // mixed case base32
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvC4i",
+        // truncated digest
+        "f017012203e43a8f3bb6fdbd68a4aff99a5f1d51e63e9a0d8a70613eb7904e716edd6a2",
+        // CIDv2
+        "f027012203e43a8f3bb6fdbd68a4aff99a5f1d51e63e9a0d8a70613eb7904e716edd6a2e2",
+    ] {
+        let err = setup
+            .contract
+            .try_update_config(&setup.mando, &id, &url, &String::from_str(env, cid))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidCid.into());
+    }
+}
+
+#[test]
+fn register_name_normalization() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let maintainers = vec![env, setup.grogu.clone()];
+    let url = String::from_str(env, "github.com/tansu");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+
+    // names are converted to lower case before hashing
+    let err = setup
+        .contract
+        .try_register(
+            &setup.grogu,
+            &String::from_str(env, "TanSu"),
+            &maintainers,
+            &url,
+            &ipfs,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectAlreadyExist.into());
+
+    let key = setup.contract.register(
+        &setup.grogu,
+        &String::from_str(env, "Kuiil"),
+        &maintainers,
+        &url,
+        &ipfs,
+    );
+    let name = String::from_str(env, "kuiil");
+    let expected: Bytes = env.crypto().keccak256(&name.to_bytes()).into();
+    assert_eq!(key, expected);
+    assert_eq!(setup.contract.get_project(&key).name, name);
+
+    // 15 characters is the longest valid name
+    setup.contract.register(
+        &setup.grogu,
+        &String::from_str(env, "tansuversioning"),
+        &maintainers,
+        &url,
+        &ipfs,
+    );
+
+    for (name, error) in [
+        ("", ContractErrors::InvalidDomainLength),
+        ("tansuversioningx", ContractErrors::InvalidDomainLength),
+        ("tansu-next", ContractErrors::InvalidDomainCharacter),
+        ("-tansu", ContractErrors::InvalidDomainCharacter),
+        ("tansu2", ContractErrors::InvalidDomainCharacter),
+        ("tansu next", ContractErrors::InvalidDomainCharacter),
+        ("tansü", ContractErrors::InvalidDomainCharacter),
+    ] {
+        let err = setup
+            .contract
+            .try_register(
+                &setup.grogu,
+                &String::from_str(env, name),
+                &maintainers,
+                &url,
+                &ipfs,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, error.into());
+    }
+
+    // reserved names cannot be registered or used to rename a project
+    let reserved = String::from_str(env, "Stellar");
+    setup
+        .contract
+        .set_reserved_name(&setup.contract_admin, &reserved, &true);
+    let event = NameReserved {
+        name: String::from_str(env, "stellar"),
+        reserved: true,
+        admin: setup.contract_admin.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+    assert!(
+        setup
+            .contract
+            .is_name_reserved(&String::from_str(env, "stellar"))
+    );
+
+    let err = setup
+        .contract
+        .try_set_reserved_name(&setup.grogu, &reserved, &false)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    let err = setup
+        .contract
+        .try_register(&setup.grogu, &reserved, &maintainers, &url, &ipfs)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ReservedDomain.into());
+    let err = setup
+        .contract
+        .try_rename_project(&setup.grogu, &id, &reserved)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ReservedDomain.into());
+
+    setup
+        .contract
+        .set_reserved_name(&setup.contract_admin, &reserved, &false);
+    assert!(!setup.contract.is_name_reserved(&reserved));
+    setup
+        .contract
+        .register(&setup.grogu, &reserved, &maintainers, &url, &ipfs);
+}
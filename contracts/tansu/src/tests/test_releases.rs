@@ -0,0 +1,139 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::ContractErrors;
+use crate::events::ReleaseTagged;
+use crate::hash;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{Event, String};
+
+#[test]
+fn release_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash_1 = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let hash_2 = String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let notes = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    setup.contract.commit(&setup.mando, &id, &hash_1, &None);
+    setup.contract.commit(&setup.mando, &id, &hash_2, &None);
+
+    let err = setup.contract.try_latest_release(&id).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::NoReleaseFound.into());
+
+    let v1 = String::from_str(env, "1.0.0");
+    setup
+        .contract
+        .tag_release(&setup.grogu, &id, &v1, &hash_1, &notes);
+
+    let event = ReleaseTagged {
+        project_key: id.clone(),
+        version: v1.clone(),
+        commit_hash: hash_1.clone(),
+        maintainer: setup.grogu.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+
+    let release = setup.contract.get_release(&id, &v1);
+    assert_eq!(hash::to_hex(env, &release.commit_hash), hash_1);
+    assert_eq!(release.ipfs, crate::cid::parse(env, &notes));
+    assert_eq!(setup.contract.latest_release(&id), release);
+
+    // pre-releases are listed but never the latest
+    let rc = String::from_str(env, "2.0.0-rc.1");
+    setup
+        .contract
+        .tag_release(&setup.grogu, &id, &rc, &hash_2, &notes);
+    assert_eq!(setup.contract.latest_release(&id).version, v1);
+
+    // an older patch release does not become the latest
+    let v2 = String::from_str(env, "2.0.0+build.5");
+    let v0 = String::from_str(env, "0.9.1");
+    setup
+        .contract
+        .tag_release(&setup.grogu, &id, &v2, &hash_2, &notes);
+    setup
+        .contract
+        .tag_release(&setup.grogu, &id, &v0, &hash_1, &notes);
+    assert_eq!(setup.contract.latest_release(&id).version, v2);
+
+    // build metadata is ignored for lookups
+    let v2_lookup = String::from_str(env, "2.0.0");
+    assert_eq!(setup.contract.get_release(&id, &v2_lookup).version, v2);
+
+    let releases = setup.contract.list_releases(&id, &0);
+    assert_eq!(releases.len(), 4);
+    assert_eq!(releases.get(1).unwrap().version, rc);
+    assert_eq!(setup.contract.list_releases(&id, &1).len(), 0);
+}
+
+#[test]
+fn release_validation_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let notes = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+
+    for version in [
+        "1.0", "v1.0.0", "01.0.0", "1.0.0-", "1.0.0-01", "1.0.0+", "",
+    ] {
+        let err = setup
+            .contract
+            .try_tag_release(
+                &setup.grogu,
+                &id,
+                &String::from_str(env, version),
+                &hash,
+                &notes,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidVersion.into());
+    }
+
+    // hash must be anchored
+    let err = setup
+        .contract
+        .try_tag_release(
+            &setup.grogu,
+            &id,
+            &String::from_str(env, "1.0.0"),
+            &String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990"),
+            &notes,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    // tags are immutable
+    setup.contract.tag_release(
+        &setup.grogu,
+        &id,
+        &String::from_str(env, "1.0.0+a"),
+        &hash,
+        &notes,
+    );
+    let err = setup
+        .contract
+        .try_tag_release(
+            &setup.mando,
+            &id,
+            &String::from_str(env, "1.0.0+b"),
+            &hash,
+            &notes,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ReleaseAlreadyExist.into());
+}
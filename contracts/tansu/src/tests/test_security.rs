@@ -0,0 +1,422 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::ContractErrors;
+use crate::events::{ArtifactAttested, DependencyDeclared, DependencyRemoved, HashYanked};
+use crate::types::{Badge, Dependency, Severity};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Bytes, BytesN, Event, String, vec};
+
+#[test]
+fn yank_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let reason = String::from_str(env, "CVE-2025-0001");
+
+    // hash must be anchored
+    let err = setup
+        .contract
+        .try_yank(&setup.grogu, &id, &hash, &reason)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+    assert_eq!(setup.contract.get_yank(&id, &hash), None);
+
+    setup.contract.yank(&setup.grogu, &id, &hash, &reason);
+
+    let event = HashYanked {
+        project_key: id.clone(),
+        hash: hash.clone(),
+        reason: reason.clone(),
+        maintainer: setup.grogu.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+
+    let yank = setup.contract.get_yank(&id, &hash).unwrap();
+    assert_eq!(yank.reason, reason);
+    assert_eq!(yank.maintainer, setup.grogu);
+
+    // yanked hashes are not deleted
+    assert_eq!(setup.contract.get_commit(&id), hash);
+
+    let err = setup
+        .contract
+        .try_yank(&setup.mando, &id, &hash, &reason)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::HashAlreadyYanked.into());
+}
+
+#[test]
+fn advisory_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let hash_1 = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let hash_2 = String::from_str(env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    setup.contract.commit(&setup.mando, &id, &hash_1, &None);
+    setup.contract.commit(&setup.mando, &id, &hash_2, &None);
+    setup.contract.tag_release(
+        &setup.mando,
+        &id,
+        &String::from_str(env, "1.1.0"),
+        &hash_1,
+        &ipfs,
+    );
+    setup.contract.tag_release(
+        &setup.mando,
+        &id,
+        &String::from_str(env, "1.2.3"),
+        &hash_2,
+        &ipfs,
+    );
+
+    let advisory_0 = setup.contract.publish_advisory(
+        &setup.grogu,
+        &id,
+        &Severity::High,
+        &String::from_str(env, ">=1.0.0 <1.2.3"),
+        &Some(String::from_str(env, "1.2.3")),
+        &ipfs,
+    );
+    let advisory_1 = setup.contract.publish_advisory(
+        &setup.grogu,
+        &id,
+        &Severity::Low,
+        &String::from_str(env, "<1.0.0 || >=1.1.0 <=1.2.3"),
+        &None,
+        &ipfs,
+    );
+    assert_eq!((advisory_0, advisory_1), (0, 1));
+
+    let advisory = setup.contract.get_advisory(&id, &advisory_0);
+    assert_eq!(advisory.severity, Severity::High);
+    assert_eq!(advisory.fixed_version, Some(String::from_str(env, "1.2.3")));
+
+    let affected = |value: &str| {
+        setup
+            .contract
+            .is_version_affected(&id, &String::from_str(env, value))
+    };
+    assert_eq!(affected("1.1.0"), vec![env, 0, 1]);
+    assert_eq!(affected("1.2.3"), vec![env, 1]);
+    assert_eq!(affected("0.1.0"), vec![env, 1]);
+    assert_eq!(affected("2.0.0"), vec![env]);
+
+    // commit hashes are resolved through their releases
+    assert_eq!(
+        affected("6663520bd9e6ede248fef8157b2af0b6b6b41046"),
+        vec![env, 0, 1]
+    );
+    assert_eq!(
+        affected("2ef4f49fdd8fa9dc463f1f06a094c26b88710990"),
+        vec![env, 1]
+    );
+    assert_eq!(affected("deadbeef"), vec![env]);
+
+    let err = setup
+        .contract
+        .try_get_advisory(&id, &2)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoAdvisoryFound.into());
+}
+
+#[test]
+fn advisory_validation_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+
+    for range in ["", "1.0", ">=1.0.0 ||", "~1.0.0", ">= 1.0.0"] {
+        let err = setup
+            .contract
+            .try_publish_advisory(
+                &setup.grogu,
+                &id,
+                &Severity::Critical,
+                &String::from_str(env, range),
+                &None,
+                &ipfs,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidVersion.into());
+    }
+
+    let err = setup
+        .contract
+        .try_publish_advisory(
+            &setup.grogu,
+            &id,
+            &Severity::Critical,
+            &String::from_str(env, "*"),
+            &Some(String::from_str(env, "latest")),
+            &ipfs,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidVersion.into());
+}
+
+#[test]
+fn attestation_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+
+    let meta = String::from_str(env, "builder");
+    let builders = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for (i, builder) in builders.iter().enumerate() {
+        setup.contract.add_member(builder, &meta);
+        let badge = if i == 0 {
+            Badge::Verified
+        } else {
+            Badge::Developer
+        };
+        setup
+            .contract
+            .set_badges(&setup.mando, &id, builder, &vec![env, badge]);
+    }
+
+    let digest = BytesN::from_array(env, &[1; 32]);
+    let other_digest = BytesN::from_array(env, &[2; 32]);
+
+    let verification = setup.contract.get_build_verification(&id, &hash);
+    assert_eq!(verification.threshold, 2);
+    assert_eq!(verification.verified_digest, None);
+    assert!(verification.digests.is_empty());
+
+    setup
+        .contract
+        .attest_build(&builders[0], &id, &hash, &digest);
+    assert_eq!(
+        setup
+            .contract
+            .get_build_verification(&id, &hash)
+            .verified_digest,
+        None
+    );
+
+    setup
+        .contract
+        .attest_build(&builders[1], &id, &hash, &digest);
+    let event = ArtifactAttested {
+        project_key: id.clone(),
+        commit_hash: hash.clone(),
+        digest: digest.clone(),
+        attester: builders[1].clone(),
+        verified: true,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+
+    // a conflicting digest is reported along the verified one
+    setup
+        .contract
+        .attest_build(&builders[2], &id, &hash, &other_digest);
+    let verification = setup.contract.get_build_verification(&id, &hash);
+    assert_eq!(verification.verified_digest, Some(digest.clone()));
+    assert_eq!(verification.digests.len(), 2);
+    let artifact = verification.digests.get(0).unwrap();
+    assert_eq!(artifact.digest, digest);
+    assert_eq!(
+        artifact.attesters,
+        vec![env, builders[0].clone(), builders[1].clone()]
+    );
+    let artifact = verification.digests.get(1).unwrap();
+    assert_eq!(artifact.digest, other_digest);
+    assert_eq!(artifact.attesters, vec![env, builders[2].clone()]);
+
+    // with a threshold of 1 both digests reach it, none is verified
+    setup
+        .contract
+        .set_attestation_threshold(&setup.grogu, &id, &1);
+    let verification = setup.contract.get_build_verification(&id, &hash);
+    assert_eq!(verification.threshold, 1);
+    assert_eq!(verification.verified_digest, None);
+}
+
+#[test]
+fn attestation_validation_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let digest = BytesN::from_array(env, &[1; 32]);
+
+    let builder = Address::generate(env);
+    setup
+        .contract
+        .add_member(&builder, &String::from_str(env, "builder"));
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &builder, &vec![env, Badge::Community]);
+
+    // badge must be Verified or Developer
+    let err = setup
+        .contract
+        .try_attest_build(&builder, &id, &hash, &digest)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &builder, &vec![env, Badge::Developer]);
+
+    // hash must be anchored
+    let err = setup
+        .contract
+        .try_attest_build(&builder, &id, &hash, &digest)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+    setup.contract.attest_build(&builder, &id, &hash, &digest);
+
+    let err = setup
+        .contract
+        .try_attest_build(&builder, &id, &hash, &BytesN::from_array(env, &[2; 32]))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::AlreadyAttested.into());
+
+    let err = setup
+        .contract
+        .try_set_attestation_threshold(&setup.grogu, &id, &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidThreshold.into());
+}
+
+#[test]
+fn dependencies_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let url = String::from_str(env, "github.com/tansu");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let maintainers = vec![env, setup.grogu.clone()];
+    let [app, cli] = ["app", "cli"].map(|name| {
+        setup.contract.register(
+            &setup.grogu,
+            &String::from_str(env, name),
+            &maintainers,
+            &url,
+            &ipfs,
+        )
+    });
+
+    let requirement = String::from_str(env, ">=1.0.0 <2.0.0");
+    setup
+        .contract
+        .add_dependency(&setup.grogu, &app, &id, &requirement);
+    let event = DependencyDeclared {
+        project_key: app.clone(),
+        dependency_key: id.clone(),
+        requirement: requirement.clone(),
+        maintainer: setup.grogu.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+    setup
+        .contract
+        .add_dependency(&setup.grogu, &cli, &id, &requirement);
+
+    // declaring again updates the requirement
+    let requirement = String::from_str(env, ">=1.2.0 <2.0.0");
+    setup
+        .contract
+        .add_dependency(&setup.grogu, &app, &id, &requirement);
+    assert_eq!(
+        setup.contract.get_dependencies(&app),
+        vec![
+            env,
+            Dependency {
+                project_key: id.clone(),
+                requirement: requirement.clone(),
+            }
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_dependents(&id, &0),
+        vec![env, app.clone(), cli.clone()]
+    );
+    assert_eq!(setup.contract.get_dependents(&app, &0), vec![env]);
+
+    setup.contract.remove_dependency(&setup.grogu, &app, &id);
+    let event = DependencyRemoved {
+        project_key: app.clone(),
+        dependency_key: id.clone(),
+        maintainer: setup.grogu.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+    assert_eq!(setup.contract.get_dependencies(&app), vec![env]);
+    assert_eq!(setup.contract.get_dependents(&id, &0), vec![env, cli]);
+
+    let err = setup
+        .contract
+        .try_remove_dependency(&setup.grogu, &app, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoDependencyFound.into());
+
+    let invalid = [
+        (
+            id.clone(),
+            String::from_str(env, "1.0"),
+            ContractErrors::InvalidVersion,
+        ),
+        (app.clone(), requirement.clone(), ContractErrors::InvalidKey),
+        (
+            Bytes::from_array(env, &[0; 32]),
+            requirement.clone(),
+            ContractErrors::InvalidKey,
+        ),
+    ];
+    for (dependency_key, requirement, error) in invalid {
+        let err = setup
+            .contract
+            .try_add_dependency(&setup.grogu, &app, &dependency_key, &requirement)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, error.into());
+    }
+}
@@ -0,0 +1,54 @@
+use crate::semver::{Version, canonical};
+
+#[test]
+fn semver_parse() {
+    let version = Version::parse(b"1.20.3-alpha.1+build.7").unwrap();
+    assert_eq!((version.major, version.minor, version.patch), (1, 20, 3));
+    assert_eq!(version.pre, b"alpha.1");
+    assert_eq!(version.build, b"build.7");
+    assert!(version.is_prerelease());
+    assert_eq!(canonical(b"1.20.3-alpha.1+build.7"), b"1.20.3-alpha.1");
+
+    for invalid in [
+        &b""[..],
+        b"1",
+        b"1.2",
+        b"1.2.3.4",
+        b"1.2.x",
+        b"01.2.3",
+        b"1.2.3-",
+        b"1.2.3-alpha..1",
+        b"1.2.3-01",
+        b"1.2.3+",
+        b"1.2.3+b_1",
+        b"99999999999999999999.0.0",
+    ] {
+        assert!(Version::parse(invalid).is_none());
+    }
+}
+
+#[test]
+fn semver_precedence() {
+    // ordering example from https://semver.org
+    let ordered: [&[u8]; 9] = [
+        b"1.0.0-alpha",
+        b"1.0.0-alpha.1",
+        b"1.0.0-alpha.beta",
+        b"1.0.0-beta",
+        b"1.0.0-beta.2",
+        b"1.0.0-beta.11",
+        b"1.0.0-rc.1",
+        b"1.0.0",
+        b"2.0.0",
+    ];
+    for pair in ordered.windows(2) {
+        assert!(Version::parse(pair[0]).unwrap() < Version::parse(pair[1]).unwrap());
+    }
+
+    assert_eq!(
+        Version::parse(b"1.0.0+a")
+            .unwrap()
+            .cmp(&Version::parse(b"1.0.0+b").unwrap()),
+        core::cmp::Ordering::Equal
+    );
+}
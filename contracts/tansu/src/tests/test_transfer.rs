@@ -0,0 +1,292 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::ContractErrors;
+use crate::events::{ProjectActionApproved, ProjectRenamed, ProjectTransferred};
+use crate::types::{Badge, ProjectAction, PublicVote, Vote, VoteChoice};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Bytes, Event, String, vec};
+
+#[test]
+fn rename_project_alias() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+
+    let name = String::from_str(env, "tansunext");
+    let alias: Bytes = env.crypto().keccak256(&name.to_bytes()).into();
+
+    // both maintainers must approve
+    assert_eq!(
+        setup.contract.rename_project(&setup.grogu, &id, &name),
+        alias
+    );
+    assert_eq!(
+        setup.contract.get_project(&id).name,
+        String::from_str(env, "tansu")
+    );
+    let err = setup.contract.try_get_project(&alias).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+
+    setup.contract.rename_project(&setup.mando, &id, &name);
+    let approved = ProjectActionApproved {
+        project_key: id.clone(),
+        action: ProjectAction::Rename(name.clone()),
+        maintainer: setup.mando.clone(),
+        approvals_count: 2,
+        quorum_reached: true,
+    };
+    let renamed = ProjectRenamed {
+        project_key: id.clone(),
+        previous_name: String::from_str(env, "tansu"),
+        name: name.clone(),
+        alias: alias.clone(),
+        caller: setup.mando.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [
+            approved.to_xdr(env, &setup.contract_id),
+            renamed.to_xdr(env, &setup.contract_id)
+        ]
+    );
+
+    // both keys resolve to the project
+    assert_eq!(setup.contract.get_project(&alias).name, name);
+    assert_eq!(setup.contract.get_project(&id).name, name);
+    assert_eq!(setup.contract.get_commit(&alias), hash);
+    assert_eq!(
+        setup.contract.get_dao(&alias, &0),
+        setup.contract.get_dao(&id, &0)
+    );
+    assert_eq!(setup.contract.resolve_project_key(&alias), id);
+    assert_eq!(setup.contract.resolve_project_key(&id), id);
+
+    // neither name can be registered again
+    let maintainers = vec![env, setup.grogu.clone()];
+    let url = String::from_str(env, "github.com/tansu");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    for name in [name.clone(), String::from_str(env, "tansu")] {
+        let err = setup
+            .contract
+            .try_register(&setup.grogu, &name, &maintainers, &url, &ipfs)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::ProjectAlreadyExist.into());
+    }
+    let err = setup
+        .contract
+        .try_rename_project(&setup.grogu, &id, &String::from_str(env, "tansu"))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectAlreadyExist.into());
+
+    let err = setup
+        .contract
+        .try_rename_project(
+            &setup.grogu,
+            &id,
+            &String::from_str(env, "averylongname123"),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidDomainLength.into());
+}
+
+#[test]
+fn transfer_project_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let kuiil = Address::generate(env);
+    let din = Address::generate(env);
+    let maintainers = vec![env, kuiil.clone(), din.clone()];
+
+    let ci = Address::generate(env);
+    setup
+        .contract
+        .add_delegate(&setup.grogu, &id, &ci, &vec![env], &u64::MAX);
+
+    let err = setup
+        .contract
+        .try_accept_transfer(&id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoTransferFound.into());
+
+    let err = setup
+        .contract
+        .try_transfer_project(&setup.grogu, &id, &vec![env, kuiil.clone(), kuiil.clone()])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidMaintainersChange.into());
+
+    // the new maintainers cannot approve their own offer
+    let err = setup
+        .contract
+        .try_transfer_project(&kuiil, &id, &maintainers)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    // the offer is made once the quorum is reached
+    setup
+        .contract
+        .transfer_project(&setup.grogu, &id, &maintainers);
+    let err = setup
+        .contract
+        .try_get_transfer_offer(&id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoTransferFound.into());
+    setup
+        .contract
+        .transfer_project(&setup.mando, &id, &maintainers);
+    assert_eq!(setup.contract.get_transfer_offer(&id), maintainers);
+
+    // a maintainer can still withdraw the offer
+    setup.contract.cancel_transfer(&setup.mando, &id);
+    let err = setup
+        .contract
+        .try_get_transfer_offer(&id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoTransferFound.into());
+
+    setup
+        .contract
+        .transfer_project(&setup.grogu, &id, &maintainers);
+    setup
+        .contract
+        .transfer_project(&setup.mando, &id, &maintainers);
+    setup.contract.accept_transfer(&id);
+    let event = ProjectTransferred {
+        project_key: id.clone(),
+        previous_maintainers: vec![env, setup.grogu.clone(), setup.mando.clone()],
+        maintainers: maintainers.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&setup.contract_id),
+        [event.to_xdr(env, &setup.contract_id)]
+    );
+    assert_eq!(setup.contract.get_project(&id).maintainers, maintainers);
+    assert_eq!(setup.contract.get_maintainers_quorum(&id), 2);
+
+    // the former maintainers and their delegates lost access
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    for signer in [setup.grogu.clone(), ci] {
+        let err = setup
+            .contract
+            .try_commit(&signer, &id, &hash, &None)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    }
+    setup.contract.commit(&din, &id, &hash, &None);
+    assert_eq!(setup.contract.get_commit(&id), hash);
+}
+
+#[test]
+fn rename_project_dao_alias() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let name = String::from_str(env, "tansunext");
+    let alias = setup.contract.rename_project(&setup.grogu, &id, &name);
+    setup.contract.rename_project(&setup.mando, &id, &name);
+
+    // proposals and votes through the alias go to the project
+    let title = String::from_str(env, "Integrate with xlm.sh");
+    let ipfs = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let voting_ends_at = env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &alias,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+        &None,
+    );
+    setup.contract.vote(
+        &setup.mando,
+        &alias,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: setup.mando.clone(),
+            weight: 1,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+    let proposal = setup.contract.get_proposal(&id, &proposal_id);
+    assert_eq!(proposal.title, title);
+    // the proposer abstains, then the vote
+    assert_eq!(proposal.vote_data.votes.len(), 2);
+
+    // the alias of an archived project is read-only
+    setup.contract.archive_project(&setup.grogu, &id);
+    setup.contract.archive_project(&setup.mando, &id);
+    let err = setup
+        .contract
+        .try_create_proposal(
+            &setup.grogu,
+            &alias,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &true,
+            &None,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectReadOnly.into());
+}
+
+#[test]
+fn rename_project_alias_entrypoints() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+
+    let name = String::from_str(env, "tansunext");
+    let alias = setup.contract.rename_project(&setup.grogu, &id, &name);
+    setup.contract.rename_project(&setup.mando, &id, &name);
+
+    // commits, releases and badges through the alias go to the project
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup.contract.commit(&setup.mando, &alias, &hash, &None);
+    assert_eq!(setup.contract.get_commit(&id), hash);
+    assert_eq!(setup.contract.get_commit_history(&id, &0).len(), 1);
+
+    let version = String::from_str(env, "1.0.0");
+    let notes = String::from_str(
+        env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    setup
+        .contract
+        .tag_release(&setup.grogu, &alias, &version, &hash, &notes);
+    assert_eq!(
+        setup.contract.get_release(&id, &version),
+        setup.contract.latest_release(&alias)
+    );
+
+    let kuiil = Address::generate(env);
+    let meta = String::from_str(env, "abcd");
+    setup.contract.add_member(&kuiil, &meta);
+    setup
+        .contract
+        .set_badges(&setup.mando, &alias, &kuiil, &vec![env, Badge::Community]);
+    assert_eq!(setup.contract.get_badges(&id).community, vec![env, kuiil]);
+}
@@ -0,0 +1,105 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::ContractErrors;
+use crate::ttl::{EXTEND_TO, THRESHOLD};
+use crate::types::ProjectKey;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::{Bytes, String};
+
+#[test]
+fn touched_entries_extended() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+    let ttl = |key: &ProjectKey| {
+        env.as_contract(&setup.contract_id, || {
+            env.storage().persistent().get_ttl(key)
+        })
+    };
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+
+    for key in [
+        ProjectKey::Key(id.clone()),
+        ProjectKey::LastHash(id.clone()),
+        ProjectKey::CommitHistory(id.clone(), 0),
+        ProjectKey::TotalCommits(id.clone()),
+    ] {
+        assert_eq!(ttl(&key), EXTEND_TO);
+    }
+
+    // entries are not extended again before reaching the threshold
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += 1000);
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+    assert_eq!(ttl(&ProjectKey::Key(id.clone())), EXTEND_TO - 1000);
+}
+
+#[test]
+fn extend_project_ttl() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let env = &setup.env;
+    let ttl = |key: &ProjectKey| {
+        env.as_contract(&setup.contract_id, || {
+            env.storage().persistent().get_ttl(key)
+        })
+    };
+
+    let hash = String::from_str(env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup.contract.commit(&setup.mando, &id, &hash, &None);
+    let version = String::from_str(env, "1.0.0");
+    setup.contract.tag_release(
+        &setup.mando,
+        &id,
+        &version,
+        &hash,
+        &String::from_str(
+            env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+    );
+    let release_key = ProjectKey::Release(id.clone(), version);
+    assert!(ttl(&release_key) < THRESHOLD);
+
+    let report = setup.contract.get_ttl_report(&id);
+    assert_eq!(report.extended_at, 0);
+    assert_eq!(report.live_until, 0);
+    assert_eq!(report.threshold, THRESHOLD);
+    assert_eq!(report.extend_to, EXTEND_TO);
+
+    // anyone can extend a project, also when the contract is paused
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += 1000);
+    setup.contract.pause(&setup.contract_admin, &true);
+    let mut cursor = 0;
+    let mut batches = 0;
+    while let Some(next) = setup.contract.extend_project_ttl(&id, &cursor, &10) {
+        assert_eq!(next, cursor + 10);
+        cursor = next;
+        batches += 1;
+    }
+    for key in [
+        release_key,
+        ProjectKey::Releases(id.clone(), 0),
+        ProjectKey::TotalReleases(id.clone()),
+        ProjectKey::LatestRelease(id.clone()),
+        ProjectKey::TtlExtended(id.clone()),
+    ] {
+        assert_eq!(ttl(&key), EXTEND_TO);
+    }
+
+    let sequence = env.ledger().sequence();
+    let report = setup.contract.get_ttl_report(&id);
+    assert_eq!(report.entries.div_ceil(10), batches + 1);
+    assert_eq!(report.extended_at, sequence);
+    assert_eq!(report.live_until, sequence + THRESHOLD);
+
+    let err = setup
+        .contract
+        .try_extend_project_ttl(&Bytes::from_slice(env, b"unknown"), &0, &10)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+}
@@ -0,0 +1,29 @@
+//! Time to live (TTL) of the contract storage.
+//!
+//! Persistent entries are archived once their TTL runs out. Entries touched
+//! by a call are extended along the way and `extend_project_ttl` extends, in batches, every
+//! entry of a project. Archived entries have to be restored with a
+//! `RestoreFootprintOp` transaction before they can be used or extended again.
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Number of ledgers in a day, with 5 seconds ledgers.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// TTL given to the extended entries, about 120 days.
+pub const EXTEND_TO: u32 = 120 * DAY_IN_LEDGERS;
+
+/// Entries are only extended once their TTL is below, about 90 days.
+pub const THRESHOLD: u32 = 90 * DAY_IN_LEDGERS;
+
+/// Extend the TTL of the contract instance and code.
+pub fn extend_instance(env: &Env) {
+    env.storage().instance().extend_ttl(THRESHOLD, EXTEND_TO);
+}
+
+/// Extend the TTL of an existing persistent entry.
+pub fn extend<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, THRESHOLD, EXTEND_TO);
+}
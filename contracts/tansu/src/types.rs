@@ -1,7 +1,16 @@
 use soroban_sdk::{Address, Bytes, BytesN, String, Symbol, Val, Vec, contracttype};
 
 // Constants
-pub const TIMELOCK_DELAY: u64 = 24 * 3600; // 24 hours in seconds
+pub const TIMELOCK_DELAY: u64 = 24 * 3600; // 24 hours in seconds, default upgrade timelock
+pub const MIN_UPGRADE_TIMELOCK: u64 = 3600; // 1 hour in seconds
+pub const MAX_UPGRADE_TIMELOCK: u64 = 30 * 24 * 3600; // 30 days in seconds
+pub const UPGRADE_EXECUTION_WINDOW: u64 = 7 * 24 * 3600; // 7 days in seconds
+pub const MAINTAINERS_CHANGE_DELAY: u64 = 24 * 3600; // 24 hours in seconds
+pub const DEFAULT_BRANCH: &str = "main"; // branch tracked by LastHash
+pub const DEFAULT_COMMIT_APPROVAL_WINDOW: u64 = 7 * 24 * 3600; // 7 days in seconds
+pub const DEFAULT_ATTESTATION_THRESHOLD: u32 = 2; // independent matching builds
+pub const SCHEMA_VERSION: u32 = 4; // storage layout expected by the code
+pub const CONTRACT_VERSION: u32 = 3; // bumped with each release
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -18,10 +27,18 @@ pub enum ContractKey {
 
 #[contracttype]
 pub enum DataKey {
-    Member(Address), // Member of the DAO, address
-    Paused,          // Contract pause state
-    UpgradeProposal, // Pending upgrade proposal
-    AdminsConfig,    // Admin configuration for upgrades and other admin operations
+    Member(Address),                  // Member of the DAO, address
+    Paused,                           // Contract pause state
+    UpgradeProposal,                  // Pending upgrade proposal
+    AdminsConfig,                     // Admin configuration for upgrades and other admin operations
+    MaintainerProjects(Address, u32), // Projects maintained by an address, pagination
+    TotalMaintainerProjects(Address), // Number of projects maintained by an address
+    MemberProjects(Address, u32),     // Projects where an address holds badges, pagination
+    TotalMemberProjects(Address),     // Number of projects where an address holds badges
+    SchemaVersion,                    // Storage layout version, 0 if not set
+    MigrationCursor,                  // Progress of the running migration
+    MigrationEntry,                   // Next entry of the project being migrated
+    PendingMigration,                 // Migration to run on the first call after an upgrade
 }
 
 #[contracttype]
@@ -131,6 +148,7 @@ pub struct AnonymousVoteConfig {
 pub struct AdminsConfig {
     pub threshold: u32,       // M-of-N threshold (e.g., 2 for 2-of-3)
     pub admins: Vec<Address>, // List of authorized admins
+    pub timelock: u64,        // Delay before an upgrade can be executed, in seconds
 }
 
 #[contracttype]
@@ -138,8 +156,31 @@ pub struct AdminsConfig {
 pub struct UpgradeProposal {
     pub wasm_hash: BytesN<32>,
     pub executable_at: u64,
+    pub expires_at: u64, // cancelled if not executed by then
     pub approvals: Vec<Address>,
     pub admins_config: AdminsConfig,
+    pub migration: UpgradeMigration,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrationCall {
+    pub function: Symbol, // migration entrypoint, only `migrate`
+    pub args: Vec<Val>,   // arguments of the entrypoint, without the admin
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum UpgradeMigration {
+    None,
+    Call(MigrationCall),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingMigration {
+    pub admin: Address, // admin who executed the upgrade
+    pub call: MigrationCall,
 }
 
 #[contracttype]
@@ -148,7 +189,7 @@ pub struct Proposal {
     pub id: u32,
     pub title: String,
     pub proposer: Address,
-    pub ipfs: String,
+    pub ipfs: Bytes, // binary CID
     pub vote_data: VoteData,
     pub status: ProposalStatus,
     pub outcome_contracts: Option<Vec<OutcomeContract>>,
@@ -169,15 +210,64 @@ pub enum ProjectKey {
     Dao(Bytes, u32), // Decentralized organization, pagination
     DaoTotalProposals(Bytes),
     AnonymousVoteConfig(Bytes),
-    ProjectKeys(u32), // List of project keys, pagination
-    TotalProjects,    // Total number of projects
+    ProjectKeys(u32),                      // List of project keys, pagination
+    TotalProjects,                         // Total number of projects
+    CommitHistory(Bytes, u32),             // Append-only commit log, pagination
+    TotalCommits(Bytes),                   // Number of commits in the log
+    CommitIndex(Bytes, Bytes),             // Position of a hash in the commit log
+    Release(Bytes, String),                // Release by canonical version
+    Releases(Bytes, u32),                  // List of release versions, pagination
+    TotalReleases(Bytes),                  // Number of releases
+    LatestRelease(Bytes),                  // Highest stable release version
+    CommitReleases(Bytes, Bytes),          // Release versions tagged on a hash
+    Yank(Bytes, Bytes),                    // Yanked commit hash
+    Advisory(Bytes, u32),                  // Security advisory by ID
+    TotalAdvisories(Bytes),                // Number of security advisories
+    BranchHead(Bytes, String),             // Head hash of a non-default branch
+    Branches(Bytes),                       // Names of the non-default branches
+    PendingCommits(Bytes),                 // Commits waiting for maintainer approvals
+    Manifest(Bytes, Bytes),                // Merkle root published for a hash
+    Attestations(Bytes, Bytes),            // Artifact digests attested for a hash
+    AttestationThreshold(Bytes),           // Matching attestations to verify a build
+    Delegate(Bytes, Address),              // Scoped committer key, e.g. for CI
+    MaintainersChange(Bytes),              // Pending change of the maintainers
+    MaintainersQuorum(Bytes),              // Approvals needed to change the maintainers
+    Status(Bytes),                         // Lifecycle status, active if not set
+    ActionApprovals(Bytes, ProjectAction), // Maintainers approving an action
+    Alias(Bytes),                          // Registered key of a renamed project
+    TransferOffer(Bytes),                  // Maintainers the project is offered to
+    Parent(Bytes),                         // Organization of a sub-project
+    ParentConsent(Bytes),                  // Organization a project agreed to join
+    Dependencies(Bytes),                   // Projects a project depends on
+    Dependents(Bytes, u32),                // Projects depending on a project, pagination
+    TotalDependents(Bytes),                // Number of projects depending on a project
+    Forks(Bytes, u32),                     // Forks of a project, pagination
+    TotalForks(Bytes),                     // Number of forks of a project
+    Metadata(Bytes),                       // Structured metadata of a project
+    Reserved(Bytes),                       // Name reserved by the admins
+    TtlExtended(Bytes),                    // Ledger of the last TTL extension of a project
+    Listed(Bytes),                         // Position of a project in the projects listing
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum HashAlgorithm {
+    Any,       // 40 or 64 hex characters, nothing declared
+    GitSha1,   // 40 hex characters
+    GitSha256, // 64 hex characters
+    Mercurial, // 40 hex characters
+    Fossil,    // 40 or 64 hex characters
+    Sha256,    // 64 hex characters
 }
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Config {
-    pub url: String,  // link to VCS
-    pub ipfs: String, // CID of the tansu.toml file with metadata
+    pub url: String,                   // link to VCS
+    pub ipfs: Bytes,                   // binary CID of the tansu.toml file with metadata
+    pub commit_threshold: u32,         // maintainer approvals needed to anchor a commit
+    pub commit_approval_window: u64,   // time to gather approvals in seconds
+    pub hash_algorithm: HashAlgorithm, // VCS hash format of the commits
 }
 
 #[contracttype]
@@ -187,4 +277,246 @@ pub struct Project {
     pub config: Config,
     pub maintainers: Vec<Address>,
     pub sub_projects: Option<Vec<Bytes>>,
+    pub origin: Origin,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Metadata {
+    pub license: String,      // SPDX license identifier or expression
+    pub description: String,  // short description
+    pub tags: Vec<String>,    // topic tags, lowercase
+    pub mirrors: Vec<String>, // URLs of additional VCS mirrors
+    pub homepage: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectSummary {
+    pub name: String,
+    pub url: String,
+    pub ipfs: Bytes, // binary CID
+    pub status: ProjectStatus,
+    pub maintainers_count: u32,
+    pub metadata: Metadata, // empty fields if not set
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProjectFilter {
+    All,
+    HasDao,              // projects with at least one proposal
+    HasAnonymousVoting,  // projects with an anonymous voting setup
+    Archived,            // archived projects
+    Maintainer(Address), // projects maintained by an address
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectListItem {
+    pub key: Bytes,
+    pub name: String,
+    pub url: String,
+    pub status: ProjectStatus,
+    pub maintainers_count: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectList {
+    pub items: Vec<ProjectListItem>,
+    pub next_cursor: Option<u32>, // None once the end of the listing is reached
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrationStatus {
+    pub schema_version: u32, // storage layout version
+    pub cursor: u32,         // progress of the migration to the next version
+    pub done: bool,          // storage layout is up to date
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexReport {
+    pub total: u32,              // value of TotalProjects
+    pub listed: u32,             // keys stored in the pages
+    pub duplicates: Vec<Bytes>,  // keys listed more than once
+    pub unknown: Vec<Bytes>,     // keys without a project
+    pub partial_pages: Vec<u32>, // pages not matching TotalProjects
+    pub consistent: bool,
+    pub next_page: Option<u32>, // first page left to check, None at the end
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlReport {
+    pub entries: u32,     // entries walked by `extend_project_ttl`, bound of its cursor
+    pub extended_at: u32, // ledger of the last completed `extend_project_ttl`, 0 if never
+    pub live_until: u32,  // entries live at least until this ledger, 0 if never extended
+    pub threshold: u32,   // TTL below which entries are extended
+    pub extend_to: u32,   // TTL given to extended entries
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Origin {
+    Original,   // registered with `register`
+    Fork(Fork), // registered with `register_fork`
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fork {
+    pub upstream: Bytes,            // key of the upstream project
+    pub commit_hash: Option<Bytes>, // upstream commit at fork time, raw, None without commit
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectNode {
+    pub key: Bytes,
+    pub name: String,
+    pub parent: Option<Bytes>, // None for a top-level project
+    pub depth: u32,            // 0 for the root of the tree
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommitRecord {
+    pub hash: Bytes, // raw hash
+    pub branch: String,
+    pub committer: Address,
+    pub timestamp: u64, // ledger timestamp at anchoring time
+    pub sequence: u32,  // ledger sequence number at anchoring time
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Release {
+    pub version: String,    // semver version
+    pub commit_hash: Bytes, // anchored commit hash, raw
+    pub ipfs: Bytes,        // binary CID of the release notes
+    pub maintainer: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MerkleHash {
+    Keccak256,
+    Sha256,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Manifest {
+    pub root: BytesN<32>,          // Merkle root over the files or artifacts
+    pub hash_function: MerkleHash, // hash of the leaves and nodes
+    pub maintainer: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Yank {
+    pub reason: String,
+    pub maintainer: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Advisory {
+    pub id: u32,
+    pub severity: Severity,
+    pub affected: String,              // semver range of affected versions
+    pub fixed_version: Option<String>, // first release with a fix
+    pub ipfs: Bytes,                   // binary CID of the advisory details
+    pub maintainer: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dependency {
+    pub project_key: Bytes,
+    pub requirement: String, // semver range of compatible releases
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArtifactDigest {
+    pub digest: BytesN<32>, // digest of the built artifact, e.g. WASM hash
+    pub attesters: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuildVerification {
+    pub threshold: u32,
+    pub verified_digest: Option<BytesN<32>>, // digest reaching the threshold
+    pub digests: Vec<ArtifactDigest>,        // more than one is a conflict
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProjectStatus {
+    Active,
+    Archived,     // read-only, still listed
+    Deregistered, // read-only, removed from the listing
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProjectAction {
+    Archive,
+    Deregister,
+    Rename(String),         // new name
+    Transfer(Vec<Address>), // new maintainers
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaintainersChange {
+    pub add: Vec<Address>,
+    pub remove: Vec<Address>,
+    pub quorum: Option<u32>, // new quorum, None to keep the current one
+    pub executable_at: u64,
+    pub approvals: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Role {
+    Maintainer,
+    Committer(String), // commit on the given branch
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delegate {
+    pub branches: Vec<String>, // allowed branches, empty for any branch
+    pub expires_at: u64,
+    pub maintainer: Address, // maintainer who added the delegate
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingCommit {
+    pub branch: String,
+    pub hash: Bytes,                // raw hash
+    pub parent_hash: Option<Bytes>, // expected head when anchored, raw
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
 }
@@ -22,6 +22,12 @@ export declare const ContractErrors: {
   102: {
     message: string;
   };
+  103: {
+    message: string;
+  };
+  104: {
+    message: string;
+  };
   200: {
     message: string;
   };
@@ -55,6 +61,72 @@ export declare const ContractErrors: {
   210: {
     message: string;
   };
+  211: {
+    message: string;
+  };
+  212: {
+    message: string;
+  };
+  213: {
+    message: string;
+  };
+  214: {
+    message: string;
+  };
+  215: {
+    message: string;
+  };
+  216: {
+    message: string;
+  };
+  217: {
+    message: string;
+  };
+  218: {
+    message: string;
+  };
+  219: {
+    message: string;
+  };
+  220: {
+    message: string;
+  };
+  221: {
+    message: string;
+  };
+  222: {
+    message: string;
+  };
+  223: {
+    message: string;
+  };
+  224: {
+    message: string;
+  };
+  225: {
+    message: string;
+  };
+  226: {
+    message: string;
+  };
+  227: {
+    message: string;
+  };
+  228: {
+    message: string;
+  };
+  229: {
+    message: string;
+  };
+  230: {
+    message: string;
+  };
+  231: {
+    message: string;
+  };
+  232: {
+    message: string;
+  };
   300: {
     message: string;
   };
@@ -67,6 +139,39 @@ export declare const ContractErrors: {
   303: {
     message: string;
   };
+  304: {
+    message: string;
+  };
+  305: {
+    message: string;
+  };
+  306: {
+    message: string;
+  };
+  307: {
+    message: string;
+  };
+  308: {
+    message: string;
+  };
+  309: {
+    message: string;
+  };
+  310: {
+    message: string;
+  };
+  311: {
+    message: string;
+  };
+  312: {
+    message: string;
+  };
+  313: {
+    message: string;
+  };
+  314: {
+    message: string;
+  };
   400: {
     message: string;
   };
@@ -79,6 +184,9 @@ export declare const ContractErrors: {
   403: {
     message: string;
   };
+  404: {
+    message: string;
+  };
   500: {
     message: string;
   };
@@ -97,6 +205,9 @@ export declare const ContractErrors: {
   603: {
     message: string;
   };
+  604: {
+    message: string;
+  };
 };
 export interface Contract {
   address: string;
@@ -127,6 +238,38 @@ export type DataKey =
   | {
       tag: "AdminsConfig";
       values: void;
+    }
+  | {
+      tag: "MaintainerProjects";
+      values: readonly [string, u32];
+    }
+  | {
+      tag: "TotalMaintainerProjects";
+      values: readonly [string];
+    }
+  | {
+      tag: "MemberProjects";
+      values: readonly [string, u32];
+    }
+  | {
+      tag: "TotalMemberProjects";
+      values: readonly [string];
+    }
+  | {
+      tag: "SchemaVersion";
+      values: void;
+    }
+  | {
+      tag: "MigrationCursor";
+      values: void;
+    }
+  | {
+      tag: "MigrationEntry";
+      values: void;
+    }
+  | {
+      tag: "PendingMigration";
+      values: void;
     };
 export interface Badges {
   community: Array<string>;
@@ -223,16 +366,36 @@ export interface AnonymousVoteConfig {
 export interface AdminsConfig {
   admins: Array<string>;
   threshold: u32;
+  timelock: u64;
 }
 export interface UpgradeProposal {
   admins_config: AdminsConfig;
   approvals: Array<string>;
   executable_at: u64;
+  expires_at: u64;
+  migration: UpgradeMigration;
   wasm_hash: Buffer;
 }
+export interface MigrationCall {
+  args: Array<any>;
+  function: string;
+}
+export type UpgradeMigration =
+  | {
+      tag: "None";
+      values: void;
+    }
+  | {
+      tag: "Call";
+      values: readonly [MigrationCall];
+    };
+export interface PendingMigration {
+  admin: string;
+  call: MigrationCall;
+}
 export interface Proposal {
   id: u32;
-  ipfs: string;
+  ipfs: Buffer;
   outcome_contracts: Option<Array<OutcomeContract>>;
   proposer: string;
   status: ProposalStatus;
@@ -274,55 +437,443 @@ export type ProjectKey =
   | {
       tag: "TotalProjects";
       values: void;
+    }
+  | {
+      tag: "CommitHistory";
+      values: readonly [Buffer, u32];
+    }
+  | {
+      tag: "TotalCommits";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "CommitIndex";
+      values: readonly [Buffer, Buffer];
+    }
+  | {
+      tag: "Release";
+      values: readonly [Buffer, string];
+    }
+  | {
+      tag: "Releases";
+      values: readonly [Buffer, u32];
+    }
+  | {
+      tag: "TotalReleases";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "LatestRelease";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "CommitReleases";
+      values: readonly [Buffer, Buffer];
+    }
+  | {
+      tag: "Yank";
+      values: readonly [Buffer, Buffer];
+    }
+  | {
+      tag: "Advisory";
+      values: readonly [Buffer, u32];
+    }
+  | {
+      tag: "TotalAdvisories";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "BranchHead";
+      values: readonly [Buffer, string];
+    }
+  | {
+      tag: "Branches";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "PendingCommits";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Manifest";
+      values: readonly [Buffer, Buffer];
+    }
+  | {
+      tag: "Attestations";
+      values: readonly [Buffer, Buffer];
+    }
+  | {
+      tag: "AttestationThreshold";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Delegate";
+      values: readonly [Buffer, string];
+    }
+  | {
+      tag: "MaintainersChange";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "MaintainersQuorum";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Status";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "ActionApprovals";
+      values: readonly [Buffer, ProjectAction];
+    }
+  | {
+      tag: "Alias";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "TransferOffer";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Parent";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "ParentConsent";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Dependencies";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Dependents";
+      values: readonly [Buffer, u32];
+    }
+  | {
+      tag: "TotalDependents";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Forks";
+      values: readonly [Buffer, u32];
+    }
+  | {
+      tag: "TotalForks";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Metadata";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Reserved";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "TtlExtended";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "Listed";
+      values: readonly [Buffer];
+    };
+export type HashAlgorithm =
+  | {
+      tag: "Any";
+      values: void;
+    }
+  | {
+      tag: "GitSha1";
+      values: void;
+    }
+  | {
+      tag: "GitSha256";
+      values: void;
+    }
+  | {
+      tag: "Mercurial";
+      values: void;
+    }
+  | {
+      tag: "Fossil";
+      values: void;
+    }
+  | {
+      tag: "Sha256";
+      values: void;
     };
 export interface Config {
-  ipfs: string;
+  commit_approval_window: u64;
+  commit_threshold: u32;
+  hash_algorithm: HashAlgorithm;
+  ipfs: Buffer;
   url: string;
 }
 export interface Project {
   config: Config;
   maintainers: Array<string>;
   name: string;
+  origin: Origin;
   sub_projects: Option<Array<Buffer>>;
 }
-export interface Client {
-  /**
-   * Construct and simulate a anonymous_voting_setup transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Setup anonymous voting for a project.
-   *
-   * Configures BLS12-381 cryptographic primitives for anonymous voting.
-   *
-   * # Arguments
-   * * `env` - The environment object
-   * * `maintainer` - The address of the maintainer (must be authorized)
-   * * `project_key` - Unique identifier for the project
-   * * `public_key` - Asymmetric public key to be used for vote encryption
-   *
-   * # Panics
-   * * If the caller is not an authorized maintainer of the project
-   */
-  anonymous_voting_setup: (
-    {
-      maintainer,
-      project_key,
-      public_key,
-    }: {
-      maintainer: string;
-      project_key: Buffer;
-      public_key: string;
-    },
-    options?: MethodOptions,
-  ) => Promise<AssembledTransaction<null>>;
-  /**
-   * Construct and simulate a get_anonymous_voting_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the anonymous voting configuration for a project.
-   *
-   * # Arguments
-   * * `env` - The environment object
-   * * `project_key` - The project key identifier
-   *
-   * # Returns
-   * * `types::AnonymousVoteConfig` - The anonymous voting configuration
+export interface Metadata {
+  description: string;
+  homepage: string;
+  license: string;
+  mirrors: Array<string>;
+  tags: Array<string>;
+}
+export interface ProjectSummary {
+  ipfs: Buffer;
+  maintainers_count: u32;
+  metadata: Metadata;
+  name: string;
+  status: ProjectStatus;
+  url: string;
+}
+export type ProjectFilter =
+  | {
+      tag: "All";
+      values: void;
+    }
+  | {
+      tag: "HasDao";
+      values: void;
+    }
+  | {
+      tag: "HasAnonymousVoting";
+      values: void;
+    }
+  | {
+      tag: "Archived";
+      values: void;
+    }
+  | {
+      tag: "Maintainer";
+      values: readonly [string];
+    };
+export interface ProjectListItem {
+  key: Buffer;
+  maintainers_count: u32;
+  name: string;
+  status: ProjectStatus;
+  url: string;
+}
+export interface ProjectList {
+  items: Array<ProjectListItem>;
+  next_cursor: Option<u32>;
+}
+export interface MigrationStatus {
+  cursor: u32;
+  done: boolean;
+  schema_version: u32;
+}
+export interface IndexReport {
+  consistent: boolean;
+  duplicates: Array<Buffer>;
+  listed: u32;
+  next_page: Option<u32>;
+  partial_pages: Array<u32>;
+  total: u32;
+  unknown: Array<Buffer>;
+}
+export interface TtlReport {
+  entries: u32;
+  extend_to: u32;
+  extended_at: u32;
+  live_until: u32;
+  threshold: u32;
+}
+export type Origin =
+  | {
+      tag: "Original";
+      values: void;
+    }
+  | {
+      tag: "Fork";
+      values: readonly [Fork];
+    };
+export interface Fork {
+  commit_hash: Option<Buffer>;
+  timestamp: u64;
+  upstream: Buffer;
+}
+export interface ProjectNode {
+  depth: u32;
+  key: Buffer;
+  name: string;
+  parent: Option<Buffer>;
+}
+export interface CommitRecord {
+  branch: string;
+  committer: string;
+  hash: Buffer;
+  sequence: u32;
+  timestamp: u64;
+}
+export interface Release {
+  commit_hash: Buffer;
+  ipfs: Buffer;
+  maintainer: string;
+  timestamp: u64;
+  version: string;
+}
+export type MerkleHash =
+  | {
+      tag: "Keccak256";
+      values: void;
+    }
+  | {
+      tag: "Sha256";
+      values: void;
+    };
+export interface Manifest {
+  hash_function: MerkleHash;
+  maintainer: string;
+  root: Buffer;
+  timestamp: u64;
+}
+export interface Yank {
+  maintainer: string;
+  reason: string;
+  timestamp: u64;
+}
+export type Severity =
+  | {
+      tag: "Low";
+      values: void;
+    }
+  | {
+      tag: "Medium";
+      values: void;
+    }
+  | {
+      tag: "High";
+      values: void;
+    }
+  | {
+      tag: "Critical";
+      values: void;
+    };
+export interface Advisory {
+  affected: string;
+  fixed_version: Option<string>;
+  id: u32;
+  ipfs: Buffer;
+  maintainer: string;
+  severity: Severity;
+  timestamp: u64;
+}
+export interface Dependency {
+  project_key: Buffer;
+  requirement: string;
+}
+export interface ArtifactDigest {
+  attesters: Array<string>;
+  digest: Buffer;
+}
+export interface BuildVerification {
+  digests: Array<ArtifactDigest>;
+  threshold: u32;
+  verified_digest: Option<Buffer>;
+}
+export type ProjectStatus =
+  | {
+      tag: "Active";
+      values: void;
+    }
+  | {
+      tag: "Archived";
+      values: void;
+    }
+  | {
+      tag: "Deregistered";
+      values: void;
+    };
+export type ProjectAction =
+  | {
+      tag: "Archive";
+      values: void;
+    }
+  | {
+      tag: "Deregister";
+      values: void;
+    }
+  | {
+      tag: "Rename";
+      values: readonly [string];
+    }
+  | {
+      tag: "Transfer";
+      values: readonly [Array<string>];
+    };
+export interface MaintainersChange {
+  add: Array<string>;
+  approvals: Array<string>;
+  executable_at: u64;
+  quorum: Option<u32>;
+  remove: Array<string>;
+}
+export type Role =
+  | {
+      tag: "Maintainer";
+      values: void;
+    }
+  | {
+      tag: "Committer";
+      values: readonly [string];
+    };
+export interface Delegate {
+  branches: Array<string>;
+  expires_at: u64;
+  maintainer: string;
+}
+export interface PendingCommit {
+  approvals: Array<string>;
+  branch: string;
+  expires_at: u64;
+  hash: Buffer;
+  parent_hash: Option<Buffer>;
+}
+export interface Client {
+  /**
+   * Construct and simulate a anonymous_voting_setup transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Setup anonymous voting for a project.
+   *
+   * Configures BLS12-381 cryptographic primitives for anonymous voting.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer (must be authorized)
+   * * `project_key` - Unique identifier for the project
+   * * `public_key` - Asymmetric public key to be used for vote encryption
+   *
+   * # Panics
+   * * If the caller is not an authorized maintainer of the project
+   */
+  anonymous_voting_setup: (
+    {
+      maintainer,
+      project_key,
+      public_key,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      public_key: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_anonymous_voting_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the anonymous voting configuration for a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `types::AnonymousVoteConfig` - The anonymous voting configuration
    *
    * # Panics
    * * If no anonymous voting configuration exists for the project
@@ -396,6 +947,7 @@ export interface Client {
    * # Panics
    * * If the title is too long
    * * If the voting period is invalid
+   * * If the CID is malformed
    * * If the project doesn't exist
    */
   create_proposal: (
@@ -725,6 +1277,28 @@ export interface Client {
     },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<Badges>>;
+  /**
+   * Construct and simulate a get_projects_by_member transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the projects where an address holds badges.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `member` - The member address
+   * * `page` - The page number (0-based)
+   *
+   * # Returns
+   * * `Vec<Bytes>` - Project keys on the requested page, empty if none
+   */
+  get_projects_by_member: (
+    {
+      member,
+      page,
+    }: {
+      member: string;
+      page: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<Buffer>>>;
   /**
    * Construct and simulate a get_max_weight transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the maximum voting weight for an address in a specific project.
@@ -755,6 +1329,10 @@ export interface Client {
    * Construct and simulate a add_projects_to_pagination transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Add projects to the new pagination list. This is used to migrate projects when the project was created before the pagination was implemented.
    *
+   * Projects which are already listed are skipped, so the call can be
+   * repeated safely. Keys listed before the migration to version 1 are
+   * only known once it ran, it removes the duplicates added meanwhile.
+   *
    * # Arguments
    * * `env` - The environment object
    * * `admin` - The admin address
@@ -774,336 +1352,2102 @@ export interface Client {
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<null>>;
   /**
-   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Pause or unpause the contract (emergency stop.)
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Run a batch of the migration to the next storage layout version.
+   *
+   * Each call processes at most `limit` entries (max 20) per version and
+   * records its progress, call it again until `done` is set. The migration scheduled
+   * by an upgrade, if any, runs first. Admins can migrate while the
+   * contract is paused. Migrations:
+   * * to version 1: remove duplicated and unknown keys from the projects
+   * listing, see `verify_project_index`
+   * * to version 2: add the upgrade timelock to the admins configuration
+   * * to version 3: convert the projects and their DAO pages to the
+   * current layout. Unlisted projects must first be added with
+   * `add_projects_to_pagination`
+   * * to version 4: list the projects of their maintainers and badge
+   * holders, see `get_projects_by_maintainer` and `get_projects_by_member`
    *
    * # Arguments
    * * `env` - The environment object
    * * `admin` - The admin address
-   * * `paused` - Pause or unpause the contract operations which change
-   * ledger states.
+   * * `limit` - The maximum number of entries to process
+   *
+   * # Returns
+   * * `types::MigrationStatus` - The progress of the migration
+   *
+   * # Panics
+   * * If the admin is not authorized
    */
-  pause: (
+  migrate: (
     {
       admin,
-      paused,
+      limit,
     }: {
       admin: string;
-      paused: boolean;
+      limit: u32;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<null>>;
-  /**
-   * Construct and simulate a require_not_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Require that the contract is not paused, panic if it is
-   *
-   * # Panics
-   * * If the contract is paused.
-   */
-  require_not_paused: (
-    options?: MethodOptions,
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<MigrationStatus>>;
   /**
-   * Construct and simulate a get_admins_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current administrators configuration.
+   * Construct and simulate a get_migration_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the storage layout version and the progress of its migration.
    *
    * # Arguments
    * * `env` - The environment object
    *
    * # Returns
-   * * `types::AdminsConfig` - The administrators configuration
+   * * `types::MigrationStatus` - The progress of the migration
    */
-  get_admins_config: (
+  get_migration_status: (
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<AdminsConfig>>;
+  ) => Promise<AssembledTransaction<MigrationStatus>>;
   /**
-   * Construct and simulate a set_domain_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set the Soroban Domain contract.
+   * Construct and simulate a verify_project_index transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check a range of pages of the projects listing against `TotalProjects`.
+   *
+   * Pages before the last one must be full and no page may exist past
+   * the last one. Every key must be listed once and belong to a project:
+   * a key is a duplicate if it is listed twice in the range, or if the
+   * listing stores another position for it. Call it again from
+   * `next_page` to check the rest of the listing.
    *
    * # Arguments
    * * `env` - The environment object
-   * * `admin` - The admin address
-   * * `domain_contract` - The new domain contract
+   * * `start_page` - The first page to check
+   * * `pages` - The maximum number of pages to check (max 5)
+   *
+   * # Returns
+   * * `types::IndexReport` - The inconsistencies of the pages
    */
-  set_domain_contract: (
+  verify_project_index: (
     {
-      admin,
-      domain_contract,
+      start_page,
+      pages,
     }: {
-      admin: string;
-      domain_contract: Contract;
+      start_page: u32;
+      pages: u32;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<IndexReport>>;
   /**
-   * Construct and simulate a set_collateral_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set the Collateral contract.
+   * Construct and simulate a yank transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Yank an anchored commit hash.
+   *
+   * The hash stays in the commit history and its releases remain
+   * queryable, but consumers get an on-chain signal that it should not
+   * be used anymore.
    *
    * # Arguments
    * * `env` - The environment object
-   * * `admin` - The admin address
-   * * `collateral_contract` - The new collateral contract
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `hash` - The anchored commit hash to yank
+   * * `reason` - Why the hash is yanked
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the hash does not match the project's hash algorithm
+   * * If the hash was never anchored
+   * * If the hash is already yanked
    */
-  set_collateral_contract: (
+  yank: (
     {
-      admin,
-      collateral_contract,
+      maintainer,
+      project_key,
+      hash,
+      reason,
     }: {
-      admin: string;
-      collateral_contract: Contract;
+      maintainer: string;
+      project_key: Buffer;
+      hash: string;
+      reason: string;
     },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<null>>;
   /**
-   * Construct and simulate a propose_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Propose a contract upgrade.
+   * Construct and simulate a get_yank transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the yank information of a commit hash.
    *
    * # Arguments
    * * `env` - The environment object
-   * * `admin` - An admin address
-   * * `new_wasm_hash` - The new WASM hash
-   * * `new_admins_config` - Optional new admin configuration (None to keep current)
+   * * `project_key` - The project key identifier
+   * * `hash` - The commit hash
    *
-   * # Panics
-   * * If the admin is not authorized
-   * * If there is already an existing proposal (cancel the previous first)
+   * # Returns
+   * * `Option<types::Yank>` - The yank information, None if not yanked
    */
-  propose_upgrade: (
+  get_yank: (
     {
-      admin,
-      new_wasm_hash,
-      new_admins_config,
+      project_key,
+      hash,
     }: {
-      admin: string;
-      new_wasm_hash: Buffer;
-      new_admins_config: Option<AdminsConfig>;
+      project_key: Buffer;
+      hash: string;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Option<Yank>>>;
   /**
-   * Construct and simulate a approve_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Approve an upgrade proposal
+   * Construct and simulate a publish_advisory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Publish a security advisory.
    *
    * # Arguments
    * * `env` - The environment object
-   * * `admin` - An admin address
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `severity` - Severity of the vulnerability
+   * * `affected` - Semver range of affected versions, e.g. `>=1.0.0 <1.2.3`
+   * * [`Option<fixed_version>`] - First version with a fix
+   * * `ipfs` - CID of the advisory details
+   *
+   * # Returns
+   * * `u32` - The ID of the advisory
    *
    * # Panics
-   * * If the admin is not authorized
-   * * If the admin already approved
-   * * If there is no upgrade to approve
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the range or the fixed version are not valid semver
+   * * If the CID is malformed
    */
-  approve_upgrade: (
+  publish_advisory: (
     {
-      admin,
+      maintainer,
+      project_key,
+      severity,
+      affected,
+      fixed_version,
+      ipfs,
     }: {
-      admin: string;
+      maintainer: string;
+      project_key: Buffer;
+      severity: Severity;
+      affected: string;
+      fixed_version: Option<string>;
+      ipfs: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a get_advisory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a security advisory.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `advisory_id` - The ID of the advisory
+   *
+   * # Returns
+   * * `types::Advisory` - The advisory
+   *
+   * # Panics
+   * * If the advisory doesn't exist
+   */
+  get_advisory: (
+    {
+      project_key,
+      advisory_id,
+    }: {
+      project_key: Buffer;
+      advisory_id: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Advisory>>;
+  /**
+   * Construct and simulate a is_version_affected transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * List the advisories affecting a version or a commit hash.
+   *
+   * If the input is not a valid semver version, it is treated as a commit
+   * hash and every release tagged on that hash is checked.
+   *
+   * Note: the cost of this call grows with the number of advisories
+   * published for the project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `version_or_hash` - A semver version or an anchored commit hash
+   *
+   * # Returns
+   * * `Vec<u32>` - IDs of the advisories affecting the version, empty if
+   * none or if the hash has no release
+   */
+  is_version_affected: (
+    {
+      project_key,
+      version_or_hash,
+    }: {
+      project_key: Buffer;
+      version_or_hash: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<u32>>>;
+  /**
+   * Construct and simulate a attest_build transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Attest that building a commit produces an artifact digest.
+   *
+   * Only members holding the `Verified` or `Developer` badge of the
+   * project can attest. Each of them attests a commit once.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `attester` - The address of the builder
+   * * `project_key` - The project key identifier
+   * * `commit_hash` - The anchored commit hash which was built
+   * * `digest` - The digest of the artifact, e.g. a WASM hash
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the project is archived or deregistered
+   * * If the attester does not hold a `Verified` or `Developer` badge
+   * * If the hash does not match the project's hash algorithm
+   * * If the commit hash was never anchored
+   * * If the attester already attested the commit
+   * * If the commit has too many attestations
+   */
+  attest_build: (
+    {
+      attester,
+      project_key,
+      commit_hash,
+      digest,
+    }: {
+      attester: string;
+      project_key: Buffer;
+      commit_hash: string;
+      digest: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a set_attestation_threshold transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how many matching attestations verify a build.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `threshold` - Number of matching attestations, at least 1
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the threshold is 0
+   */
+  set_attestation_threshold: (
+    {
+      maintainer,
+      project_key,
+      threshold,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      threshold: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_build_verification transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the attestations of a commit and whether its build is verified.
+   *
+   * A build is verified when exactly one digest has at least `threshold`
+   * attesters. Several digests are a sign of a non reproducible build or
+   * of a compromised builder.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `commit_hash` - The commit hash
+   *
+   * # Returns
+   * * `types::BuildVerification` - The verified digest if any, and the
+   * attesters of every digest
+   *
+   * # Panics
+   * * If the hash is not a valid hex hash
+   */
+  get_build_verification: (
+    {
+      project_key,
+      commit_hash,
+    }: {
+      project_key: Buffer;
+      commit_hash: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<BuildVerification>>;
+  /**
+   * Construct and simulate a add_dependency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Declare that a project depends on another project.
+   *
+   * The dependency is listed in the dependents of the other project so
+   * that advisories and yanks can be traced to the projects they affect.
+   * Declaring a dependency again updates its requirement.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The key of the dependent project
+   * * `dependency_key` - The key of the project depended on
+   * * `requirement` - Semver range of compatible releases, e.g. `>=1.2.0 <2.0.0`
+   *
+   * # Panics
+   * * If either project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the project depends on itself
+   * * If the requirement is not a valid semver range
+   * * If the project already has 20 dependencies
+   */
+  add_dependency: (
+    {
+      maintainer,
+      project_key,
+      dependency_key,
+      requirement,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      dependency_key: Buffer;
+      requirement: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a remove_dependency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove a dependency of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The key of the dependent project
+   * * `dependency_key` - The key of the project depended on
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the dependency was not declared
+   */
+  remove_dependency: (
+    {
+      maintainer,
+      project_key,
+      dependency_key,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      dependency_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_dependencies transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the dependencies of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `Vec<types::Dependency>` - The projects depended on and their requirement
+   */
+  get_dependencies: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<Dependency>>>;
+  /**
+   * Construct and simulate a get_dependents transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the projects depending on a project.
+   *
+   * Combined with `get_dependencies`, this tells which dependents have a
+   * requirement matching the versions of an advisory or a yanked release.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `page` - The page number (0-based)
+   *
+   * # Returns
+   * * `Vec<Bytes>` - Keys of the dependent projects, empty if none
+   */
+  get_dependents: (
+    {
+      project_key,
+      page,
+    }: {
+      project_key: Buffer;
+      page: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<Buffer>>>;
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pause or unpause the contract (emergency stop.)
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `admin` - The admin address
+   * * `paused` - Pause or unpause the contract operations which change
+   * ledger states.
+   */
+  pause: (
+    {
+      admin,
+      paused,
+    }: {
+      admin: string;
+      paused: boolean;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a require_not_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Require that the contract is not paused and that its storage layout
+   * is up to date, panic if not.
+   *
+   * The first call after an upgrade runs the migration of the upgrade
+   * proposal, if any.
+   *
+   * # Panics
+   * * If the contract is paused.
+   * * If the storage layout still has to be migrated, see `migrate`.
+   */
+  require_not_paused: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_admins_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current administrators configuration.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   *
+   * # Returns
+   * * `types::AdminsConfig` - The administrators configuration
+   */
+  get_admins_config: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<AdminsConfig>>;
+  /**
+   * Construct and simulate a set_domain_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the Soroban Domain contract.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `admin` - The admin address
+   * * `domain_contract` - The new domain contract
+   */
+  set_domain_contract: (
+    {
+      admin,
+      domain_contract,
+    }: {
+      admin: string;
+      domain_contract: Contract;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a set_collateral_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the Collateral contract.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `admin` - The admin address
+   * * `collateral_contract` - The new collateral contract
+   */
+  set_collateral_contract: (
+    {
+      admin,
+      collateral_contract,
+    }: {
+      admin: string;
+      collateral_contract: Contract;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a set_reserved_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reserve a project name or release it.
+   *
+   * A reserved name cannot be registered nor used to rename a project.
+   * Existing projects are not affected.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `admin` - The admin address
+   * * `name` - The project name, normalized before use
+   * * `reserved` - Reserve or release the name
+   *
+   * # Panics
+   * * If the admin is not authorized
+   * * If the name is invalid
+   */
+  set_reserved_name: (
+    {
+      admin,
+      name,
+      reserved,
+    }: {
+      admin: string;
+      name: string;
+      reserved: boolean;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a is_name_reserved transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if a project name is reserved.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `name` - The project name, normalized before use
+   *
+   * # Returns
+   * * `bool` - True if the name is reserved
+   *
+   * # Panics
+   * * If the name is invalid
+   */
+  is_name_reserved: (
+    {
+      name,
+    }: {
+      name: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+  /**
+   * Construct and simulate a propose_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a contract upgrade.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `admin` - An admin address
+   * * `new_wasm_hash` - The new WASM hash
+   * * `new_admins_config` - Optional new admin configuration (None to keep current)
+   * including the timelock of the next upgrades
+   * * `migration` - Optional migration to run after the upgrade, only
+   * `migrate` with its `limit` argument
+   *
+   * The upgrade can be executed once the timelock of the current
+   * configuration is over, and until the execution window closes. An
+   * expired proposal is cancelled by the next one.
+   *
+   * # Panics
+   * * If the admin is not authorized
+   * * If there is already an existing proposal (cancel the previous first)
+   * * If the new threshold or timelock is out of bounds
+   * * If the migration is not supported
+   */
+  propose_upgrade: (
+    {
+      admin,
+      new_wasm_hash,
+      new_admins_config,
+      migration,
+    }: {
+      admin: string;
+      new_wasm_hash: Buffer;
+      new_admins_config: Option<AdminsConfig>;
+      migration: Option<MigrationCall>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a approve_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve an upgrade proposal
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `admin` - An admin address
+   *
+   * # Panics
+   * * If the admin is not authorized
+   * * If the admin already approved
+   * * If there is no upgrade to approve or if it expired
+   */
+  approve_upgrade: (
+    {
+      admin,
+    }: {
+      admin: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a revoke_upgrade_approval transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke the approval of an upgrade proposal
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `admin` - An admin address
+   *
+   * # Panics
+   * * If the admin is not authorized
+   * * If the admin did not approve
+   * * If there is no upgrade to revoke from or if it expired
+   */
+  revoke_upgrade_approval: (
+    {
+      admin,
+    }: {
+      admin: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a finalize_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Execute or cancel upgrade proposal
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `admin` - An admin address
+   * * `accept` - true to accept and false to reject.
+   *
+   * Upgrades can always be cancelled but only executed if there are enough
+   * approvals and the timelock period is over.
+   * Note that current governance rules apply. New config changes only
+   * in force after an update.
+   *
+   * The new WASM only runs once this call ends, so the migration of the
+   * proposal cannot run here. It is scheduled instead and runs on the first
+   * call after the upgrade, before anything else. Calls changing the ledger
+   * fail with `MigrationPending` until the storage layout is up to date:
+   * if the migration does not complete in a single batch, admins finish it
+   * with `migrate`.
+   *
+   * # Panics
+   * * If the admin is not authorized
+   * * If it is too early to execute
+   * * If there are not enough approvals
+   * * If there is no upgrade to execute or if it expired
+   */
+  finalize_upgrade: (
+    {
+      admin,
+      accept,
+    }: {
+      admin: string;
+      accept: boolean;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_upgrade_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get upgrade proposal details
+   *
+   * # Panics
+   * * If there is no upgrade proposal or if it expired
+   */
+  get_upgrade_proposal: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<UpgradeProposal>>;
+  /**
+   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current version of the contract.
+   *
+   * This is the version of the code, see `get_migration_status` for the
+   * version of the storage layout.
+   *
+   * # Returns
+   * * `u32` - The contract version number
+   */
+  version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a register transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register a new project.
+   *
+   * Creates a new project entry with maintainers, URL, and commit hash.
+   * Also registers the project name in the domain contract if not already registered.
+   * The project key is the keccak256 hash of the name converted to lower case.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `name` - The project name (max 15 ASCII letters)
+   * * `maintainers` - List of maintainer addresses for the project
+   * * `url` - The project's Git repository URL
+   * * `ipfs` - CID of the tansu.toml file with associated metadata
+   *
+   * # Returns
+   * * `Bytes` - The project key (keccak256 hash of the name)
+   *
+   * # Panics
+   * * If the project name is empty or longer than 15 characters
+   * * If the project name contains anything else than ASCII letters
+   * * If the project already exists
+   * * If the project name is reserved
+   * * If the maintainer is not authorized
+   * * If the CID is malformed
+   * * If the domain registration fails
+   * * If the maintainer doesn't own an existing domain
+   */
+  register: (
+    {
+      maintainer,
+      name,
+      maintainers,
+      url,
+      ipfs,
+    }: {
+      maintainer: string;
+      name: string;
+      maintainers: Array<string>;
+      url: string;
+      ipfs: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+  /**
+   * Construct and simulate a register_fork transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register a new project as a fork of another project.
+   *
+   * Same as `register`, recording the upstream project and its current
+   * commit hash, if any. The fork uses the hash algorithm of the upstream
+   * project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `name` - The project name (max 15 ASCII letters)
+   * * `upstream_key` - The key of the forked project
+   * * `maintainers` - List of maintainer addresses for the project
+   * * `url` - The project's Git repository URL
+   * * `ipfs` - CID of the tansu.toml file with associated metadata
+   *
+   * # Returns
+   * * `Bytes` - The project key (keccak256 hash of the name)
+   *
+   * # Panics
+   * * If the upstream project doesn't exist
+   * * Same as `register`
+   */
+  register_fork: (
+    {
+      maintainer,
+      name,
+      upstream_key,
+      maintainers,
+      url,
+      ipfs,
+    }: {
+      maintainer: string;
+      name: string;
+      upstream_key: Buffer;
+      maintainers: Array<string>;
+      url: string;
+      ipfs: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+  /**
+   * Construct and simulate a get_forks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the forks of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `upstream_key` - The key of the forked project
+   * * `page` - The page number (0-based)
+   *
+   * # Returns
+   * * `Vec<Bytes>` - Keys of the forks, empty if none
+   */
+  get_forks: (
+    {
+      upstream_key,
+      page,
+    }: {
+      upstream_key: Buffer;
+      page: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<Buffer>>>;
+  /**
+   * Construct and simulate a update_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the configuration of an existing project.
+   *
+   * Allows maintainers to change the project's URL and IPFS metadata.
+   * Maintainers are changed with `propose_maintainers_change`.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `key` - The project key identifier
+   * * `url` - New Git repository URL
+   * * `ipfs` - New CID of the tansu.toml file with metadata
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the CID is malformed
+   */
+  update_config: (
+    {
+      maintainer,
+      key,
+      url,
+      ipfs,
+    }: {
+      maintainer: string;
+      key: Buffer;
+      url: string;
+      ipfs: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a set_metadata transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the structured metadata of a project.
+   *
+   * This mirrors the main fields of the tansu.toml file so that they can
+   * be read on-chain. Empty fields are not set.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `metadata` - The metadata
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the license is longer than 64 characters or not an SPDX expression
+   * * If the description is longer than 256 characters or has control
+   * characters
+   * * If there are more than 10 tags, or a tag is empty, longer than 32
+   * characters or not made of lowercase letters, digits and dashes
+   * * If there are more than 5 mirrors, or a mirror or the homepage is
+   * longer than 128 characters
+   * * If a mirror or a non-empty homepage is not an `https://` URL
+   * without spaces
+   */
+  set_metadata: (
+    {
+      maintainer,
+      project_key,
+      metadata,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      metadata: Metadata;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a propose_maintainers_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose to add and remove maintainers of a project.
+   *
+   * The change needs the approval of a quorum of the current maintainers,
+   * see `get_maintainers_quorum`, and can only be executed after a
+   * 24 hours delay. The proposer's approval is counted. A project has at
+   * most one pending change.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `add` - The addresses to add as maintainers
+   * * `remove` - The maintainers to remove
+   * * [`Option<quorum>`] - New quorum (None to keep current)
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If there is already a pending change (cancel the previous first)
+   * * If the change is empty, adds a maintainer or removes a non-maintainer
+   * * If the resulting maintainers are fewer than the quorum or the commit
+   * threshold
+   */
+  propose_maintainers_change: (
+    {
+      maintainer,
+      project_key,
+      add,
+      remove,
+      quorum,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      add: Array<string>;
+      remove: Array<string>;
+      quorum: Option<u32>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a approve_maintainers_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve the pending change of maintainers.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If there is no change to approve
+   * * If the maintainer already approved
+   */
+  approve_maintainers_change: (
+    {
+      maintainer,
+      project_key,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a finalize_maintainers_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Execute or cancel the pending change of maintainers.
+   *
+   * Changes can always be cancelled but only executed if enough current
+   * maintainers approved it and the delay is over. Emits a
+   * `MaintainerAdded` or `MaintainerRemoved` event per address.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `accept` - true to execute and false to cancel
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If there is no change to execute
+   * * If there are not enough approvals
+   * * If it is too early to execute
+   * * If the change is not valid anymore
+   */
+  finalize_maintainers_change: (
+    {
+      maintainer,
+      project_key,
+      accept,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      accept: boolean;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_maintainers_change transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the pending change of maintainers.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `types::MaintainersChange` - The pending change
+   *
+   * # Panics
+   * * If there is no pending change
+   */
+  get_maintainers_change: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<MaintainersChange>>;
+  /**
+   * Construct and simulate a get_maintainers_quorum transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get how many maintainer approvals are needed to change the maintainers.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `u32` - The quorum, a majority of the maintainers if not set
+   *
+   * # Panics
+   * * If the project doesn't exist
+   */
+  get_maintainers_quorum: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a archive_project transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Archive a project, making it read-only.
+   *
+   * Commits, releases, proposals, votes and badge changes are rejected
+   * while the history stays queryable. Open proposals can still be
+   * executed or revoked. An admin archives a project directly, otherwise
+   * each call is a maintainer approval and the project is archived once
+   * the maintainers quorum is reached.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `caller` - An admin or a maintainer of the project
+   * * `project_key` - The project key identifier
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the caller is neither an admin nor a maintainer
+   * * If the project is already archived or deregistered
+   * * If the maintainer already approved
+   */
+  archive_project: (
+    {
+      caller,
+      project_key,
+    }: {
+      caller: string;
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a deregister_project transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deregister a project.
+   *
+   * The project is removed from the projects of its maintainers and
+   * members, and becomes read-only like an archived project. It keeps its
+   * place in the projects listing so that cursors stay valid, but is no
+   * longer returned. Its data stays queryable and its
+   * name cannot be registered again. An admin deregisters a project
+   * directly, otherwise each call is a maintainer approval and the
+   * project is deregistered once the maintainers quorum is reached.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `caller` - An admin or a maintainer of the project
+   * * `project_key` - The project key identifier
+   * * `release_domain` - Burn the Soroban domain of the current project
+   * name, the domain owner must authorize it
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the caller is neither an admin nor a maintainer
+   * * If the project is already deregistered
+   * * If the maintainer already approved
+   * * If the domain cannot be released
+   */
+  deregister_project: (
+    {
+      caller,
+      project_key,
+      release_domain,
+    }: {
+      caller: string;
+      project_key: Buffer;
+      release_domain: boolean;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_project_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the lifecycle status of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `types::ProjectStatus` - The status of the project
+   *
+   * # Panics
+   * * If the project doesn't exist
+   */
+  get_project_status: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<ProjectStatus>>;
+  /**
+   * Construct and simulate a rename_project transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rename a project.
+   *
+   * The domain of the new name is registered, or must be owned by the
+   * maintainer approving last. The project keeps the key it was
+   * registered with and the key of the new name becomes an alias of it,
+   * every call accepts both keys. See `resolve_project_key`.
+   *
+   * Each call is a maintainer approval and the project is renamed once
+   * the maintainers quorum is reached.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `name` - The new project name (max 15 ASCII letters)
+   *
+   * # Returns
+   * * `Bytes` - The key of the new name (keccak256 hash of the name)
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the project is archived or deregistered
+   * * If the new name is not 1 to 15 ASCII letters
+   * * If the new name is used by a project or reserved
+   * * If the maintainer already approved
+   * * If the domain registration fails
+   * * If the maintainer doesn't own an existing domain
+   */
+  rename_project: (
+    {
+      maintainer,
+      project_key,
+      name,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      name: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+  /**
+   * Construct and simulate a resolve_project_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the registered key of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The registered key or the key of a new name
+   *
+   * # Returns
+   * * `Bytes` - The key the project was registered with
+   *
+   * # Panics
+   * * If the project doesn't exist
+   */
+  resolve_project_key: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+  /**
+   * Construct and simulate a transfer_project transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer a project to new maintainers.
+   *
+   * Each call is a maintainer approval and the offer is made once the
+   * maintainers quorum is reached. It replaces any previous offer. The new
+   * maintainers take over with `accept_transfer`.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `maintainers` - The new maintainers
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the project is archived or deregistered
+   * * If the new maintainers are empty or contain duplicates
+   * * If the maintainer already approved
+   */
+  transfer_project: (
+    {
+      maintainer,
+      project_key,
+      maintainers,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      maintainers: Array<string>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a accept_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept the transfer of a project.
+   *
+   * Every new maintainer must authorize the call. They replace the
+   * maintainers, the maintainers quorum is reset to a majority and the
+   * commit threshold is lowered to the number of new maintainers if
+   * needed. Pending commits and maintainers changes are dropped.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Panics
+   * * If there is no transfer offer
+   * * If a new maintainer did not authorize the call
+   * * If the project is archived or deregistered
+   */
+  accept_transfer: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a cancel_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel the transfer offer of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If there is no transfer offer
+   */
+  cancel_transfer: (
+    {
+      maintainer,
+      project_key,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_transfer_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the maintainers a project is offered to.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `Vec<Address>` - The new maintainers
+   *
+   * # Panics
+   * * If there is no transfer offer
+   */
+  get_transfer_offer: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<string>>>;
+  /**
+   * Construct and simulate a commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the latest commit hash for a project.
+   *
+   * The hash is appended to the commit history. A given `parent_hash` must
+   * match the current commit hash, see `force_commit` for rewrites. With
+   * a commit threshold above 1 this is an approval, see `approve_commit`.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The maintainer or delegate calling this function
+   * * `project_key` - The project key identifier
+   * * `hash` - The new commit hash
+   * * [`Option<parent_hash>`] - The expected current commit hash
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer or delegate is not authorized
+   * * If the hash does not match the project's hash algorithm
+   * * If the parent hash does not match the current commit hash
+   */
+  commit: (
+    {
+      maintainer,
+      project_key,
+      hash,
+      parent_hash,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      hash: string;
+      parent_hash: Option<string>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a force_commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the latest commit hash for a project, rewriting its history.
+   *
+   * Same as `commit` without any parent hash check. This is meant for
+   * deliberate history rewrites (e.g. force-push) and emits a dedicated
+   * `HistoryRewritten` event so that rewrites are visible to everyone.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `hash` - The new commit hash
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the project requires more than one maintainer approval
+   * * If the hash does not match the project's hash algorithm
+   */
+  force_commit: (
+    {
+      maintainer,
+      project_key,
+      hash,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      hash: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a approve_commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve a commit hash for a branch of a project.
+   *
+   * The hash only becomes the head of the branch once enough distinct
+   * maintainers approved it (see `set_commit_threshold`) within the
+   * approval window. Approvals which are not completed in time expire.
+   * With a threshold of 1, this directly anchors the hash.
+   *
+   * Delegates can propose a commit this way, but only maintainers'
+   * approvals count towards the threshold.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer or delegate calling this function
+   * * `project_key` - The project key identifier
+   * * `branch` - The branch name
+   * * `hash` - The commit hash to approve
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer or delegate is not authorized
+   * * If the hash does not match the project's hash algorithm
+   * * If the maintainer already approved this commit
+   * * If there are too many pending commits
+   * * If the head moved away from the parent hash of the commit
+   */
+  approve_commit: (
+    {
+      maintainer,
+      project_key,
+      branch,
+      hash,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      branch: string;
+      hash: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_pending_commits transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the commits of a project waiting for maintainer approvals.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `Vec<types::PendingCommit>` - Pending commits which did not expire
+   */
+  get_pending_commits: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<PendingCommit>>>;
+  /**
+   * Construct and simulate a set_hash_algorithm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Declare the hash algorithm of the project's version control system.
+   *
+   * Commit hashes are validated against it.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `hash_algorithm` - The hash algorithm, `Any` accepts 40 or 64
+   * characters hex hashes
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   */
+  set_hash_algorithm: (
+    {
+      maintainer,
+      project_key,
+      hash_algorithm,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      hash_algorithm: HashAlgorithm;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a set_commit_threshold transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how many maintainer approvals are needed to anchor a commit.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `threshold` - Number of distinct maintainer approvals (K-of-N)
+   * * `approval_window` - Time in seconds to gather the approvals
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the threshold is 0 or larger than the number of maintainers
+   * * If the approval window is 0
+   */
+  set_commit_threshold: (
+    {
+      maintainer,
+      project_key,
+      threshold,
+      approval_window,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      threshold: u32;
+      approval_window: u64;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a add_delegate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add a delegate allowed to commit on behalf of the maintainers.
+   *
+   * Delegates are meant for automation such as CI, so that maintainer keys
+   * do not need to be shared. They can only commit, optionally limited to
+   * some branches, until they expire. Adding an existing delegate replaces
+   * its scope.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `delegate` - The address of the delegate
+   * * `branches` - The branches the delegate can commit to, empty for any
+   * * `expires_at` - Ledger timestamp after which the delegate is invalid
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If there are too many branches or a branch name is invalid
+   * * If the expiry is not in the future
+   */
+  add_delegate: (
+    {
+      maintainer,
+      project_key,
+      delegate,
+      branches,
+      expires_at,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      delegate: string;
+      branches: Array<string>;
+      expires_at: u64;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a revoke_delegate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a delegate.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `delegate` - The address of the delegate
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the delegate doesn't exist
+   */
+  revoke_delegate: (
+    {
+      maintainer,
+      project_key,
+      delegate,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      delegate: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_delegate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the scope of a delegate.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `delegate` - The address of the delegate
+   *
+   * # Returns
+   * * `Option<types::Delegate>` - The delegate, None if unknown or revoked
+   */
+  get_delegate: (
+    {
+      project_key,
+      delegate,
+    }: {
+      project_key: Buffer;
+      delegate: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Delegate>>>;
+  /**
+   * Construct and simulate a get_commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the latest commit hash for a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `String` - The current commit hash
+   *
+   * # Panics
+   * * If the project doesn't exist
+   */
+  get_commit: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+  /**
+   * Construct and simulate a commit_branch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the head commit hash of a branch of a project.
+   *
+   * Committing to the default branch (`main`) is the same as calling
+   * `commit` without a parent hash. Other branches are created on their
+   * first commit. Every commit is appended to the project's history.
+   *
+   * If the project requires more than one maintainer approval, this
+   * counts as an approval, see `approve_commit`.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer or delegate calling this function
+   * * `project_key` - The project key identifier
+   * * `branch` - The branch name
+   * * `hash` - The new commit hash
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer or delegate is not authorized
+   * * If the branch name is empty or too long
+   * * If the project already tracks too many branches
+   */
+  commit_branch: (
+    {
+      maintainer,
+      project_key,
+      branch,
+      hash,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      branch: string;
+      hash: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_branch_head transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the head commit hash of a branch of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `branch` - The branch name
+   *
+   * # Returns
+   * * `String` - The head commit hash of the branch
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the branch has no commit
+   */
+  get_branch_head: (
+    {
+      project_key,
+      branch,
+    }: {
+      project_key: Buffer;
+      branch: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+  /**
+   * Construct and simulate a list_branches transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * List the branches of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `Vec<String>` - Branch names, starting with the default branch if
+   * it has a commit
+   */
+  list_branches: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<string>>>;
+  /**
+   * Construct and simulate a delete_branch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Delete a branch of a project.
+   *
+   * Only the branch head is removed, its commits stay in the history.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `branch` - The branch name
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the branch is the default branch
+   * * If the branch doesn't exist
+   */
+  delete_branch: (
+    {
+      maintainer,
+      project_key,
+      branch,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      branch: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_commit_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the commit history of a project.
+   *
+   * Commits are ordered from oldest to newest.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `page` - The page number (0-based)
+   *
+   * # Returns
+   * * `Vec<types::CommitRecord>` - List of commits on the requested page,
+   * empty if the page does not exist
+   *
+   * # Panics
+   * * If the project doesn't exist
+   */
+  get_commit_history: (
+    {
+      project_key,
+      page,
+    }: {
+      project_key: Buffer;
+      page: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<CommitRecord>>>;
+  /**
+   * Construct and simulate a get_commit_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a single entry of the commit history of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `index` - The position of the commit in the history (0-based)
+   *
+   * # Returns
+   * * `types::CommitRecord` - The commit anchored at that position
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If there is no commit at this index
+   */
+  get_commit_at: (
+    {
+      project_key,
+      index,
+    }: {
+      project_key: Buffer;
+      index: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<CommitRecord>>;
+  /**
+   * Construct and simulate a tag_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Tag a release of a project.
+   *
+   * Releases are named with a semver version and point to a commit hash
+   * which must already be anchored with `commit`. Tags are immutable:
+   * once a version is published it cannot be moved to another commit.
+   * Build metadata is ignored when comparing versions, so `1.0.0+a` and
+   * `1.0.0+b` are the same release.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `version` - The semver version of the release
+   * * `commit_hash` - The anchored commit hash of the release
+   * * `ipfs_notes` - CID of the release notes
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the version is not a valid semver version
+   * * If the version was already released
+   * * If the hash does not match the project's hash algorithm
+   * * If the commit hash was never anchored
+   * * If the CID is malformed
+   */
+  tag_release: (
+    {
+      maintainer,
+      project_key,
+      version,
+      commit_hash,
+      ipfs_notes,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      version: string;
+      commit_hash: string;
+      ipfs_notes: string;
     },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<null>>;
   /**
-   * Construct and simulate a finalize_upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Execute or cancel upgrade proposal
+   * Construct and simulate a get_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a release of a project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `version` - The semver version of the release
+   *
+   * # Returns
+   * * `types::Release` - The release
+   *
+   * # Panics
+   * * If the version is not a valid semver version
+   * * If there is no such release
+   */
+  get_release: (
+    {
+      project_key,
+      version,
+    }: {
+      project_key: Buffer;
+      version: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Release>>;
+  /**
+   * Construct and simulate a list_releases transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of releases of a project, in publication order.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `page` - The page number (0-based)
+   *
+   * # Returns
+   * * `Vec<types::Release>` - List of releases on the requested page,
+   * empty if the page does not exist
+   *
+   * # Panics
+   * * If the project doesn't exist
+   */
+  list_releases: (
+    {
+      project_key,
+      page,
+    }: {
+      project_key: Buffer;
+      page: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<Release>>>;
+  /**
+   * Construct and simulate a latest_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the latest release of a project.
+   *
+   * The latest release is the one with the highest semver precedence,
+   * pre-releases are not considered.
    *
    * # Arguments
    * * `env` - The environment object
-   * * `admin` - An admin address
-   * * `accept` - true to accept and false to reject.
+   * * `project_key` - The project key identifier
    *
-   * Upgrades can always be cancelled but only executed if there are enough
-   * approvals and the timelock period is over.
-   * Note that current governance rules apply. New config changes only
-   * in force after an update.
+   * # Returns
+   * * `types::Release` - The latest release
    *
    * # Panics
-   * * If the admin is not authorized
-   * * If it is too early to execute
-   * * If there are not enough approvals
-   * * If there is no upgrade to execute
+   * * If the project has no stable release
    */
-  finalize_upgrade: (
+  latest_release: (
     {
-      admin,
-      accept,
+      project_key,
     }: {
-      admin: string;
-      accept: boolean;
+      project_key: Buffer;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Release>>;
   /**
-   * Construct and simulate a get_upgrade_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get upgrade proposal details
+   * Construct and simulate a publish_manifest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Publish the Merkle root over the files or artifacts of a commit.
+   *
+   * A manifest is immutable once published.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer calling this function
+   * * `project_key` - The project key identifier
+   * * `commit_hash` - The anchored commit hash
+   * * `root` - The Merkle root
+   * * `hash_function` - The hash function used to build the tree
+   *
+   * # Panics
+   * * If the project doesn't exist
+   * * If the maintainer is not authorized
+   * * If the hash does not match the project's hash algorithm
+   * * If the commit hash was never anchored
+   * * If a manifest was already published for the commit
    */
-  get_upgrade_proposal: (
+  publish_manifest: (
+    {
+      maintainer,
+      project_key,
+      commit_hash,
+      root,
+      hash_function,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      commit_hash: string;
+      root: Buffer;
+      hash_function: MerkleHash;
+    },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<UpgradeProposal>>;
+  ) => Promise<AssembledTransaction<null>>;
   /**
-   * Construct and simulate a version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current version of the contract.
+   * Construct and simulate a get_manifest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the manifest published for a commit.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `commit_hash` - The commit hash
    *
    * # Returns
-   * * `u32` - The contract version number
+   * * `types::Manifest` - The Merkle root and how it was built
+   *
+   * # Panics
+   * * If the hash is not a valid hex hash
+   * * If no manifest was published for the commit
    */
-  version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>;
+  get_manifest: (
+    {
+      project_key,
+      commit_hash,
+    }: {
+      project_key: Buffer;
+      commit_hash: string;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Manifest>>;
   /**
-   * Construct and simulate a register transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Register a new project.
+   * Construct and simulate a verify_inclusion transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Prove that a file or an artifact belongs to an anchored commit.
    *
-   * Creates a new project entry with maintainers, URL, and commit hash.
-   * Also registers the project name in the domain contract if not already registered.
-   * The project key is generated using keccak256 hash of the project name.
+   * The leaf is the hash of the file and the proof lists the sibling
+   * nodes from the leaf up to the root. Pairs of nodes are sorted before
+   * being hashed together, so the proof does not need to tell on which
+   * side each sibling is.
    *
    * # Arguments
    * * `env` - The environment object
-   * * `maintainer` - The address of the maintainer calling this function
-   * * `name` - The project name (max 15 characters)
-   * * `maintainers` - List of maintainer addresses for the project
-   * * `url` - The project's Git repository URL
-   * * `ipfs` - CID of the tansu.toml file with associated metadata
+   * * `project_key` - The project key identifier
+   * * `commit_hash` - The commit hash
+   * * `leaf` - The hash of the file or artifact
+   * * `proof` - The sibling nodes from the leaf to the root
    *
    * # Returns
-   * * `Bytes` - The project key (keccak256 hash of the name)
+   * * `bool` - True if the proof leads to the published root
    *
    * # Panics
-   * * If the project name is longer than 15 characters
-   * * If the project already exists
-   * * If the maintainer is not authorized
-   * * If the domain registration fails
-   * * If the maintainer doesn't own an existing domain
+   * * If the hash is not a valid hex hash
+   * * If no manifest was published for the commit
    */
-  register: (
+  verify_inclusion: (
     {
-      maintainer,
-      name,
-      maintainers,
-      url,
-      ipfs,
+      project_key,
+      commit_hash,
+      leaf,
+      proof,
     }: {
-      maintainer: string;
-      name: string;
-      maintainers: Array<string>;
-      url: string;
-      ipfs: string;
+      project_key: Buffer;
+      commit_hash: string;
+      leaf: Buffer;
+      proof: Array<Buffer>;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<Buffer>>;
+  ) => Promise<AssembledTransaction<boolean>>;
   /**
-   * Construct and simulate a update_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the configuration of an existing project.
-   *
-   * Allows maintainers to change the project's URL, IPFS metadata, and maintainer list.
+   * Construct and simulate a get_project transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get project information including configuration and maintainers.
    *
    * # Arguments
    * * `env` - The environment object
-   * * `maintainer` - The address of the maintainer calling this function
-   * * `key` - The project key identifier
-   * * `maintainers` - New list of maintainer addresses
-   * * `url` - New Git repository URL
-   * * `ipfs` - New CID of the tansu.toml file with metadata
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `types::Project` - Project information including name, config, and maintainers
    *
    * # Panics
    * * If the project doesn't exist
-   * * If the maintainer is not authorized
    */
-  update_config: (
+  get_project: (
     {
-      maintainer,
-      key,
-      maintainers,
-      url,
-      ipfs,
+      project_key,
     }: {
-      maintainer: string;
-      key: Buffer;
-      maintainers: Array<string>;
-      url: string;
-      ipfs: string;
+      project_key: Buffer;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<Project>>;
   /**
-   * Construct and simulate a commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set the latest commit hash for a project.
+   * Construct and simulate a get_project_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a summary of a project.
    *
-   * Updates the current commit hash for the specified project.
+   * Lighter than `get_project` as the maintainers are only counted.
    *
    * # Arguments
    * * `env` - The environment object
-   * * `maintainer` - The address of the maintainer calling this function
    * * `project_key` - The project key identifier
-   * * `hash` - The new commit hash
+   *
+   * # Returns
+   * * `types::ProjectSummary` - Name, links, status and metadata of the project
    *
    * # Panics
    * * If the project doesn't exist
-   * * If the maintainer is not authorized
    */
-  commit: (
+  get_project_summary: (
     {
-      maintainer,
       project_key,
-      hash,
     }: {
-      maintainer: string;
       project_key: Buffer;
-      hash: string;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<null>>;
+  ) => Promise<AssembledTransaction<ProjectSummary>>;
   /**
-   * Construct and simulate a get_commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the latest commit hash for a project.
+   * Construct and simulate a extend_project_ttl transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of the entries of a project, a batch at a time.
+   *
+   * Anyone can call this to keep a project from being archived, even while
+   * the contract is paused. Start with a cursor of 0 and call it again
+   * with the returned cursor until it returns None. Entries keyed by a
+   * commit hash, delegates and former aliases are only extended when they
+   * are used.
    *
    * # Arguments
    * * `env` - The environment object
    * * `project_key` - The project key identifier
+   * * `cursor` - The position of the first entry to extend
+   * * `limit` - The maximum number of entries to extend (1 to 30)
    *
    * # Returns
-   * * `String` - The current commit hash
+   * * `Option<u32>` - The cursor of the next batch, None once done
    *
    * # Panics
    * * If the project doesn't exist
    */
-  get_commit: (
+  extend_project_ttl: (
     {
       project_key,
+      cursor,
+      limit,
     }: {
       project_key: Buffer;
+      cursor: u32;
+      limit: u32;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<string>>;
+  ) => Promise<AssembledTransaction<Option<u32>>>;
   /**
-   * Construct and simulate a get_project transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get project information including configuration and maintainers.
+   * Construct and simulate a get_ttl_report transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the TTL policy applied to a project.
+   *
+   * Contracts cannot read the TTL of an entry, the report is based on the
+   * last completed run of `extend_project_ttl`.
    *
    * # Arguments
    * * `env` - The environment object
    * * `project_key` - The project key identifier
    *
    * # Returns
-   * * `types::Project` - Project information including name, config, and maintainers
+   * * `types::TtlReport` - The TTL report
    *
    * # Panics
    * * If the project doesn't exist
    */
-  get_project: (
+  get_ttl_report: (
     {
       project_key,
     }: {
       project_key: Buffer;
     },
     options?: MethodOptions,
-  ) => Promise<AssembledTransaction<Project>>;
+  ) => Promise<AssembledTransaction<TtlReport>>;
   /**
    * Construct and simulate a get_projects transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get a page of projects.
    *
+   * Pages list 10 projects, deregistered projects are left out. See
+   * `list_projects` for lighter results.
+   *
    * # Arguments
    * * `env` - The environment object
    * * `page` - The page number (0-based)
    *
    * # Returns
    * * `Vec<types::Project>` - List of projects on the requested page
+   *
+   * # Panics
+   * * If the page doesn't exist
    */
   get_projects: (
     {
@@ -1113,6 +3457,59 @@ export interface Client {
     },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<Array<Project>>>;
+  /**
+   * Construct and simulate a list_projects transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * List projects from a cursor.
+   *
+   * Deregistered projects are left out.
+   * At most 50 projects are looked at per call, so that a filtered result
+   * can be empty while `next_cursor` is set.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `cursor` - The position to start from, 0 or a previous `next_cursor`
+   * * `limit` - The maximum number of projects (1 to 20)
+   * * `filter` - The projects to keep
+   *
+   * # Returns
+   * * `types::ProjectList` - The projects and the cursor of the next call
+   */
+  list_projects: (
+    {
+      cursor,
+      limit,
+      filter,
+    }: {
+      cursor: u32;
+      limit: u32;
+      filter: ProjectFilter;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<ProjectList>>;
+  /**
+   * Construct and simulate a get_projects_by_maintainer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the projects maintained by an address.
+   *
+   * Archived projects are included, deregistered ones are not.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The maintainer address
+   * * `page` - The page number (0-based)
+   *
+   * # Returns
+   * * `Vec<Bytes>` - Project keys on the requested page, empty if none
+   */
+  get_projects_by_maintainer: (
+    {
+      maintainer,
+      page,
+    }: {
+      maintainer: string;
+      page: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<Buffer>>>;
   /**
    * Construct and simulate a get_sub_projects transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get sub-projects for a project (if it's an organization).
@@ -1136,10 +3533,9 @@ export interface Client {
    * Construct and simulate a set_sub_projects transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Set sub-projects for a project (making it an organization).
    *
-   * Note: by design, sub-project keys are not validated against existing
-   * projects. This allows reserving a project space before the project is
-   * registered (since the key is derived from the name). A project can
-   * also appear in multiple organizations.
+   * Sub-projects must be registered and belong to no other organization.
+   * Their maintainers must first agree to join with `accept_parent`.
+   * Sub-projects missing from the new list are detached.
    *
    * # Arguments
    * * `env` - The environment object
@@ -1151,6 +3547,10 @@ export interface Client {
    * * If the project doesn't exist
    * * If the maintainer is not authorized
    * * If more than 10 sub-projects are provided
+   * * If a sub-project doesn't exist
+   * * If a sub-project is listed twice or belongs to another organization
+   * * If a sub-project is the project itself or one of its parents
+   * * If a sub-project did not agree to join
    */
   set_sub_projects: (
     {
@@ -1164,6 +3564,82 @@ export interface Client {
     },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a accept_parent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Agree to join an organization as one of its sub-projects.
+   *
+   * The organization then adds the project with `set_sub_projects`. A new
+   * call replaces the previous agreement.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `maintainer` - The maintainer address calling this function
+   * * `project_key` - The project key identifier
+   * * `parent_key` - The key of the organization
+   *
+   * # Panics
+   * * If the project or the organization doesn't exist
+   * * If the maintainer is not authorized
+   */
+  accept_parent: (
+    {
+      maintainer,
+      project_key,
+      parent_key,
+    }: {
+      maintainer: string;
+      project_key: Buffer;
+      parent_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+  /**
+   * Construct and simulate a get_parent transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the organization a project belongs to.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   *
+   * # Returns
+   * * `Option<Bytes>` - The key of the organization, if any
+   */
+  get_parent: (
+    {
+      project_key,
+    }: {
+      project_key: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Buffer>>>;
+  /**
+   * Construct and simulate a get_project_tree transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a project and its sub-projects, recursively.
+   *
+   * The nodes are listed depth first, each sub-project after its parent.
+   * The depth is capped at 5 levels below the project.
+   *
+   * # Arguments
+   * * `env` - The environment object
+   * * `project_key` - The project key identifier
+   * * `depth` - Levels of sub-projects to include, 0 for the project alone
+   *
+   * # Returns
+   * * `Vec<types::ProjectNode>` - The project followed by its sub-projects
+   *
+   * # Panics
+   * * If the project doesn't exist
+   */
+  get_project_tree: (
+    {
+      project_key,
+      depth,
+    }: {
+      project_key: Buffer;
+      depth: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<ProjectNode>>>;
 }
 export declare class Client extends ContractClient {
   readonly options: ContractClientOptions;
@@ -1205,29 +3681,118 @@ export declare class Client extends ContractClient {
     get_member: (json: string) => AssembledTransaction<Member>;
     set_badges: (json: string) => AssembledTransaction<null>;
     get_badges: (json: string) => AssembledTransaction<Badges>;
+    get_projects_by_member: (
+      json: string,
+    ) => AssembledTransaction<Buffer<ArrayBufferLike>[]>;
     get_max_weight: (json: string) => AssembledTransaction<number>;
     add_projects_to_pagination: (json: string) => AssembledTransaction<null>;
+    migrate: (json: string) => AssembledTransaction<MigrationStatus>;
+    get_migration_status: (
+      json: string,
+    ) => AssembledTransaction<MigrationStatus>;
+    verify_project_index: (json: string) => AssembledTransaction<IndexReport>;
+    yank: (json: string) => AssembledTransaction<null>;
+    get_yank: (json: string) => AssembledTransaction<Option<Yank>>;
+    publish_advisory: (json: string) => AssembledTransaction<number>;
+    get_advisory: (json: string) => AssembledTransaction<Advisory>;
+    is_version_affected: (json: string) => AssembledTransaction<number[]>;
+    attest_build: (json: string) => AssembledTransaction<null>;
+    set_attestation_threshold: (json: string) => AssembledTransaction<null>;
+    get_build_verification: (
+      json: string,
+    ) => AssembledTransaction<BuildVerification>;
+    add_dependency: (json: string) => AssembledTransaction<null>;
+    remove_dependency: (json: string) => AssembledTransaction<null>;
+    get_dependencies: (json: string) => AssembledTransaction<Dependency[]>;
+    get_dependents: (
+      json: string,
+    ) => AssembledTransaction<Buffer<ArrayBufferLike>[]>;
     pause: (json: string) => AssembledTransaction<null>;
     require_not_paused: (json: string) => AssembledTransaction<null>;
     get_admins_config: (json: string) => AssembledTransaction<AdminsConfig>;
     set_domain_contract: (json: string) => AssembledTransaction<null>;
     set_collateral_contract: (json: string) => AssembledTransaction<null>;
+    set_reserved_name: (json: string) => AssembledTransaction<null>;
+    is_name_reserved: (json: string) => AssembledTransaction<boolean>;
     propose_upgrade: (json: string) => AssembledTransaction<null>;
     approve_upgrade: (json: string) => AssembledTransaction<null>;
+    revoke_upgrade_approval: (json: string) => AssembledTransaction<null>;
     finalize_upgrade: (json: string) => AssembledTransaction<null>;
     get_upgrade_proposal: (
       json: string,
     ) => AssembledTransaction<UpgradeProposal>;
     version: (json: string) => AssembledTransaction<number>;
     register: (json: string) => AssembledTransaction<Buffer<ArrayBufferLike>>;
+    register_fork: (
+      json: string,
+    ) => AssembledTransaction<Buffer<ArrayBufferLike>>;
+    get_forks: (
+      json: string,
+    ) => AssembledTransaction<Buffer<ArrayBufferLike>[]>;
     update_config: (json: string) => AssembledTransaction<null>;
+    set_metadata: (json: string) => AssembledTransaction<null>;
+    propose_maintainers_change: (json: string) => AssembledTransaction<null>;
+    approve_maintainers_change: (json: string) => AssembledTransaction<null>;
+    finalize_maintainers_change: (json: string) => AssembledTransaction<null>;
+    get_maintainers_change: (
+      json: string,
+    ) => AssembledTransaction<MaintainersChange>;
+    get_maintainers_quorum: (json: string) => AssembledTransaction<number>;
+    archive_project: (json: string) => AssembledTransaction<null>;
+    deregister_project: (json: string) => AssembledTransaction<null>;
+    get_project_status: (json: string) => AssembledTransaction<ProjectStatus>;
+    rename_project: (
+      json: string,
+    ) => AssembledTransaction<Buffer<ArrayBufferLike>>;
+    resolve_project_key: (
+      json: string,
+    ) => AssembledTransaction<Buffer<ArrayBufferLike>>;
+    transfer_project: (json: string) => AssembledTransaction<null>;
+    accept_transfer: (json: string) => AssembledTransaction<null>;
+    cancel_transfer: (json: string) => AssembledTransaction<null>;
+    get_transfer_offer: (json: string) => AssembledTransaction<string[]>;
     commit: (json: string) => AssembledTransaction<null>;
+    force_commit: (json: string) => AssembledTransaction<null>;
+    approve_commit: (json: string) => AssembledTransaction<null>;
+    get_pending_commits: (
+      json: string,
+    ) => AssembledTransaction<PendingCommit[]>;
+    set_hash_algorithm: (json: string) => AssembledTransaction<null>;
+    set_commit_threshold: (json: string) => AssembledTransaction<null>;
+    add_delegate: (json: string) => AssembledTransaction<null>;
+    revoke_delegate: (json: string) => AssembledTransaction<null>;
+    get_delegate: (json: string) => AssembledTransaction<Option<Delegate>>;
     get_commit: (json: string) => AssembledTransaction<string>;
+    commit_branch: (json: string) => AssembledTransaction<null>;
+    get_branch_head: (json: string) => AssembledTransaction<string>;
+    list_branches: (json: string) => AssembledTransaction<string[]>;
+    delete_branch: (json: string) => AssembledTransaction<null>;
+    get_commit_history: (json: string) => AssembledTransaction<CommitRecord[]>;
+    get_commit_at: (json: string) => AssembledTransaction<CommitRecord>;
+    tag_release: (json: string) => AssembledTransaction<null>;
+    get_release: (json: string) => AssembledTransaction<Release>;
+    list_releases: (json: string) => AssembledTransaction<Release[]>;
+    latest_release: (json: string) => AssembledTransaction<Release>;
+    publish_manifest: (json: string) => AssembledTransaction<null>;
+    get_manifest: (json: string) => AssembledTransaction<Manifest>;
+    verify_inclusion: (json: string) => AssembledTransaction<boolean>;
     get_project: (json: string) => AssembledTransaction<Project>;
+    get_project_summary: (json: string) => AssembledTransaction<ProjectSummary>;
+    extend_project_ttl: (json: string) => AssembledTransaction<Option<number>>;
+    get_ttl_report: (json: string) => AssembledTransaction<TtlReport>;
     get_projects: (json: string) => AssembledTransaction<Project[]>;
+    list_projects: (json: string) => AssembledTransaction<ProjectList>;
+    get_projects_by_maintainer: (
+      json: string,
+    ) => AssembledTransaction<Buffer<ArrayBufferLike>[]>;
     get_sub_projects: (
       json: string,
     ) => AssembledTransaction<Buffer<ArrayBufferLike>[]>;
     set_sub_projects: (json: string) => AssembledTransaction<null>;
+    accept_parent: (json: string) => AssembledTransaction<null>;
+    get_parent: (
+      json: string,
+    ) => AssembledTransaction<Option<Buffer<ArrayBufferLike>>>;
+    get_project_tree: (json: string) => AssembledTransaction<ProjectNode[]>;
   };
 }
@@ -15,6 +15,8 @@ export const ContractErrors = {
   100: { message: "UnauthorizedSigner" },
   101: { message: "WrongVoter" },
   102: { message: "MaintainerNotDomainOwner" },
+  103: { message: "CommitApprovalRequired" },
+  104: { message: "QuorumNotReached" },
   200: { message: "InvalidKey" },
   201: { message: "ProjectAlreadyExist" },
   202: { message: "TooManySubProjects" },
@@ -26,20 +28,55 @@ export const ContractErrors = {
   208: { message: "BadCommitment" },
   209: { message: "VoterWeight" },
   210: { message: "VoteLimitExceeded" },
+  211: { message: "ParentHashMismatch" },
+  212: { message: "InvalidVersion" },
+  213: { message: "ReleaseAlreadyExist" },
+  214: { message: "HashAlreadyYanked" },
+  215: { message: "TooManyBranches" },
+  216: { message: "InvalidBranch" },
+  217: { message: "InvalidThreshold" },
+  218: { message: "TooManyPendingCommits" },
+  219: { message: "InvalidHashLength" },
+  220: { message: "InvalidHashEncoding" },
+  221: { message: "ManifestAlreadyExist" },
+  222: { message: "AlreadyAttested" },
+  223: { message: "TooManyAttestations" },
+  224: { message: "InvalidMaintainersChange" },
+  225: { message: "InvalidSubProject" },
+  226: { message: "SubProjectCycle" },
+  227: { message: "TooManyDependencies" },
+  228: { message: "InvalidMetadata" },
+  229: { message: "InvalidCid" },
+  230: { message: "InvalidDomainLength" },
+  231: { message: "InvalidDomainCharacter" },
+  232: { message: "ReservedDomain" },
   300: { message: "NoHashFound" },
   301: { message: "NoProposalorPageFound" },
   302: { message: "NoProjectPageFound" },
   303: { message: "NoAnonymousVotingConfig" },
+  304: { message: "NoReleaseFound" },
+  305: { message: "NoAdvisoryFound" },
+  306: { message: "NoManifestFound" },
+  307: { message: "UnknownDelegate" },
+  308: { message: "NoMaintainersChangeFound" },
+  309: { message: "MaintainersChangePending" },
+  310: { message: "ProjectReadOnly" },
+  311: { message: "NoTransferFound" },
+  312: { message: "NoParentConsent" },
+  313: { message: "NoDependencyFound" },
+  314: { message: "NoApprovalFound" },
   400: { message: "AlreadyVoted" },
   401: { message: "ProposalVotingTime" },
   402: { message: "ProposalActive" },
   403: { message: "OutcomeError" },
+  404: { message: "InvalidExpiry" },
   500: { message: "TallySeedError" },
   501: { message: "InvalidProof" },
   600: { message: "ContractPaused" },
   601: { message: "UpgradeError" },
   602: { message: "ContractValidation" },
   603: { message: "CollateralError" },
+  604: { message: "MigrationPending" },
 };
 export var Badge;
 (function (Badge) {
@@ -65,7 +102,7 @@ export class Client extends ContractClient {
         "AAAAAAAAAZ9TZXR1cCBhbm9ueW1vdXMgdm90aW5nIGZvciBhIHByb2plY3QuCgpDb25maWd1cmVzIEJMUzEyLTM4MSBjcnlwdG9ncmFwaGljIHByaW1pdGl2ZXMgZm9yIGFub255bW91cyB2b3RpbmcuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIChtdXN0IGJlIGF1dGhvcml6ZWQpCiogYHByb2plY3Rfa2V5YCAtIFVuaXF1ZSBpZGVudGlmaWVyIGZvciB0aGUgcHJvamVjdAoqIGBwdWJsaWNfa2V5YCAtIEFzeW1tZXRyaWMgcHVibGljIGtleSB0byBiZSB1c2VkIGZvciB2b3RlIGVuY3J5cHRpb24KCiMgUGFuaWNzCiogSWYgdGhlIGNhbGxlciBpcyBub3QgYW4gYXV0aG9yaXplZCBtYWludGFpbmVyIG9mIHRoZSBwcm9qZWN0AAAAABZhbm9ueW1vdXNfdm90aW5nX3NldHVwAAAAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAApwdWJsaWNfa2V5AAAAAAAQAAAAAA==",
         "AAAAAAAAASdHZXQgdGhlIGFub255bW91cyB2b3RpbmcgY29uZmlndXJhdGlvbiBmb3IgYSBwcm9qZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGB0eXBlczo6QW5vbnltb3VzVm90ZUNvbmZpZ2AgLSBUaGUgYW5vbnltb3VzIHZvdGluZyBjb25maWd1cmF0aW9uCgojIFBhbmljcwoqIElmIG5vIGFub255bW91cyB2b3RpbmcgY29uZmlndXJhdGlvbiBleGlzdHMgZm9yIHRoZSBwcm9qZWN0AAAAABtnZXRfYW5vbnltb3VzX3ZvdGluZ19jb25maWcAAAAAAQAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAfQAAAAE0Fub255bW91c1ZvdGVDb25maWcA",
         "AAAAAAAAAxJCdWlsZCB2b3RlIGNvbW1pdG1lbnRzIGZyb20gdm90ZXMgYW5kIHNlZWRzIGZvciBhbm9ueW1vdXMgdm90aW5nLgoKQ3JlYXRlcyBCTFMxMi0zODEgY29tbWl0bWVudHMgZm9yIGVhY2ggdm90ZSB1c2luZyB0aGUgZm9ybXVsYToKQyA9IGfCt3ZvdGUgKyBowrdzZWVkIHdoZXJlIGcgYW5kIGggYXJlIGdlbmVyYXRvciBwb2ludHMgb24gQkxTMTItMzgxLgoKTm90ZTogVGhpcyBmdW5jdGlvbiBkb2VzIG5vdCBjb25zaWRlciB2b3Rpbmcgd2VpZ2h0cywgd2hpY2ggYXJlIGFwcGxpZWQKZHVyaW5nIHRoZSB0YWxseWluZyBwaGFzZS4gQ2FsbGluZyB0aGlzIG9uIHRoZSBzbWFydCBjb250cmFjdCB3b3VsZCByZXZlYWwKdGhlIHZvdGVzIGFuZCBzZWVkcywgc28gaXQgbXVzdCBiZSBydW4gZWl0aGVyIGluIHNpbXVsYXRpb24gb3IgY2xpZW50LXNpZGUuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBVbmlxdWUgaWRlbnRpZmllciBmb3IgdGhlIHByb2plY3QKKiBgdm90ZXNgIC0gVmVjdG9yIG9mIHZvdGUgY2hvaWNlcyAoMD1hcHByb3ZlLCAxPXJlamVjdCwgMj1hYnN0YWluKQoqIGBzZWVkc2AgLSBWZWN0b3Igb2YgcmFuZG9tIHNlZWRzIGZvciBlYWNoIHZvdGUKCiMgUmV0dXJucwoqIGBWZWM8Qnl0ZXNOPDk2Pj5gIC0gVmVjdG9yIG9mIHZvdGUgY29tbWl0bWVudHMgKG9uZSBwZXIgdm90ZSkKCiMgUGFuaWNzCiogSWYgbm8gYW5vbnltb3VzIHZvdGluZyBjb25maWd1cmF0aW9uIGV4aXN0cyBmb3IgdGhlIHByb2plY3QAAAAAABxidWlsZF9jb21taXRtZW50c19mcm9tX3ZvdGVzAAAAAwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAFdm90ZXMAAAAAAAPqAAAACgAAAAAAAAAFc2VlZHMAAAAAAAPqAAAACgAAAAEAAAPqAAAD7gAAAGA=",
-        "AAAAAAAAA5xDcmVhdGUgYSBuZXcgcHJvcG9zYWwgZm9yIGEgcHJvamVjdC4KClRoZSBwcm9wb3NlciBpcyBhdXRvbWF0aWNhbGx5IGFkZGVkIHRvIHRoZSBhYnN0YWluIGdyb3VwLgpCeSBjcmVhdGluZyBhIHByb3Bvc2FsLCB0aGUgcHJvcG9zZXIgaW5jdXIgYSBjb2xsYXRlcmFsIHdoaWNoIGlzCnJlcGFpZCB1cG9uIGV4ZWN1dGlvbiBvZiB0aGUgcHJvcG9zYWwgdW5sZXNzIHRoZSBwcm9wb3NhbCBpcyByZXZva2VkLgpUaGlzIGlzIGEgZGV0ZXJyZW50IG1lY2hhbmlzbS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb3Bvc2VyYCAtIEFkZHJlc3Mgb2YgdGhlIHByb3Bvc2FsIGNyZWF0b3IKKiBgcHJvamVjdF9rZXlgIC0gVW5pcXVlIGlkZW50aWZpZXIgZm9yIHRoZSBwcm9qZWN0CiogYHRpdGxlYCAtIFRpdGxlIG9mIHRoZSBwcm9wb3NhbAoqIGBpcGZzYCAtIElQRlMgY29udGVudCBpZGVudGlmaWVyIGRlc2NyaWJpbmcgdGhlIHByb3Bvc2FsCiogYHZvdGluZ19lbmRzX2F0YCAtIFVOSVggdGltZXN0YW1wIHdoZW4gdm90aW5nIGVuZHMKKiBgcHVibGljX3ZvdGluZ2AgLSBXaGV0aGVyIHZvdGluZyBpcyBwdWJsaWMgb3IgYW5vbnltb3VzCiogW2BPcHRpb248dG9rZW5fY29udHJhY3Q+YF0gLSB0b2tlbiBjb250cmFjdCBmb3IgdG9rZW4tYmFzZWQgdm90aW5nCiogW2BPcHRpb248VmVjPE91dGNvbWVDb250cmFjdD4+YF0gLSBvdXRjb21lIGNvbnRyYWN0cyBleGVjdXRlZCBhZnRlciBwcm9wb3NhbCBjb21wbGV0aW9uCgojIFJldHVybnMKKiBgdTMyYCAtIFRoZSBJRCBvZiB0aGUgY3JlYXRlZCBwcm9wb3NhbC4KCiMgUGFuaWNzCiogSWYgdGhlIHRpdGxlIGlzIHRvbyBsb25nCiogSWYgdGhlIHZvdGluZyBwZXJpb2QgaXMgaW52YWxpZAoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QAAAAPY3JlYXRlX3Byb3Bvc2FsAAAAAAgAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAFdGl0bGUAAAAAAAAQAAAAAAAAAARpcGZzAAAAEAAAAAAAAAAOdm90aW5nX2VuZHNfYXQAAAAAAAYAAAAAAAAADXB1YmxpY192b3RpbmcAAAAAAAABAAAAAAAAAA50b2tlbl9jb250cmFjdAAAAAAD6AAAABMAAAAAAAAAEW91dGNvbWVfY29udHJhY3RzAAAAAAAD6AAAA+oAAAfQAAAAD091dGNvbWVDb250cmFjdAAAAAABAAAABA==",
+        "AAAAAAAAA7ZDcmVhdGUgYSBuZXcgcHJvcG9zYWwgZm9yIGEgcHJvamVjdC4KClRoZSBwcm9wb3NlciBpcyBhdXRvbWF0aWNhbGx5IGFkZGVkIHRvIHRoZSBhYnN0YWluIGdyb3VwLgpCeSBjcmVhdGluZyBhIHByb3Bvc2FsLCB0aGUgcHJvcG9zZXIgaW5jdXIgYSBjb2xsYXRlcmFsIHdoaWNoIGlzCnJlcGFpZCB1cG9uIGV4ZWN1dGlvbiBvZiB0aGUgcHJvcG9zYWwgdW5sZXNzIHRoZSBwcm9wb3NhbCBpcyByZXZva2VkLgpUaGlzIGlzIGEgZGV0ZXJyZW50IG1lY2hhbmlzbS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb3Bvc2VyYCAtIEFkZHJlc3Mgb2YgdGhlIHByb3Bvc2FsIGNyZWF0b3IKKiBgcHJvamVjdF9rZXlgIC0gVW5pcXVlIGlkZW50aWZpZXIgZm9yIHRoZSBwcm9qZWN0CiogYHRpdGxlYCAtIFRpdGxlIG9mIHRoZSBwcm9wb3NhbAoqIGBpcGZzYCAtIElQRlMgY29udGVudCBpZGVudGlmaWVyIGRlc2NyaWJpbmcgdGhlIHByb3Bvc2FsCiogYHZvdGluZ19lbmRzX2F0YCAtIFVOSVggdGltZXN0YW1wIHdoZW4gdm90aW5nIGVuZHMKKiBgcHVibGljX3ZvdGluZ2AgLSBXaGV0aGVyIHZvdGluZyBpcyBwdWJsaWMgb3IgYW5vbnltb3VzCiogW2BPcHRpb248dG9rZW5fY29udHJhY3Q+YF0gLSB0b2tlbiBjb250cmFjdCBmb3IgdG9rZW4tYmFzZWQgdm90aW5nCiogW2BPcHRpb248VmVjPE91dGNvbWVDb250cmFjdD4+YF0gLSBvdXRjb21lIGNvbnRyYWN0cyBleGVjdXRlZCBhZnRlciBwcm9wb3NhbCBjb21wbGV0aW9uCgojIFJldHVybnMKKiBgdTMyYCAtIFRoZSBJRCBvZiB0aGUgY3JlYXRlZCBwcm9wb3NhbC4KCiMgUGFuaWNzCiogSWYgdGhlIHRpdGxlIGlzIHRvbyBsb25nCiogSWYgdGhlIHZvdGluZyBwZXJpb2QgaXMgaW52YWxpZAoqIElmIHRoZSBDSUQgaXMgbWFsZm9ybWVkCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAAAAAAAD2NyZWF0ZV9wcm9wb3NhbAAAAAAIAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAABXRpdGxlAAAAAAAAEAAAAAAAAAAEaXBmcwAAABAAAAAAAAAADnZvdGluZ19lbmRzX2F0AAAAAAAGAAAAAAAAAA1wdWJsaWNfdm90aW5nAAAAAAAAAQAAAAAAAAAOdG9rZW5fY29udHJhY3QAAAAAA+gAAAATAAAAAAAAABFvdXRjb21lX2NvbnRyYWN0cwAAAAAAA+gAAAPqAAAH0AAAAA9PdXRjb21lQ29udHJhY3QAAAAAAQAAAAQ=",
         "AAAAAAAAAaVSZXZva2UgYSBwcm9wb3NhbC4KClVzZWZ1bCBpZiB0aGVyZSB3YXMgc29tZSBzcGFtIG9yIGJhZCBpbnRlbnQuIFRoYXQgd2lsbCBwcmV2ZW50IHRoZQpjb2xsYXRlcmFsIHRvIGJlIGNsYWltZWQgYmFjay4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gQWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBvciBhZG1pbiByZXZva2luZyB0aGUgcHJvcG9zYWwKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgcHJvcG9zYWxfaWRgIC0gVGhlIElEIG9mIHRoZSBwcm9wb3NhbCB0byByZXZva2UKCiMgUGFuaWNzCiogSWYgdGhlIHByb3Bvc2FsIGlzIG5vdCBhY3RpdmUgYW55bW9yZQoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkAAAAAAAAD3Jldm9rZV9wcm9wb3NhbAAAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAEAAAAAA==",
         "AAAAAAAAA3tDYXN0IGEgdm90ZSBvbiBhIHByb3Bvc2FsLgoKQWxsb3dzIGEgbWVtYmVyIHRvIHZvdGUgb24gYSBwcm9wb3NhbC4KVGhlIHZvdGUgY2FuIGJlIGVpdGhlciBwdWJsaWMgb3IgYW5vbnltb3VzIGRlcGVuZGluZyBvbiB0aGUgcHJvcG9zYWwgY29uZmlndXJhdGlvbi4KRm9yIHB1YmxpYyB2b3RlcywgdGhlIGNob2ljZSBhbmQgd2VpZ2h0IGFyZSB2aXNpYmxlLiBGb3IgYW5vbnltb3VzIHZvdGVzLCBvbmx5CnRoZSB3ZWlnaHQgaXMgdmlzaWJsZSwgYW5kIHRoZSBjaG9pY2UgaXMgZW5jcnlwdGVkLgoKVm90aW5nIGluY3VycyBhIGNvbGxhdGVyYWwgd2hpY2ggaXMgcmVwYWlkIHVwb24gcHJvcG9zYWwgZXhlY3V0aW9uLgpJZiB0aGUgcHJvcG9zYWwgaXMgcmV2b2tlZCwgdGhlIGNvbGxhdGVyYWwgaXMgbm90IHJlcGFpZCBhcyB0aGUgdm90ZXIKZW5nYWdlZCB3aXRoIGEgbWFsaWNpb3VzIHByb3Bvc2FsLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgdm90ZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIHZvdGVyCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHByb3Bvc2FsX2lkYCAtIFRoZSBJRCBvZiB0aGUgcHJvcG9zYWwgdG8gdm90ZSBvbgoqIGB2b3RlYCAtIFRoZSB2b3RlIGRhdGEgKHB1YmxpYyBvciBhbm9ueW1vdXMpCgojIFBhbmljcwoqIElmIHRoZSB2b3RlciBoYXMgYWxyZWFkeSB2b3RlZAoqIElmIHRoZSB2b3RpbmcgcGVyaW9kIGhhcyBlbmRlZAoqIElmIHRoZSBwcm9wb3NhbCBpcyBub3QgYWN0aXZlIGFueW1vcmUKKiBJZiB0aGUgcHJvcG9zYWwgZG9lc24ndCBleGlzdAoqIElmIHRoZSB2b3RlcidzIHdlaWdodCBleGNlZWRzIHRoZWlyIG1heGltdW0gYWxsb3dlZCB3ZWlnaHQAAAAABHZvdGUAAAAEAAAAAAAAAAV2b3RlcgAAAAAAABMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAQAAAAAAAAABHZvdGUAAAfQAAAABFZvdGUAAAAA",
         "AAAAAAAABABFeGVjdXRlIGEgdm90ZSBhZnRlciB0aGUgdm90aW5nIHBlcmlvZCBlbmRzLgoKUHJvY2Vzc2VzIHRoZSB2b3RpbmcgcmVzdWx0cyBhbmQgZGV0ZXJtaW5lcyB0aGUgZmluYWwgc3RhdHVzIG9mIHRoZSBwcm9wb3NhbC4KRm9yIHB1YmxpYyB2b3RlcywgdGhlIHJlc3VsdHMgYXJlIGNhbGN1bGF0ZWQgZGlyZWN0bHkgZnJvbSB2b3RlIGNvdW50cy4KRm9yIGFub255bW91cyB2b3RlcywgdGFsbGllcyBhbmQgc2VlZHMgYXJlIHZhbGlkYXRlZCBhZ2FpbnN0IHZvdGUgY29tbWl0bWVudHMKdG8gZW5zdXJlIHRoZSByZXN1bHRzIGFyZSBjb3JyZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBleGVjdXRpbmcgdGhlIHByb3Bvc2FsCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHByb3Bvc2FsX2lkYCAtIFRoZSBJRCBvZiB0aGUgcHJvcG9zYWwgdG8gZXhlY3V0ZQoqIFtgT3B0aW9uPHRhbGxpZXM+YF0gLSBkZWNvZGVkIHRhbGx5IHZhbHVlcyAoc2NhbGVkIGJ5IHdlaWdodHMpLCByZXNwZWN0aXZlbHkgQXBwcm92ZSwgcmVqZWN0IGFuZCBhYnN0YWluCiogW2BPcHRpb248c2VlZHM+YF0gLSBkZWNvZGVkIHNlZWQgdmFsdWVzIChzY2FsZWQgYnkgd2VpZ2h0cyksIHJlc3BlY3RpdmVseSBBcHByb3ZlLCByZWplY3QgYW5kIGFic3RhaW4KCiMgUmV0dXJucwoqIGB0eXBlczo6UHJvcG9zYWxTdGF0dXNgIC0gVGhlIGZpbmFsIHN0YXR1cyBvZiB0aGUgcHJvcG9zYWwgKEFwcHJvdmVkLCBSZWplY3RlZCwgb3IgQ2FuY2VsbGVkKQoKIyBQYW5pY3MKKiBJZiB0aGUgdm90aW5nIHBlcmlvZCBoYXNuJ3QgZW5kZWQKKiBJZiB0aGUgcHJvcG9zYWwgZG9lc24ndCBleGlzdAoqIElmIHRoZSBwcm9wb3NhbCBpcyBub3QgYWN0aXZlIGFueW1vcmUKKiBJZiB0YWxsaWVzL3NlZWRzIGFyZSBtaXNzaW5nIGZvciBhbm9ueW1vdXMgdm90ZXMKKiBJZiBjb21taXRtZW50AAAAB2V4ZWN1dGUAAAAABQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAAAAAAHdGFsbGllcwAAAAPoAAAD6gAAAAoAAAAAAAAABXNlZWRzAAAAAAAD6AAAA+oAAAAKAAAAAQAAB9AAAAAOUHJvcG9zYWxTdGF0dXMAAA==",
@@ -76,31 +113,123 @@ export class Client extends ContractClient {
         "AAAAAAAAAR1HZXQgbWVtYmVyIGluZm9ybWF0aW9uIGluY2x1ZGluZyBhbGwgcHJvamVjdCBiYWRnZXMuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtZW1iZXJfYWRkcmVzc2AgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWVtYmVyIHRvIHJldHJpZXZlCgojIFJldHVybnMKKiBgdHlwZXM6Ok1lbWJlcmAgLSBNZW1iZXIgaW5mb3JtYXRpb24gaW5jbHVkaW5nIG1ldGFkYXRhIGFuZCBwcm9qZWN0IGJhZGdlcwoKIyBQYW5pY3MKKiBJZiB0aGUgbWVtYmVyIGRvZXNuJ3QgZXhpc3QAAAAAAAAKZ2V0X21lbWJlcgAAAAAAAQAAAAAAAAAObWVtYmVyX2FkZHJlc3MAAAAAABMAAAABAAAH0AAAAAZNZW1iZXIAAA==",
         "AAAAAAAAAltTZXQgYmFkZ2VzIGZvciBhIG1lbWJlciBpbiBhIHNwZWNpZmljIHByb2plY3QuCgpUaGlzIGZ1bmN0aW9uIHJlcGxhY2VzIGFsbCBleGlzdGluZyBiYWRnZXMgZm9yIHRoZSBtZW1iZXIgaW4gdGhlIHNwZWNpZmllZCBwcm9qZWN0CndpdGggdGhlIG5ldyBiYWRnZSBsaXN0LiBUaGUgbWVtYmVyJ3MgbWF4aW11bSB2b3RpbmcKd2VpZ2h0IGlzIGNhbGN1bGF0ZWQgYXMgdGhlIHN1bSBvZiBhbGwgYXNzaWduZWQgYmFkZ2Ugd2VpZ2h0cy4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgKG11c3QgYmUgYXV0aG9yaXplZCkKKiBga2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYG1lbWJlcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWVtYmVyIHRvIHNldCBiYWRnZXMgZm9yCiogYGJhZGdlc2AgLSBWZWN0b3Igb2YgYmFkZ2VzIHRvIGFzc2lnbgoKIyBQYW5pY3MKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBtZW1iZXIgZG9lc24ndCBleGlzdAoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QAAAAACnNldF9iYWRnZXMAAAAAAAQAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAA2tleQAAAAAOAAAAAAAAAAZtZW1iZXIAAAAAABMAAAAAAAAABmJhZGdlcwAAAAAD6gAAB9AAAAAFQmFkZ2UAAAAAAAAA",
         "AAAAAAAAAWVHZXQgYWxsIGJhZGdlcyBmb3IgYSBzcGVjaWZpYyBwcm9qZWN0LCBvcmdhbml6ZWQgYnkgYmFkZ2UgdHlwZS4KClJldHVybnMgYSBzdHJ1Y3R1cmUgY29udGFpbmluZyB2ZWN0b3JzIG9mIG1lbWJlciBhZGRyZXNzZXMgZm9yIGVhY2ggYmFkZ2UgdHlwZQooRGV2ZWxvcGVyLCBUcmlhZ2UsIENvbW11bml0eSwgVmVyaWZpZWQpLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBga2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCgojIFJldHVybnMKKiBgdHlwZXM6OkJhZGdlc2AgLSBTdHJ1Y3R1cmUgY29udGFpbmluZyBtZW1iZXIgYWRkcmVzc2VzIGZvciBlYWNoIGJhZGdlIHR5cGUAAAAAAAAKZ2V0X2JhZGdlcwAAAAAAAQAAAAAAAAADa2V5AAAAAA4AAAABAAAH0AAAAAZCYWRnZXMAAA==",
+        "AAAAAAAAAPpHZXQgYSBwYWdlIG9mIHRoZSBwcm9qZWN0cyB3aGVyZSBhbiBhZGRyZXNzIGhvbGRzIGJhZGdlcy4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1lbWJlcmAgLSBUaGUgbWVtYmVyIGFkZHJlc3MKKiBgcGFnZWAgLSBUaGUgcGFnZSBudW1iZXIgKDAtYmFzZWQpCgojIFJldHVybnMKKiBgVmVjPEJ5dGVzPmAgLSBQcm9qZWN0IGtleXMgb24gdGhlIHJlcXVlc3RlZCBwYWdlLCBlbXB0eSBpZiBub25lAAAAAAAWZ2V0X3Byb2plY3RzX2J5X21lbWJlcgAAAAAAAgAAAAAAAAAGbWVtYmVyAAAAAAATAAAAAAAAAARwYWdlAAAABAAAAAEAAAPqAAAADg==",
         "AAAAAAAAAbpHZXQgdGhlIG1heGltdW0gdm90aW5nIHdlaWdodCBmb3IgYW4gYWRkcmVzcyBpbiBhIHNwZWNpZmljIHByb2plY3QuCgpDYWxjdWxhdGVzIHRoZSBzdW0gb2YgYWxsIGJhZGdlIHdlaWdodHMgZm9yIHRoZSBhZGRyZXNzIGluIHRoZSBwcm9qZWN0LgpSZXR1cm5zIHRoZSBEZWZhdWx0IGJhZGdlIHdlaWdodCAoMSkgaWYgdGhlIGFkZHJlc3MgaGFzIG5vIGJhZGdlcwphc3NpZ25lZCBvciBpcyBub3QgYSByZWdpc3RlcmVkIG1lbWJlci4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYG1lbWJlcl9hZGRyZXNzYCAtIFRoZSBhZGRyZXNzIHRvIGNoZWNrCgojIFJldHVybnMKKiBgdTMyYCAtIFRoZSBtYXhpbXVtIHZvdGluZyB3ZWlnaHQgZm9yIHRoZSBhZGRyZXNzAAAAAAAOZ2V0X21heF93ZWlnaHQAAAAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAADm1lbWJlcl9hZGRyZXNzAAAAAAATAAAAAQAAAAQ=",
-        "AAAAAAAAARhBZGQgcHJvamVjdHMgdG8gdGhlIG5ldyBwYWdpbmF0aW9uIGxpc3QuIFRoaXMgaXMgdXNlZCB0byBtaWdyYXRlIHByb2plY3RzIHdoZW4gdGhlIHByb2plY3Qgd2FzIGNyZWF0ZWQgYmVmb3JlIHRoZSBwYWdpbmF0aW9uIHdhcyBpbXBsZW1lbnRlZC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGFkbWluYCAtIFRoZSBhZG1pbiBhZGRyZXNzCiogYG5hbWVzYCAtIFRoZSBuYW1lcyBvZiB0aGUgcHJvamVjdHMgdG8gYWRkCgojIFJldHVybnMKKiBgKClgAAAAGmFkZF9wcm9qZWN0c190b19wYWdpbmF0aW9uAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABW5hbWVzAAAAAAAD6gAAABAAAAAA",
+        "AAAAAAAAAeFBZGQgcHJvamVjdHMgdG8gdGhlIG5ldyBwYWdpbmF0aW9uIGxpc3QuIFRoaXMgaXMgdXNlZCB0byBtaWdyYXRlIHByb2plY3RzIHdoZW4gdGhlIHByb2plY3Qgd2FzIGNyZWF0ZWQgYmVmb3JlIHRoZSBwYWdpbmF0aW9uIHdhcyBpbXBsZW1lbnRlZC4KClByb2plY3RzIHdoaWNoIGFyZSBhbHJlYWR5IGxpc3RlZCBhcmUgc2tpcHBlZCwgc28gdGhlIGNhbGwgY2FuIGJlCnJlcGVhdGVkIHNhZmVseS4gS2V5cyBsaXN0ZWQgYmVmb3JlIHRoZSBtaWdyYXRpb24gdG8gdmVyc2lvbiAxIGFyZQpvbmx5IGtub3duIG9uY2UgaXQgcmFuLCBpdCByZW1vdmVzIHRoZSBkdXBsaWNhdGVzIGFkZGVkIG1lYW53aGlsZS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGFkbWluYCAtIFRoZSBhZG1pbiBhZGRyZXNzCiogYG5hbWVzYCAtIFRoZSBuYW1lcyBvZiB0aGUgcHJvamVjdHMgdG8gYWRkCgojIFJldHVybnMKKiBgKClgAAAAAAAAGmFkZF9wcm9qZWN0c190b19wYWdpbmF0aW9uAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABW5hbWVzAAAAAAAD6gAAABAAAAAA",
+        "AAAAAAAAA/dSdW4gYSBiYXRjaCBvZiB0aGUgbWlncmF0aW9uIHRvIHRoZSBuZXh0IHN0b3JhZ2UgbGF5b3V0IHZlcnNpb24uCgpFYWNoIGNhbGwgcHJvY2Vzc2VzIGF0IG1vc3QgYGxpbWl0YCBlbnRyaWVzIChtYXggMjApIHBlciB2ZXJzaW9uIGFuZApyZWNvcmRzIGl0cyBwcm9ncmVzcywgY2FsbCBpdCBhZ2FpbiB1bnRpbCBgZG9uZWAgaXMgc2V0LiBUaGUgbWlncmF0aW9uIHNjaGVkdWxlZApieSBhbiB1cGdyYWRlLCBpZiBhbnksIHJ1bnMgZmlyc3QuIEFkbWlucyBjYW4gbWlncmF0ZSB3aGlsZSB0aGUKY29udHJhY3QgaXMgcGF1c2VkLiBNaWdyYXRpb25zOgoqIHRvIHZlcnNpb24gMTogcmVtb3ZlIGR1cGxpY2F0ZWQgYW5kIHVua25vd24ga2V5cyBmcm9tIHRoZSBwcm9qZWN0cwpsaXN0aW5nLCBzZWUgYHZlcmlmeV9wcm9qZWN0X2luZGV4YAoqIHRvIHZlcnNpb24gMjogYWRkIHRoZSB1cGdyYWRlIHRpbWVsb2NrIHRvIHRoZSBhZG1pbnMgY29uZmlndXJhdGlvbgoqIHRvIHZlcnNpb24gMzogY29udmVydCB0aGUgcHJvamVjdHMgYW5kIHRoZWlyIERBTyBwYWdlcyB0byB0aGUKY3VycmVudCBsYXlvdXQuIFVubGlzdGVkIHByb2plY3RzIG11c3QgZmlyc3QgYmUgYWRkZWQgd2l0aApgYWRkX3Byb2plY3RzX3RvX3BhZ2luYXRpb25gCiogdG8gdmVyc2lvbiA0OiBsaXN0IHRoZSBwcm9qZWN0cyBvZiB0aGVpciBtYWludGFpbmVycyBhbmQgYmFkZ2UKaG9sZGVycywgc2VlIGBnZXRfcHJvamVjdHNfYnlfbWFpbnRhaW5lcmAgYW5kIGBnZXRfcHJvamVjdHNfYnlfbWVtYmVyYAoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgYWRtaW5gIC0gVGhlIGFkbWluIGFkZHJlc3MKKiBgbGltaXRgIC0gVGhlIG1heGltdW0gbnVtYmVyIG9mIGVudHJpZXMgdG8gcHJvY2VzcwoKIyBSZXR1cm5zCiogYHR5cGVzOjpNaWdyYXRpb25TdGF0dXNgIC0gVGhlIHByb2dyZXNzIG9mIHRoZSBtaWdyYXRpb24KCiMgUGFuaWNzCiogSWYgdGhlIGFkbWluIGlzIG5vdCBhdXRob3JpemVkAAAAAAdtaWdyYXRlAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAB9AAAAAPTWlncmF0aW9uU3RhdHVzAA==",
+        "AAAAAAAAALVHZXQgdGhlIHN0b3JhZ2UgbGF5b3V0IHZlcnNpb24gYW5kIHRoZSBwcm9ncmVzcyBvZiBpdHMgbWlncmF0aW9uLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKCiMgUmV0dXJucwoqIGB0eXBlczo6TWlncmF0aW9uU3RhdHVzYCAtIFRoZSBwcm9ncmVzcyBvZiB0aGUgbWlncmF0aW9uAAAAAAAAFGdldF9taWdyYXRpb25fc3RhdHVzAAAAAAAAAAEAAAfQAAAAD01pZ3JhdGlvblN0YXR1cwA=",
+        "AAAAAAAAAlBDaGVjayBhIHJhbmdlIG9mIHBhZ2VzIG9mIHRoZSBwcm9qZWN0cyBsaXN0aW5nIGFnYWluc3QgYFRvdGFsUHJvamVjdHNgLgoKUGFnZXMgYmVmb3JlIHRoZSBsYXN0IG9uZSBtdXN0IGJlIGZ1bGwgYW5kIG5vIHBhZ2UgbWF5IGV4aXN0IHBhc3QKdGhlIGxhc3Qgb25lLiBFdmVyeSBrZXkgbXVzdCBiZSBsaXN0ZWQgb25jZSBhbmQgYmVsb25nIHRvIGEgcHJvamVjdDoKYSBrZXkgaXMgYSBkdXBsaWNhdGUgaWYgaXQgaXMgbGlzdGVkIHR3aWNlIGluIHRoZSByYW5nZSwgb3IgaWYgdGhlCmxpc3Rpbmcgc3RvcmVzIGFub3RoZXIgcG9zaXRpb24gZm9yIGl0LiBDYWxsIGl0IGFnYWluIGZyb20KYG5leHRfcGFnZWAgdG8gY2hlY2sgdGhlIHJlc3Qgb2YgdGhlIGxpc3RpbmcuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBzdGFydF9wYWdlYCAtIFRoZSBmaXJzdCBwYWdlIHRvIGNoZWNrCiogYHBhZ2VzYCAtIFRoZSBtYXhpbXVtIG51bWJlciBvZiBwYWdlcyB0byBjaGVjayAobWF4IDUpCgojIFJldHVybnMKKiBgdHlwZXM6OkluZGV4UmVwb3J0YCAtIFRoZSBpbmNvbnNpc3RlbmNpZXMgb2YgdGhlIHBhZ2VzAAAAFHZlcmlmeV9wcm9qZWN0X2luZGV4AAAAAgAAAAAAAAAKc3RhcnRfcGFnZQAAAAAABAAAAAAAAAAFcGFnZXMAAAAAAAAEAAAAAQAAB9AAAAALSW5kZXhSZXBvcnQA",
+        "AAAAAAAAAmlZYW5rIGFuIGFuY2hvcmVkIGNvbW1pdCBoYXNoLgoKVGhlIGhhc2ggc3RheXMgaW4gdGhlIGNvbW1pdCBoaXN0b3J5IGFuZCBpdHMgcmVsZWFzZXMgcmVtYWluCnF1ZXJ5YWJsZSwgYnV0IGNvbnN1bWVycyBnZXQgYW4gb24tY2hhaW4gc2lnbmFsIHRoYXQgaXQgc2hvdWxkIG5vdApiZSB1c2VkIGFueW1vcmUuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBoYXNoYCAtIFRoZSBhbmNob3JlZCBjb21taXQgaGFzaCB0byB5YW5rCiogYHJlYXNvbmAgLSBXaHkgdGhlIGhhc2ggaXMgeWFua2VkCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBoYXNoIGRvZXMgbm90IG1hdGNoIHRoZSBwcm9qZWN0J3MgaGFzaCBhbGdvcml0aG0KKiBJZiB0aGUgaGFzaCB3YXMgbmV2ZXIgYW5jaG9yZWQKKiBJZiB0aGUgaGFzaCBpcyBhbHJlYWR5IHlhbmtlZAAAAAAAAAR5YW5rAAAABAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAEaGFzaAAAABAAAAAAAAAABnJlYXNvbgAAAAAAEAAAAAA=",
+        "AAAAAAAAAO5HZXQgdGhlIHlhbmsgaW5mb3JtYXRpb24gb2YgYSBjb21taXQgaGFzaC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGhhc2hgIC0gVGhlIGNvbW1pdCBoYXNoCgojIFJldHVybnMKKiBgT3B0aW9uPHR5cGVzOjpZYW5rPmAgLSBUaGUgeWFuayBpbmZvcm1hdGlvbiwgTm9uZSBpZiBub3QgeWFua2VkAAAAAAAIZ2V0X3lhbmsAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAARoYXNoAAAAEAAAAAEAAAPoAAAH0AAAAARZYW5r",
+        "AAAAAAAAAl1QdWJsaXNoIGEgc2VjdXJpdHkgYWR2aXNvcnkuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBzZXZlcml0eWAgLSBTZXZlcml0eSBvZiB0aGUgdnVsbmVyYWJpbGl0eQoqIGBhZmZlY3RlZGAgLSBTZW12ZXIgcmFuZ2Ugb2YgYWZmZWN0ZWQgdmVyc2lvbnMsIGUuZy4gYD49MS4wLjAgPDEuMi4zYAoqIFtgT3B0aW9uPGZpeGVkX3ZlcnNpb24+YF0gLSBGaXJzdCB2ZXJzaW9uIHdpdGggYSBmaXgKKiBgaXBmc2AgLSBDSUQgb2YgdGhlIGFkdmlzb3J5IGRldGFpbHMKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIElEIG9mIHRoZSBhZHZpc29yeQoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0CiogSWYgdGhlIG1haW50YWluZXIgaXMgbm90IGF1dGhvcml6ZWQKKiBJZiB0aGUgcmFuZ2Ugb3IgdGhlIGZpeGVkIHZlcnNpb24gYXJlIG5vdCB2YWxpZCBzZW12ZXIKKiBJZiB0aGUgQ0lEIGlzIG1hbGZvcm1lZAAAAAAAABBwdWJsaXNoX2Fkdmlzb3J5AAAABgAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAIc2V2ZXJpdHkAAAfQAAAACFNldmVyaXR5AAAAAAAAAAhhZmZlY3RlZAAAABAAAAAAAAAADWZpeGVkX3ZlcnNpb24AAAAAAAPoAAAAEAAAAAAAAAAEaXBmcwAAABAAAAABAAAABA==",
+        "AAAAAAAAAPRHZXQgYSBzZWN1cml0eSBhZHZpc29yeS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGFkdmlzb3J5X2lkYCAtIFRoZSBJRCBvZiB0aGUgYWR2aXNvcnkKCiMgUmV0dXJucwoqIGB0eXBlczo6QWR2aXNvcnlgIC0gVGhlIGFkdmlzb3J5CgojIFBhbmljcwoqIElmIHRoZSBhZHZpc29yeSBkb2Vzbid0IGV4aXN0AAAADGdldF9hZHZpc29yeQAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAAC2Fkdmlzb3J5X2lkAAAAAAQAAAABAAAH0AAAAAhBZHZpc29yeQ==",
+        "AAAAAAAAAiNMaXN0IHRoZSBhZHZpc29yaWVzIGFmZmVjdGluZyBhIHZlcnNpb24gb3IgYSBjb21taXQgaGFzaC4KCklmIHRoZSBpbnB1dCBpcyBub3QgYSB2YWxpZCBzZW12ZXIgdmVyc2lvbiwgaXQgaXMgdHJlYXRlZCBhcyBhIGNvbW1pdApoYXNoIGFuZCBldmVyeSByZWxlYXNlIHRhZ2dlZCBvbiB0aGF0IGhhc2ggaXMgY2hlY2tlZC4KCk5vdGU6IHRoZSBjb3N0IG9mIHRoaXMgY2FsbCBncm93cyB3aXRoIHRoZSBudW1iZXIgb2YgYWR2aXNvcmllcwpwdWJsaXNoZWQgZm9yIHRoZSBwcm9qZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgdmVyc2lvbl9vcl9oYXNoYCAtIEEgc2VtdmVyIHZlcnNpb24gb3IgYW4gYW5jaG9yZWQgY29tbWl0IGhhc2gKCiMgUmV0dXJucwoqIGBWZWM8dTMyPmAgLSBJRHMgb2YgdGhlIGFkdmlzb3JpZXMgYWZmZWN0aW5nIHRoZSB2ZXJzaW9uLCBlbXB0eSBpZgpub25lIG9yIGlmIHRoZSBoYXNoIGhhcyBubyByZWxlYXNlAAAAABNpc192ZXJzaW9uX2FmZmVjdGVkAAAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAAD3ZlcnNpb25fb3JfaGFzaAAAAAAQAAAAAQAAA+oAAAAE",
+        "AAAAAAAAAv9BdHRlc3QgdGhhdCBidWlsZGluZyBhIGNvbW1pdCBwcm9kdWNlcyBhbiBhcnRpZmFjdCBkaWdlc3QuCgpPbmx5IG1lbWJlcnMgaG9sZGluZyB0aGUgYFZlcmlmaWVkYCBvciBgRGV2ZWxvcGVyYCBiYWRnZSBvZiB0aGUKcHJvamVjdCBjYW4gYXR0ZXN0LiBFYWNoIG9mIHRoZW0gYXR0ZXN0cyBhIGNvbW1pdCBvbmNlLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgYXR0ZXN0ZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIGJ1aWxkZXIKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgY29tbWl0X2hhc2hgIC0gVGhlIGFuY2hvcmVkIGNvbW1pdCBoYXNoIHdoaWNoIHdhcyBidWlsdAoqIGBkaWdlc3RgIC0gVGhlIGRpZ2VzdCBvZiB0aGUgYXJ0aWZhY3QsIGUuZy4gYSBXQVNNIGhhc2gKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBwcm9qZWN0IGlzIGFyY2hpdmVkIG9yIGRlcmVnaXN0ZXJlZAoqIElmIHRoZSBhdHRlc3RlciBkb2VzIG5vdCBob2xkIGEgYFZlcmlmaWVkYCBvciBgRGV2ZWxvcGVyYCBiYWRnZQoqIElmIHRoZSBoYXNoIGRvZXMgbm90IG1hdGNoIHRoZSBwcm9qZWN0J3MgaGFzaCBhbGdvcml0aG0KKiBJZiB0aGUgY29tbWl0IGhhc2ggd2FzIG5ldmVyIGFuY2hvcmVkCiogSWYgdGhlIGF0dGVzdGVyIGFscmVhZHkgYXR0ZXN0ZWQgdGhlIGNvbW1pdAoqIElmIHRoZSBjb21taXQgaGFzIHRvbyBtYW55IGF0dGVzdGF0aW9ucwAAAAAMYXR0ZXN0X2J1aWxkAAAABAAAAAAAAAAIYXR0ZXN0ZXIAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAtjb21taXRfaGFzaAAAAAAQAAAAAAAAAAZkaWdlc3QAAAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAXVTZXQgaG93IG1hbnkgbWF0Y2hpbmcgYXR0ZXN0YXRpb25zIHZlcmlmeSBhIGJ1aWxkLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgdGhyZXNob2xkYCAtIE51bWJlciBvZiBtYXRjaGluZyBhdHRlc3RhdGlvbnMsIGF0IGxlYXN0IDEKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIHRocmVzaG9sZCBpcyAwAAAAAAAAGXNldF9hdHRlc3RhdGlvbl90aHJlc2hvbGQAAAAAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAf1HZXQgdGhlIGF0dGVzdGF0aW9ucyBvZiBhIGNvbW1pdCBhbmQgd2hldGhlciBpdHMgYnVpbGQgaXMgdmVyaWZpZWQuCgpBIGJ1aWxkIGlzIHZlcmlmaWVkIHdoZW4gZXhhY3RseSBvbmUgZGlnZXN0IGhhcyBhdCBsZWFzdCBgdGhyZXNob2xkYAphdHRlc3RlcnMuIFNldmVyYWwgZGlnZXN0cyBhcmUgYSBzaWduIG9mIGEgbm9uIHJlcHJvZHVjaWJsZSBidWlsZCBvcgpvZiBhIGNvbXByb21pc2VkIGJ1aWxkZXIuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBjb21taXRfaGFzaGAgLSBUaGUgY29tbWl0IGhhc2gKCiMgUmV0dXJucwoqIGB0eXBlczo6QnVpbGRWZXJpZmljYXRpb25gIC0gVGhlIHZlcmlmaWVkIGRpZ2VzdCBpZiBhbnksIGFuZCB0aGUKYXR0ZXN0ZXJzIG9mIGV2ZXJ5IGRpZ2VzdAoKIyBQYW5pY3MKKiBJZiB0aGUgaGFzaCBpcyBub3QgYSB2YWxpZCBoZXggaGFzaAAAAAAAABZnZXRfYnVpbGRfdmVyaWZpY2F0aW9uAAAAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAtjb21taXRfaGFzaAAAAAAQAAAAAQAAB9AAAAARQnVpbGRWZXJpZmljYXRpb24AAAA=",
+        "AAAAAAAAAu9EZWNsYXJlIHRoYXQgYSBwcm9qZWN0IGRlcGVuZHMgb24gYW5vdGhlciBwcm9qZWN0LgoKVGhlIGRlcGVuZGVuY3kgaXMgbGlzdGVkIGluIHRoZSBkZXBlbmRlbnRzIG9mIHRoZSBvdGhlciBwcm9qZWN0IHNvCnRoYXQgYWR2aXNvcmllcyBhbmQgeWFua3MgY2FuIGJlIHRyYWNlZCB0byB0aGUgcHJvamVjdHMgdGhleSBhZmZlY3QuCkRlY2xhcmluZyBhIGRlcGVuZGVuY3kgYWdhaW4gdXBkYXRlcyBpdHMgcmVxdWlyZW1lbnQuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBwcm9qZWN0X2tleWAgLSBUaGUga2V5IG9mIHRoZSBkZXBlbmRlbnQgcHJvamVjdAoqIGBkZXBlbmRlbmN5X2tleWAgLSBUaGUga2V5IG9mIHRoZSBwcm9qZWN0IGRlcGVuZGVkIG9uCiogYHJlcXVpcmVtZW50YCAtIFNlbXZlciByYW5nZSBvZiBjb21wYXRpYmxlIHJlbGVhc2VzLCBlLmcuIGA+PTEuMi4wIDwyLjAuMGAKCiMgUGFuaWNzCiogSWYgZWl0aGVyIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIHByb2plY3QgZGVwZW5kcyBvbiBpdHNlbGYKKiBJZiB0aGUgcmVxdWlyZW1lbnQgaXMgbm90IGEgdmFsaWQgc2VtdmVyIHJhbmdlCiogSWYgdGhlIHByb2plY3QgYWxyZWFkeSBoYXMgMjAgZGVwZW5kZW5jaWVzAAAAAA5hZGRfZGVwZW5kZW5jeQAAAAAABAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAOZGVwZW5kZW5jeV9rZXkAAAAAAA4AAAAAAAAAC3JlcXVpcmVtZW50AAAAABAAAAAA",
+        "AAAAAAAAAXNSZW1vdmUgYSBkZXBlbmRlbmN5IG9mIGEgcHJvamVjdC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBrZXkgb2YgdGhlIGRlcGVuZGVudCBwcm9qZWN0CiogYGRlcGVuZGVuY3lfa2V5YCAtIFRoZSBrZXkgb2YgdGhlIHByb2plY3QgZGVwZW5kZWQgb24KCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIGRlcGVuZGVuY3kgd2FzIG5vdCBkZWNsYXJlZAAAAAARcmVtb3ZlX2RlcGVuZGVuY3kAAAAAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAA5kZXBlbmRlbmN5X2tleQAAAAAADgAAAAA=",
+        "AAAAAAAAANRHZXQgdGhlIGRlcGVuZGVuY2llcyBvZiBhIHByb2plY3QuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoKIyBSZXR1cm5zCiogYFZlYzx0eXBlczo6RGVwZW5kZW5jeT5gIC0gVGhlIHByb2plY3RzIGRlcGVuZGVkIG9uIGFuZCB0aGVpciByZXF1aXJlbWVudAAAABBnZXRfZGVwZW5kZW5jaWVzAAAAAQAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAPqAAAH0AAAAApEZXBlbmRlbmN5AAA=",
+        "AAAAAAAAAYhHZXQgYSBwYWdlIG9mIHRoZSBwcm9qZWN0cyBkZXBlbmRpbmcgb24gYSBwcm9qZWN0LgoKQ29tYmluZWQgd2l0aCBgZ2V0X2RlcGVuZGVuY2llc2AsIHRoaXMgdGVsbHMgd2hpY2ggZGVwZW5kZW50cyBoYXZlIGEKcmVxdWlyZW1lbnQgbWF0Y2hpbmcgdGhlIHZlcnNpb25zIG9mIGFuIGFkdmlzb3J5IG9yIGEgeWFua2VkIHJlbGVhc2UuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBwYWdlYCAtIFRoZSBwYWdlIG51bWJlciAoMC1iYXNlZCkKCiMgUmV0dXJucwoqIGBWZWM8Qnl0ZXM+YCAtIEtleXMgb2YgdGhlIGRlcGVuZGVudCBwcm9qZWN0cywgZW1wdHkgaWYgbm9uZQAAAA5nZXRfZGVwZW5kZW50cwAAAAAAAgAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAEcGFnZQAAAAQAAAABAAAD6gAAAA4=",
         "AAAAAAAAAINJbml0aWFsaXplIHRoZSBUYW5zdSBjb250cmFjdCB3aXRoIGFkbWluIGNvbmZpZ3VyYXRpb24uCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBhZG1pbmAgLSBUaGUgYWRtaW4gYWRkcmVzcwAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
         "AAAAAAAAAM1QYXVzZSBvciB1bnBhdXNlIHRoZSBjb250cmFjdCAoZW1lcmdlbmN5IHN0b3AuKQoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgYWRtaW5gIC0gVGhlIGFkbWluIGFkZHJlc3MKKiBgcGF1c2VkYCAtIFBhdXNlIG9yIHVucGF1c2UgdGhlIGNvbnRyYWN0IG9wZXJhdGlvbnMgd2hpY2ggY2hhbmdlCmxlZGdlciBzdGF0ZXMuAAAAAAAABXBhdXNlAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAZwYXVzZWQAAAAAAAEAAAAA",
-        "AAAAAAAAAF5SZXF1aXJlIHRoYXQgdGhlIGNvbnRyYWN0IGlzIG5vdCBwYXVzZWQsIHBhbmljIGlmIGl0IGlzCgojIFBhbmljcwoqIElmIHRoZSBjb250cmFjdCBpcyBwYXVzZWQuAAAAAAAScmVxdWlyZV9ub3RfcGF1c2VkAAAAAAAAAAAAAA==",
+        "AAAAAAAAAR1SZXF1aXJlIHRoYXQgdGhlIGNvbnRyYWN0IGlzIG5vdCBwYXVzZWQgYW5kIHRoYXQgaXRzIHN0b3JhZ2UgbGF5b3V0CmlzIHVwIHRvIGRhdGUsIHBhbmljIGlmIG5vdC4KClRoZSBmaXJzdCBjYWxsIGFmdGVyIGFuIHVwZ3JhZGUgcnVucyB0aGUgbWlncmF0aW9uIG9mIHRoZSB1cGdyYWRlCnByb3Bvc2FsLCBpZiBhbnkuCgojIFBhbmljcwoqIElmIHRoZSBjb250cmFjdCBpcyBwYXVzZWQuCiogSWYgdGhlIHN0b3JhZ2UgbGF5b3V0IHN0aWxsIGhhcyB0byBiZSBtaWdyYXRlZCwgc2VlIGBtaWdyYXRlYC4AAAAAAAAScmVxdWlyZV9ub3RfcGF1c2VkAAAAAAAAAAAAAA==",
         "AAAAAAAAAJ1HZXQgY3VycmVudCBhZG1pbmlzdHJhdG9ycyBjb25maWd1cmF0aW9uLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKCiMgUmV0dXJucwoqIGB0eXBlczo6QWRtaW5zQ29uZmlnYCAtIFRoZSBhZG1pbmlzdHJhdG9ycyBjb25maWd1cmF0aW9uAAAAAAAAEWdldF9hZG1pbnNfY29uZmlnAAAAAAAAAAAAAAEAAAfQAAAADEFkbWluc0NvbmZpZw==",
         "AAAAAAAAAJpTZXQgdGhlIFNvcm9iYW4gRG9tYWluIGNvbnRyYWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgYWRtaW5gIC0gVGhlIGFkbWluIGFkZHJlc3MKKiBgZG9tYWluX2NvbnRyYWN0YCAtIFRoZSBuZXcgZG9tYWluIGNvbnRyYWN0AAAAAAATc2V0X2RvbWFpbl9jb250cmFjdAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAD2RvbWFpbl9jb250cmFjdAAAAAfQAAAACENvbnRyYWN0AAAAAA==",
         "AAAAAAAAAJ5TZXQgdGhlIENvbGxhdGVyYWwgY29udHJhY3QuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBhZG1pbmAgLSBUaGUgYWRtaW4gYWRkcmVzcwoqIGBjb2xsYXRlcmFsX2NvbnRyYWN0YCAtIFRoZSBuZXcgY29sbGF0ZXJhbCBjb250cmFjdAAAAAAAF3NldF9jb2xsYXRlcmFsX2NvbnRyYWN0AAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAATY29sbGF0ZXJhbF9jb250cmFjdAAAAAfQAAAACENvbnRyYWN0AAAAAA==",
-        "AAAAAAAAAU5Qcm9wb3NlIGEgY29udHJhY3QgdXBncmFkZS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGFkbWluYCAtIEFuIGFkbWluIGFkZHJlc3MKKiBgbmV3X3dhc21faGFzaGAgLSBUaGUgbmV3IFdBU00gaGFzaAoqIGBuZXdfYWRtaW5zX2NvbmZpZ2AgLSBPcHRpb25hbCBuZXcgYWRtaW4gY29uZmlndXJhdGlvbiAoTm9uZSB0byBrZWVwIGN1cnJlbnQpCgojIFBhbmljcwoqIElmIHRoZSBhZG1pbiBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZXJlIGlzIGFscmVhZHkgYW4gZXhpc3RpbmcgcHJvcG9zYWwgKGNhbmNlbCB0aGUgcHJldmlvdXMgZmlyc3QpAAAAAAAPcHJvcG9zZV91cGdyYWRlAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAABFuZXdfYWRtaW5zX2NvbmZpZwAAAAAAA+gAAAfQAAAADEFkbWluc0NvbmZpZwAAAAA=",
-        "AAAAAAAAANVBcHByb3ZlIGFuIHVwZ3JhZGUgcHJvcG9zYWwKCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGFkbWluYCAtIEFuIGFkbWluIGFkZHJlc3MKCiMgUGFuaWNzCiogSWYgdGhlIGFkbWluIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIGFkbWluIGFscmVhZHkgYXBwcm92ZWQKKiBJZiB0aGVyZSBpcyBubyB1cGdyYWRlIHRvIGFwcHJvdmUAAAAAAAAPYXBwcm92ZV91cGdyYWRlAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAgBFeGVjdXRlIG9yIGNhbmNlbCB1cGdyYWRlIHByb3Bvc2FsCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBhZG1pbmAgLSBBbiBhZG1pbiBhZGRyZXNzCiogYGFjY2VwdGAgLSB0cnVlIHRvIGFjY2VwdCBhbmQgZmFsc2UgdG8gcmVqZWN0LgoKVXBncmFkZXMgY2FuIGFsd2F5cyBiZSBjYW5jZWxsZWQgYnV0IG9ubHkgZXhlY3V0ZWQgaWYgdGhlcmUgYXJlIGVub3VnaAphcHByb3ZhbHMgYW5kIHRoZSB0aW1lbG9jayBwZXJpb2QgaXMgb3Zlci4KTm90ZSB0aGF0IGN1cnJlbnQgZ292ZXJuYW5jZSBydWxlcyBhcHBseS4gTmV3IGNvbmZpZyBjaGFuZ2VzIG9ubHkKaW4gZm9yY2UgYWZ0ZXIgYW4gdXBkYXRlLgoKIyBQYW5pY3MKKiBJZiB0aGUgYWRtaW4gaXMgbm90IGF1dGhvcml6ZWQKKiBJZiBpdCBpcyB0b28gZWFybHkgdG8gZXhlY3V0ZQoqIElmIHRoZXJlIGFyZSBub3QgZW5vdWdoIGFwcHJvdmFscwoqIElmIHRoZXJlIGlzIG5vIHVwZ3JhZGUgdG8gZXhlY3V0ZQAAABBmaW5hbGl6ZV91cGdyYWRlAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAZhY2NlcHQAAAAAAAEAAAAA",
-        "AAAAAAAAABxHZXQgdXBncmFkZSBwcm9wb3NhbCBkZXRhaWxzAAAAFGdldF91cGdyYWRlX3Byb3Bvc2FsAAAAAAAAAAEAAAfQAAAAD1VwZ3JhZGVQcm9wb3NhbAA=",
-        "AAAAAAAAAFlHZXQgdGhlIGN1cnJlbnQgdmVyc2lvbiBvZiB0aGUgY29udHJhY3QuCgojIFJldHVybnMKKiBgdTMyYCAtIFRoZSBjb250cmFjdCB2ZXJzaW9uIG51bWJlcgAAAAAAAAd2ZXJzaW9uAAAAAAAAAAABAAAABA==",
-        "AAAAAAAAA15SZWdpc3RlciBhIG5ldyBwcm9qZWN0LgoKQ3JlYXRlcyBhIG5ldyBwcm9qZWN0IGVudHJ5IHdpdGggbWFpbnRhaW5lcnMsIFVSTCwgYW5kIGNvbW1pdCBoYXNoLgpBbHNvIHJlZ2lzdGVycyB0aGUgcHJvamVjdCBuYW1lIGluIHRoZSBkb21haW4gY29udHJhY3QgaWYgbm90IGFscmVhZHkgcmVnaXN0ZXJlZC4KVGhlIHByb2plY3Qga2V5IGlzIGdlbmVyYXRlZCB1c2luZyBrZWNjYWsyNTYgaGFzaCBvZiB0aGUgcHJvamVjdCBuYW1lLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgbmFtZWAgLSBUaGUgcHJvamVjdCBuYW1lIChtYXggMTUgY2hhcmFjdGVycykKKiBgbWFpbnRhaW5lcnNgIC0gTGlzdCBvZiBtYWludGFpbmVyIGFkZHJlc3NlcyBmb3IgdGhlIHByb2plY3QKKiBgdXJsYCAtIFRoZSBwcm9qZWN0J3MgR2l0IHJlcG9zaXRvcnkgVVJMCiogYGlwZnNgIC0gQ0lEIG9mIHRoZSB0YW5zdS50b21sIGZpbGUgd2l0aCBhc3NvY2lhdGVkIG1ldGFkYXRhCgojIFJldHVybnMKKiBgQnl0ZXNgIC0gVGhlIHByb2plY3Qga2V5IChrZWNjYWsyNTYgaGFzaCBvZiB0aGUgbmFtZSkKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgbmFtZSBpcyBsb25nZXIgdGhhbiAxNSBjaGFyYWN0ZXJzCiogSWYgdGhlIHByb2plY3QgYWxyZWFkeSBleGlzdHMKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBkb21haW4gcmVnaXN0cmF0aW9uIGZhaWxzCiogSWYgdGhlIG1haW50YWluZXIgZG9lc24ndCBvd24gYW4gZXhpc3RpbmcgZG9tYWluAAAAAAAIcmVnaXN0ZXIAAAAFAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAARuYW1lAAAAEAAAAAAAAAALbWFpbnRhaW5lcnMAAAAD6gAAABMAAAAAAAAAA3VybAAAAAAQAAAAAAAAAARpcGZzAAAAEAAAAAEAAAAO",
-        "AAAAAAAAAfhVcGRhdGUgdGhlIGNvbmZpZ3VyYXRpb24gb2YgYW4gZXhpc3RpbmcgcHJvamVjdC4KCkFsbG93cyBtYWludGFpbmVycyB0byBjaGFuZ2UgdGhlIHByb2plY3QncyBVUkwsIElQRlMgbWV0YWRhdGEsIGFuZCBtYWludGFpbmVyIGxpc3QuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBrZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgbWFpbnRhaW5lcnNgIC0gTmV3IGxpc3Qgb2YgbWFpbnRhaW5lciBhZGRyZXNzZXMKKiBgdXJsYCAtIE5ldyBHaXQgcmVwb3NpdG9yeSBVUkwKKiBgaXBmc2AgLSBOZXcgQ0lEIG9mIHRoZSB0YW5zdS50b21sIGZpbGUgd2l0aCBtZXRhZGF0YQoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0CiogSWYgdGhlIG1haW50YWluZXIgaXMgbm90IGF1dGhvcml6ZWQAAAANdXBkYXRlX2NvbmZpZwAAAAAAAAUAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAA2tleQAAAAAOAAAAAAAAAAttYWludGFpbmVycwAAAAPqAAAAEwAAAAAAAAADdXJsAAAAABAAAAAAAAAABGlwZnMAAAAQAAAAAA==",
-        "AAAAAAAAAXNTZXQgdGhlIGxhdGVzdCBjb21taXQgaGFzaCBmb3IgYSBwcm9qZWN0LgoKVXBkYXRlcyB0aGUgY3VycmVudCBjb21taXQgaGFzaCBmb3IgdGhlIHNwZWNpZmllZCBwcm9qZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgaGFzaGAgLSBUaGUgbmV3IGNvbW1pdCBoYXNoCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAAAAAAGY29tbWl0AAAAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAARoYXNoAAAAEAAAAAA=",
+        "AAAAAAAAAXtSZXNlcnZlIGEgcHJvamVjdCBuYW1lIG9yIHJlbGVhc2UgaXQuCgpBIHJlc2VydmVkIG5hbWUgY2Fubm90IGJlIHJlZ2lzdGVyZWQgbm9yIHVzZWQgdG8gcmVuYW1lIGEgcHJvamVjdC4KRXhpc3RpbmcgcHJvamVjdHMgYXJlIG5vdCBhZmZlY3RlZC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGFkbWluYCAtIFRoZSBhZG1pbiBhZGRyZXNzCiogYG5hbWVgIC0gVGhlIHByb2plY3QgbmFtZSwgbm9ybWFsaXplZCBiZWZvcmUgdXNlCiogYHJlc2VydmVkYCAtIFJlc2VydmUgb3IgcmVsZWFzZSB0aGUgbmFtZQoKIyBQYW5pY3MKKiBJZiB0aGUgYWRtaW4gaXMgbm90IGF1dGhvcml6ZWQKKiBJZiB0aGUgbmFtZSBpcyBpbnZhbGlkAAAAABFzZXRfcmVzZXJ2ZWRfbmFtZQAAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAACHJlc2VydmVkAAAAAQAAAAA=",
+        "AAAAAAAAANtDaGVjayBpZiBhIHByb2plY3QgbmFtZSBpcyByZXNlcnZlZC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG5hbWVgIC0gVGhlIHByb2plY3QgbmFtZSwgbm9ybWFsaXplZCBiZWZvcmUgdXNlCgojIFJldHVybnMKKiBgYm9vbGAgLSBUcnVlIGlmIHRoZSBuYW1lIGlzIHJlc2VydmVkCgojIFBhbmljcwoqIElmIHRoZSBuYW1lIGlzIGludmFsaWQAAAAAEGlzX25hbWVfcmVzZXJ2ZWQAAAABAAAAAAAAAARuYW1lAAAAEAAAAAEAAAAB",
+        "AAAAAAAAAuZQcm9wb3NlIGEgY29udHJhY3QgdXBncmFkZS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGFkbWluYCAtIEFuIGFkbWluIGFkZHJlc3MKKiBgbmV3X3dhc21faGFzaGAgLSBUaGUgbmV3IFdBU00gaGFzaAoqIGBuZXdfYWRtaW5zX2NvbmZpZ2AgLSBPcHRpb25hbCBuZXcgYWRtaW4gY29uZmlndXJhdGlvbiAoTm9uZSB0byBrZWVwIGN1cnJlbnQpCmluY2x1ZGluZyB0aGUgdGltZWxvY2sgb2YgdGhlIG5leHQgdXBncmFkZXMKKiBgbWlncmF0aW9uYCAtIE9wdGlvbmFsIG1pZ3JhdGlvbiB0byBydW4gYWZ0ZXIgdGhlIHVwZ3JhZGUsIG9ubHkKYG1pZ3JhdGVgIHdpdGggaXRzIGBsaW1pdGAgYXJndW1lbnQKClRoZSB1cGdyYWRlIGNhbiBiZSBleGVjdXRlZCBvbmNlIHRoZSB0aW1lbG9jayBvZiB0aGUgY3VycmVudApjb25maWd1cmF0aW9uIGlzIG92ZXIsIGFuZCB1bnRpbCB0aGUgZXhlY3V0aW9uIHdpbmRvdyBjbG9zZXMuIEFuCmV4cGlyZWQgcHJvcG9zYWwgaXMgY2FuY2VsbGVkIGJ5IHRoZSBuZXh0IG9uZS4KCiMgUGFuaWNzCiogSWYgdGhlIGFkbWluIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlcmUgaXMgYWxyZWFkeSBhbiBleGlzdGluZyBwcm9wb3NhbCAoY2FuY2VsIHRoZSBwcmV2aW91cyBmaXJzdCkKKiBJZiB0aGUgbmV3IHRocmVzaG9sZCBvciB0aW1lbG9jayBpcyBvdXQgb2YgYm91bmRzCiogSWYgdGhlIG1pZ3JhdGlvbiBpcyBub3Qgc3VwcG9ydGVkAAAAAAAPcHJvcG9zZV91cGdyYWRlAAAAAAQAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAABFuZXdfYWRtaW5zX2NvbmZpZwAAAAAAA+gAAAfQAAAADEFkbWluc0NvbmZpZwAAAAAAAAAJbWlncmF0aW9uAAAAAAAD6AAAB9AAAAANTWlncmF0aW9uQ2FsbAAAAAAAAAA=",
+        "AAAAAAAAAOZBcHByb3ZlIGFuIHVwZ3JhZGUgcHJvcG9zYWwKCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGFkbWluYCAtIEFuIGFkbWluIGFkZHJlc3MKCiMgUGFuaWNzCiogSWYgdGhlIGFkbWluIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIGFkbWluIGFscmVhZHkgYXBwcm92ZWQKKiBJZiB0aGVyZSBpcyBubyB1cGdyYWRlIHRvIGFwcHJvdmUgb3IgaWYgaXQgZXhwaXJlZAAAAAAAD2FwcHJvdmVfdXBncmFkZQAAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAAPhSZXZva2UgdGhlIGFwcHJvdmFsIG9mIGFuIHVwZ3JhZGUgcHJvcG9zYWwKCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGFkbWluYCAtIEFuIGFkbWluIGFkZHJlc3MKCiMgUGFuaWNzCiogSWYgdGhlIGFkbWluIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIGFkbWluIGRpZCBub3QgYXBwcm92ZQoqIElmIHRoZXJlIGlzIG5vIHVwZ3JhZGUgdG8gcmV2b2tlIGZyb20gb3IgaWYgaXQgZXhwaXJlZAAAABdyZXZva2VfdXBncmFkZV9hcHByb3ZhbAAAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAA4JFeGVjdXRlIG9yIGNhbmNlbCB1cGdyYWRlIHByb3Bvc2FsCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBhZG1pbmAgLSBBbiBhZG1pbiBhZGRyZXNzCiogYGFjY2VwdGAgLSB0cnVlIHRvIGFjY2VwdCBhbmQgZmFsc2UgdG8gcmVqZWN0LgoKVXBncmFkZXMgY2FuIGFsd2F5cyBiZSBjYW5jZWxsZWQgYnV0IG9ubHkgZXhlY3V0ZWQgaWYgdGhlcmUgYXJlIGVub3VnaAphcHByb3ZhbHMgYW5kIHRoZSB0aW1lbG9jayBwZXJpb2QgaXMgb3Zlci4KTm90ZSB0aGF0IGN1cnJlbnQgZ292ZXJuYW5jZSBydWxlcyBhcHBseS4gTmV3IGNvbmZpZyBjaGFuZ2VzIG9ubHkKaW4gZm9yY2UgYWZ0ZXIgYW4gdXBkYXRlLgoKVGhlIG5ldyBXQVNNIG9ubHkgcnVucyBvbmNlIHRoaXMgY2FsbCBlbmRzLCBzbyB0aGUgbWlncmF0aW9uIG9mIHRoZQpwcm9wb3NhbCBjYW5ub3QgcnVuIGhlcmUuIEl0IGlzIHNjaGVkdWxlZCBpbnN0ZWFkIGFuZCBydW5zIG9uIHRoZSBmaXJzdApjYWxsIGFmdGVyIHRoZSB1cGdyYWRlLCBiZWZvcmUgYW55dGhpbmcgZWxzZS4gQ2FsbHMgY2hhbmdpbmcgdGhlIGxlZGdlcgpmYWlsIHdpdGggYE1pZ3JhdGlvblBlbmRpbmdgIHVudGlsIHRoZSBzdG9yYWdlIGxheW91dCBpcyB1cCB0byBkYXRlOgppZiB0aGUgbWlncmF0aW9uIGRvZXMgbm90IGNvbXBsZXRlIGluIGEgc2luZ2xlIGJhdGNoLCBhZG1pbnMgZmluaXNoIGl0CndpdGggYG1pZ3JhdGVgLgoKIyBQYW5pY3MKKiBJZiB0aGUgYWRtaW4gaXMgbm90IGF1dGhvcml6ZWQKKiBJZiBpdCBpcyB0b28gZWFybHkgdG8gZXhlY3V0ZQoqIElmIHRoZXJlIGFyZSBub3QgZW5vdWdoIGFwcHJvdmFscwoqIElmIHRoZXJlIGlzIG5vIHVwZ3JhZGUgdG8gZXhlY3V0ZSBvciBpZiBpdCBleHBpcmVkAAAAAAAQZmluYWxpemVfdXBncmFkZQAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAGYWNjZXB0AAAAAAABAAAAAA==",
+        "AAAAAAAAAFlHZXQgdXBncmFkZSBwcm9wb3NhbCBkZXRhaWxzCgojIFBhbmljcwoqIElmIHRoZXJlIGlzIG5vIHVwZ3JhZGUgcHJvcG9zYWwgb3IgaWYgaXQgZXhwaXJlZAAAAAAAABRnZXRfdXBncmFkZV9wcm9wb3NhbAAAAAAAAAABAAAH0AAAAA9VcGdyYWRlUHJvcG9zYWwA",
+        "AAAAAAAAAL1HZXQgdGhlIGN1cnJlbnQgdmVyc2lvbiBvZiB0aGUgY29udHJhY3QuCgpUaGlzIGlzIHRoZSB2ZXJzaW9uIG9mIHRoZSBjb2RlLCBzZWUgYGdldF9taWdyYXRpb25fc3RhdHVzYCBmb3IgdGhlCnZlcnNpb24gb2YgdGhlIHN0b3JhZ2UgbGF5b3V0LgoKIyBSZXR1cm5zCiogYHUzMmAgLSBUaGUgY29udHJhY3QgdmVyc2lvbiBudW1iZXIAAAAAAAAHdmVyc2lvbgAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAA+pSZWdpc3RlciBhIG5ldyBwcm9qZWN0LgoKQ3JlYXRlcyBhIG5ldyBwcm9qZWN0IGVudHJ5IHdpdGggbWFpbnRhaW5lcnMsIFVSTCwgYW5kIGNvbW1pdCBoYXNoLgpBbHNvIHJlZ2lzdGVycyB0aGUgcHJvamVjdCBuYW1lIGluIHRoZSBkb21haW4gY29udHJhY3QgaWYgbm90IGFscmVhZHkgcmVnaXN0ZXJlZC4KVGhlIHByb2plY3Qga2V5IGlzIHRoZSBrZWNjYWsyNTYgaGFzaCBvZiB0aGUgbmFtZSBjb252ZXJ0ZWQgdG8gbG93ZXIgY2FzZS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYG5hbWVgIC0gVGhlIHByb2plY3QgbmFtZSAobWF4IDE1IEFTQ0lJIGxldHRlcnMpCiogYG1haW50YWluZXJzYCAtIExpc3Qgb2YgbWFpbnRhaW5lciBhZGRyZXNzZXMgZm9yIHRoZSBwcm9qZWN0CiogYHVybGAgLSBUaGUgcHJvamVjdCdzIEdpdCByZXBvc2l0b3J5IFVSTAoqIGBpcGZzYCAtIENJRCBvZiB0aGUgdGFuc3UudG9tbCBmaWxlIHdpdGggYXNzb2NpYXRlZCBtZXRhZGF0YQoKIyBSZXR1cm5zCiogYEJ5dGVzYCAtIFRoZSBwcm9qZWN0IGtleSAoa2VjY2FrMjU2IGhhc2ggb2YgdGhlIG5hbWUpCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IG5hbWUgaXMgZW1wdHkgb3IgbG9uZ2VyIHRoYW4gMTUgY2hhcmFjdGVycwoqIElmIHRoZSBwcm9qZWN0IG5hbWUgY29udGFpbnMgYW55dGhpbmcgZWxzZSB0aGFuIEFTQ0lJIGxldHRlcnMKKiBJZiB0aGUgcHJvamVjdCBhbHJlYWR5IGV4aXN0cwoqIElmIHRoZSBwcm9qZWN0IG5hbWUgaXMgcmVzZXJ2ZWQKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBDSUQgaXMgbWFsZm9ybWVkCiogSWYgdGhlIGRvbWFpbiByZWdpc3RyYXRpb24gZmFpbHMKKiBJZiB0aGUgbWFpbnRhaW5lciBkb2Vzbid0IG93biBhbiBleGlzdGluZyBkb21haW4AAAAAAAhyZWdpc3RlcgAAAAUAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAttYWludGFpbmVycwAAAAPqAAAAEwAAAAAAAAADdXJsAAAAABAAAAAAAAAABGlwZnMAAAAQAAAAAQAAAA4=",
+        "AAAAAAAAAtJSZWdpc3RlciBhIG5ldyBwcm9qZWN0IGFzIGEgZm9yayBvZiBhbm90aGVyIHByb2plY3QuCgpTYW1lIGFzIGByZWdpc3RlcmAsIHJlY29yZGluZyB0aGUgdXBzdHJlYW0gcHJvamVjdCBhbmQgaXRzIGN1cnJlbnQKY29tbWl0IGhhc2gsIGlmIGFueS4gVGhlIGZvcmsgdXNlcyB0aGUgaGFzaCBhbGdvcml0aG0gb2YgdGhlIHVwc3RyZWFtCnByb2plY3QuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBuYW1lYCAtIFRoZSBwcm9qZWN0IG5hbWUgKG1heCAxNSBBU0NJSSBsZXR0ZXJzKQoqIGB1cHN0cmVhbV9rZXlgIC0gVGhlIGtleSBvZiB0aGUgZm9ya2VkIHByb2plY3QKKiBgbWFpbnRhaW5lcnNgIC0gTGlzdCBvZiBtYWludGFpbmVyIGFkZHJlc3NlcyBmb3IgdGhlIHByb2plY3QKKiBgdXJsYCAtIFRoZSBwcm9qZWN0J3MgR2l0IHJlcG9zaXRvcnkgVVJMCiogYGlwZnNgIC0gQ0lEIG9mIHRoZSB0YW5zdS50b21sIGZpbGUgd2l0aCBhc3NvY2lhdGVkIG1ldGFkYXRhCgojIFJldHVybnMKKiBgQnl0ZXNgIC0gVGhlIHByb2plY3Qga2V5IChrZWNjYWsyNTYgaGFzaCBvZiB0aGUgbmFtZSkKCiMgUGFuaWNzCiogSWYgdGhlIHVwc3RyZWFtIHByb2plY3QgZG9lc24ndCBleGlzdAoqIFNhbWUgYXMgYHJlZ2lzdGVyYAAAAAAADXJlZ2lzdGVyX2ZvcmsAAAAAAAAGAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAMdXBzdHJlYW1fa2V5AAAADgAAAAAAAAALbWFpbnRhaW5lcnMAAAAD6gAAABMAAAAAAAAAA3VybAAAAAAQAAAAAAAAAARpcGZzAAAAEAAAAAEAAAAO",
+        "AAAAAAAAAOZHZXQgYSBwYWdlIG9mIHRoZSBmb3JrcyBvZiBhIHByb2plY3QuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGB1cHN0cmVhbV9rZXlgIC0gVGhlIGtleSBvZiB0aGUgZm9ya2VkIHByb2plY3QKKiBgcGFnZWAgLSBUaGUgcGFnZSBudW1iZXIgKDAtYmFzZWQpCgojIFJldHVybnMKKiBgVmVjPEJ5dGVzPmAgLSBLZXlzIG9mIHRoZSBmb3JrcywgZW1wdHkgaWYgbm9uZQAAAAAACWdldF9mb3JrcwAAAAAAAAIAAAAAAAAADHVwc3RyZWFtX2tleQAAAA4AAAAAAAAABHBhZ2UAAAAEAAAAAQAAA+oAAAAO",
+        "AAAAAAAAAghVcGRhdGUgdGhlIGNvbmZpZ3VyYXRpb24gb2YgYW4gZXhpc3RpbmcgcHJvamVjdC4KCkFsbG93cyBtYWludGFpbmVycyB0byBjaGFuZ2UgdGhlIHByb2plY3QncyBVUkwgYW5kIElQRlMgbWV0YWRhdGEuCk1haW50YWluZXJzIGFyZSBjaGFuZ2VkIHdpdGggYHByb3Bvc2VfbWFpbnRhaW5lcnNfY2hhbmdlYC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYGtleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGB1cmxgIC0gTmV3IEdpdCByZXBvc2l0b3J5IFVSTAoqIGBpcGZzYCAtIE5ldyBDSUQgb2YgdGhlIHRhbnN1LnRvbWwgZmlsZSB3aXRoIG1ldGFkYXRhCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBDSUQgaXMgbWFsZm9ybWVkAAAADXVwZGF0ZV9jb25maWcAAAAAAAAEAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAANrZXkAAAAADgAAAAAAAAADdXJsAAAAABAAAAAAAAAABGlwZnMAAAAQAAAAAA==",
+        "AAAAAAAAA2pTZXQgdGhlIHN0cnVjdHVyZWQgbWV0YWRhdGEgb2YgYSBwcm9qZWN0LgoKVGhpcyBtaXJyb3JzIHRoZSBtYWluIGZpZWxkcyBvZiB0aGUgdGFuc3UudG9tbCBmaWxlIHNvIHRoYXQgdGhleSBjYW4KYmUgcmVhZCBvbi1jaGFpbi4gRW1wdHkgZmllbGRzIGFyZSBub3Qgc2V0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgbWV0YWRhdGFgIC0gVGhlIG1ldGFkYXRhCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBsaWNlbnNlIGlzIGxvbmdlciB0aGFuIDY0IGNoYXJhY3RlcnMgb3Igbm90IGFuIFNQRFggZXhwcmVzc2lvbgoqIElmIHRoZSBkZXNjcmlwdGlvbiBpcyBsb25nZXIgdGhhbiAyNTYgY2hhcmFjdGVycyBvciBoYXMgY29udHJvbApjaGFyYWN0ZXJzCiogSWYgdGhlcmUgYXJlIG1vcmUgdGhhbiAxMCB0YWdzLCBvciBhIHRhZyBpcyBlbXB0eSwgbG9uZ2VyIHRoYW4gMzIKY2hhcmFjdGVycyBvciBub3QgbWFkZSBvZiBsb3dlcmNhc2UgbGV0dGVycywgZGlnaXRzIGFuZCBkYXNoZXMKKiBJZiB0aGVyZSBhcmUgbW9yZSB0aGFuIDUgbWlycm9ycywgb3IgYSBtaXJyb3Igb3IgdGhlIGhvbWVwYWdlIGlzCmxvbmdlciB0aGFuIDEyOCBjaGFyYWN0ZXJzCiogSWYgYSBtaXJyb3Igb3IgYSBub24tZW1wdHkgaG9tZXBhZ2UgaXMgbm90IGFuIGBodHRwczovL2AgVVJMCndpdGhvdXQgc3BhY2VzAAAAAAAMc2V0X21ldGFkYXRhAAAAAwAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAIbWV0YWRhdGEAAAfQAAAACE1ldGFkYXRhAAAAAA==",
+        "AAAAAAAAA3BQcm9wb3NlIHRvIGFkZCBhbmQgcmVtb3ZlIG1haW50YWluZXJzIG9mIGEgcHJvamVjdC4KClRoZSBjaGFuZ2UgbmVlZHMgdGhlIGFwcHJvdmFsIG9mIGEgcXVvcnVtIG9mIHRoZSBjdXJyZW50IG1haW50YWluZXJzLApzZWUgYGdldF9tYWludGFpbmVyc19xdW9ydW1gLCBhbmQgY2FuIG9ubHkgYmUgZXhlY3V0ZWQgYWZ0ZXIgYQoyNCBob3VycyBkZWxheS4gVGhlIHByb3Bvc2VyJ3MgYXBwcm92YWwgaXMgY291bnRlZC4gQSBwcm9qZWN0IGhhcyBhdAptb3N0IG9uZSBwZW5kaW5nIGNoYW5nZS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGFkZGAgLSBUaGUgYWRkcmVzc2VzIHRvIGFkZCBhcyBtYWludGFpbmVycwoqIGByZW1vdmVgIC0gVGhlIG1haW50YWluZXJzIHRvIHJlbW92ZQoqIFtgT3B0aW9uPHF1b3J1bT5gXSAtIE5ldyBxdW9ydW0gKE5vbmUgdG8ga2VlcCBjdXJyZW50KQoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0CiogSWYgdGhlIG1haW50YWluZXIgaXMgbm90IGF1dGhvcml6ZWQKKiBJZiB0aGVyZSBpcyBhbHJlYWR5IGEgcGVuZGluZyBjaGFuZ2UgKGNhbmNlbCB0aGUgcHJldmlvdXMgZmlyc3QpCiogSWYgdGhlIGNoYW5nZSBpcyBlbXB0eSwgYWRkcyBhIG1haW50YWluZXIgb3IgcmVtb3ZlcyBhIG5vbi1tYWludGFpbmVyCiogSWYgdGhlIHJlc3VsdGluZyBtYWludGFpbmVycyBhcmUgZmV3ZXIgdGhhbiB0aGUgcXVvcnVtIG9yIHRoZSBjb21taXQKdGhyZXNob2xkAAAAGnByb3Bvc2VfbWFpbnRhaW5lcnNfY2hhbmdlAAAAAAAFAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAANhZGQAAAAD6gAAABMAAAAAAAAABnJlbW92ZQAAAAAD6gAAABMAAAAAAAAABnF1b3J1bQAAAAAD6AAAAAQAAAAA",
+        "AAAAAAAAAWFBcHByb3ZlIHRoZSBwZW5kaW5nIGNoYW5nZSBvZiBtYWludGFpbmVycy4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZXJlIGlzIG5vIGNoYW5nZSB0byBhcHByb3ZlCiogSWYgdGhlIG1haW50YWluZXIgYWxyZWFkeSBhcHByb3ZlZAAAAAAAABphcHByb3ZlX21haW50YWluZXJzX2NoYW5nZQAAAAAAAgAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAA=",
+        "AAAAAAAAAphFeGVjdXRlIG9yIGNhbmNlbCB0aGUgcGVuZGluZyBjaGFuZ2Ugb2YgbWFpbnRhaW5lcnMuCgpDaGFuZ2VzIGNhbiBhbHdheXMgYmUgY2FuY2VsbGVkIGJ1dCBvbmx5IGV4ZWN1dGVkIGlmIGVub3VnaCBjdXJyZW50Cm1haW50YWluZXJzIGFwcHJvdmVkIGl0IGFuZCB0aGUgZGVsYXkgaXMgb3Zlci4gRW1pdHMgYQpgTWFpbnRhaW5lckFkZGVkYCBvciBgTWFpbnRhaW5lclJlbW92ZWRgIGV2ZW50IHBlciBhZGRyZXNzLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgYWNjZXB0YCAtIHRydWUgdG8gZXhlY3V0ZSBhbmQgZmFsc2UgdG8gY2FuY2VsCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZXJlIGlzIG5vIGNoYW5nZSB0byBleGVjdXRlCiogSWYgdGhlcmUgYXJlIG5vdCBlbm91Z2ggYXBwcm92YWxzCiogSWYgaXQgaXMgdG9vIGVhcmx5IHRvIGV4ZWN1dGUKKiBJZiB0aGUgY2hhbmdlIGlzIG5vdCB2YWxpZCBhbnltb3JlAAAAG2ZpbmFsaXplX21haW50YWluZXJzX2NoYW5nZQAAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAZhY2NlcHQAAAAAAAEAAAAA",
+        "AAAAAAAAAOhHZXQgdGhlIHBlbmRpbmcgY2hhbmdlIG9mIG1haW50YWluZXJzLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGB0eXBlczo6TWFpbnRhaW5lcnNDaGFuZ2VgIC0gVGhlIHBlbmRpbmcgY2hhbmdlCgojIFBhbmljcwoqIElmIHRoZXJlIGlzIG5vIHBlbmRpbmcgY2hhbmdlAAAAFmdldF9tYWludGFpbmVyc19jaGFuZ2UAAAAAAAEAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAH0AAAABFNYWludGFpbmVyc0NoYW5nZQAAAA==",
+        "AAAAAAAAARVHZXQgaG93IG1hbnkgbWFpbnRhaW5lciBhcHByb3ZhbHMgYXJlIG5lZWRlZCB0byBjaGFuZ2UgdGhlIG1haW50YWluZXJzLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHF1b3J1bSwgYSBtYWpvcml0eSBvZiB0aGUgbWFpbnRhaW5lcnMgaWYgbm90IHNldAoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0AAAAAAAAFmdldF9tYWludGFpbmVyc19xdW9ydW0AAAAAAAEAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAABA==",
+        "AAAAAAAAAp5BcmNoaXZlIGEgcHJvamVjdCwgbWFraW5nIGl0IHJlYWQtb25seS4KCkNvbW1pdHMsIHJlbGVhc2VzLCBwcm9wb3NhbHMsIHZvdGVzIGFuZCBiYWRnZSBjaGFuZ2VzIGFyZSByZWplY3RlZAp3aGlsZSB0aGUgaGlzdG9yeSBzdGF5cyBxdWVyeWFibGUuIE9wZW4gcHJvcG9zYWxzIGNhbiBzdGlsbCBiZQpleGVjdXRlZCBvciByZXZva2VkLiBBbiBhZG1pbiBhcmNoaXZlcyBhIHByb2plY3QgZGlyZWN0bHksIG90aGVyd2lzZQplYWNoIGNhbGwgaXMgYSBtYWludGFpbmVyIGFwcHJvdmFsIGFuZCB0aGUgcHJvamVjdCBpcyBhcmNoaXZlZCBvbmNlCnRoZSBtYWludGFpbmVycyBxdW9ydW0gaXMgcmVhY2hlZC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGNhbGxlcmAgLSBBbiBhZG1pbiBvciBhIG1haW50YWluZXIgb2YgdGhlIHByb2plY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBjYWxsZXIgaXMgbmVpdGhlciBhbiBhZG1pbiBub3IgYSBtYWludGFpbmVyCiogSWYgdGhlIHByb2plY3QgaXMgYWxyZWFkeSBhcmNoaXZlZCBvciBkZXJlZ2lzdGVyZWQKKiBJZiB0aGUgbWFpbnRhaW5lciBhbHJlYWR5IGFwcHJvdmVkAAAAAAAPYXJjaGl2ZV9wcm9qZWN0AAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAA=",
+        "AAAAAAAAA51EZXJlZ2lzdGVyIGEgcHJvamVjdC4KClRoZSBwcm9qZWN0IGlzIHJlbW92ZWQgZnJvbSB0aGUgcHJvamVjdHMgb2YgaXRzIG1haW50YWluZXJzIGFuZAptZW1iZXJzLCBhbmQgYmVjb21lcyByZWFkLW9ubHkgbGlrZSBhbiBhcmNoaXZlZCBwcm9qZWN0LiBJdCBrZWVwcyBpdHMKcGxhY2UgaW4gdGhlIHByb2plY3RzIGxpc3Rpbmcgc28gdGhhdCBjdXJzb3JzIHN0YXkgdmFsaWQsIGJ1dCBpcyBubwpsb25nZXIgcmV0dXJuZWQuIEl0cyBkYXRhIHN0YXlzIHF1ZXJ5YWJsZSBhbmQgaXRzCm5hbWUgY2Fubm90IGJlIHJlZ2lzdGVyZWQgYWdhaW4uIEFuIGFkbWluIGRlcmVnaXN0ZXJzIGEgcHJvamVjdApkaXJlY3RseSwgb3RoZXJ3aXNlIGVhY2ggY2FsbCBpcyBhIG1haW50YWluZXIgYXBwcm92YWwgYW5kIHRoZQpwcm9qZWN0IGlzIGRlcmVnaXN0ZXJlZCBvbmNlIHRoZSBtYWludGFpbmVycyBxdW9ydW0gaXMgcmVhY2hlZC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYGNhbGxlcmAgLSBBbiBhZG1pbiBvciBhIG1haW50YWluZXIgb2YgdGhlIHByb2plY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgcmVsZWFzZV9kb21haW5gIC0gQnVybiB0aGUgU29yb2JhbiBkb21haW4gb2YgdGhlIGN1cnJlbnQgcHJvamVjdApuYW1lLCB0aGUgZG9tYWluIG93bmVyIG11c3QgYXV0aG9yaXplIGl0CgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgY2FsbGVyIGlzIG5laXRoZXIgYW4gYWRtaW4gbm9yIGEgbWFpbnRhaW5lcgoqIElmIHRoZSBwcm9qZWN0IGlzIGFscmVhZHkgZGVyZWdpc3RlcmVkCiogSWYgdGhlIG1haW50YWluZXIgYWxyZWFkeSBhcHByb3ZlZAoqIElmIHRoZSBkb21haW4gY2Fubm90IGJlIHJlbGVhc2VkAAAAAAAAEmRlcmVnaXN0ZXJfcHJvamVjdAAAAAAAAwAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAA5yZWxlYXNlX2RvbWFpbgAAAAAAAQAAAAA=",
+        "AAAAAAAAAOpHZXQgdGhlIGxpZmVjeWNsZSBzdGF0dXMgb2YgYSBwcm9qZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGB0eXBlczo6UHJvamVjdFN0YXR1c2AgLSBUaGUgc3RhdHVzIG9mIHRoZSBwcm9qZWN0CgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QAAAAAABJnZXRfcHJvamVjdF9zdGF0dXMAAAAAAAEAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAH0AAAAA1Qcm9qZWN0U3RhdHVzAAAA",
+        "AAAAAAAAA/BSZW5hbWUgYSBwcm9qZWN0LgoKVGhlIGRvbWFpbiBvZiB0aGUgbmV3IG5hbWUgaXMgcmVnaXN0ZXJlZCwgb3IgbXVzdCBiZSBvd25lZCBieSB0aGUKbWFpbnRhaW5lciBhcHByb3ZpbmcgbGFzdC4gVGhlIHByb2plY3Qga2VlcHMgdGhlIGtleSBpdCB3YXMKcmVnaXN0ZXJlZCB3aXRoIGFuZCB0aGUga2V5IG9mIHRoZSBuZXcgbmFtZSBiZWNvbWVzIGFuIGFsaWFzIG9mIGl0LApldmVyeSBjYWxsIGFjY2VwdHMgYm90aCBrZXlzLiBTZWUgYHJlc29sdmVfcHJvamVjdF9rZXlgLgoKRWFjaCBjYWxsIGlzIGEgbWFpbnRhaW5lciBhcHByb3ZhbCBhbmQgdGhlIHByb2plY3QgaXMgcmVuYW1lZCBvbmNlCnRoZSBtYWludGFpbmVycyBxdW9ydW0gaXMgcmVhY2hlZC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYG5hbWVgIC0gVGhlIG5ldyBwcm9qZWN0IG5hbWUgKG1heCAxNSBBU0NJSSBsZXR0ZXJzKQoKIyBSZXR1cm5zCiogYEJ5dGVzYCAtIFRoZSBrZXkgb2YgdGhlIG5ldyBuYW1lIChrZWNjYWsyNTYgaGFzaCBvZiB0aGUgbmFtZSkKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIHByb2plY3QgaXMgYXJjaGl2ZWQgb3IgZGVyZWdpc3RlcmVkCiogSWYgdGhlIG5ldyBuYW1lIGlzIG5vdCAxIHRvIDE1IEFTQ0lJIGxldHRlcnMKKiBJZiB0aGUgbmV3IG5hbWUgaXMgdXNlZCBieSBhIHByb2plY3Qgb3IgcmVzZXJ2ZWQKKiBJZiB0aGUgbWFpbnRhaW5lciBhbHJlYWR5IGFwcHJvdmVkCiogSWYgdGhlIGRvbWFpbiByZWdpc3RyYXRpb24gZmFpbHMKKiBJZiB0aGUgbWFpbnRhaW5lciBkb2Vzbid0IG93biBhbiBleGlzdGluZyBkb21haW4AAAAOcmVuYW1lX3Byb2plY3QAAAAAAAMAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAABG5hbWUAAAAQAAAAAQAAAA4=",
+        "AAAAAAAAAPhHZXQgdGhlIHJlZ2lzdGVyZWQga2V5IG9mIGEgcHJvamVjdC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSByZWdpc3RlcmVkIGtleSBvciB0aGUga2V5IG9mIGEgbmV3IG5hbWUKCiMgUmV0dXJucwoqIGBCeXRlc2AgLSBUaGUga2V5IHRoZSBwcm9qZWN0IHdhcyByZWdpc3RlcmVkIHdpdGgKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAAAABNyZXNvbHZlX3Byb2plY3Rfa2V5AAAAAAEAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAADg==",
+        "AAAAAAAAAntPZmZlciBhIHByb2plY3QgdG8gbmV3IG1haW50YWluZXJzLgoKRWFjaCBjYWxsIGlzIGEgbWFpbnRhaW5lciBhcHByb3ZhbCBhbmQgdGhlIG9mZmVyIGlzIG1hZGUgb25jZSB0aGUKbWFpbnRhaW5lcnMgcXVvcnVtIGlzIHJlYWNoZWQuIEl0IHJlcGxhY2VzIGFueSBwcmV2aW91cyBvZmZlci4gVGhlIG5ldwptYWludGFpbmVycyB0YWtlIG92ZXIgd2l0aCBgYWNjZXB0X3RyYW5zZmVyYC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYG1haW50YWluZXJzYCAtIFRoZSBuZXcgbWFpbnRhaW5lcnMKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIHByb2plY3QgaXMgYXJjaGl2ZWQgb3IgZGVyZWdpc3RlcmVkCiogSWYgdGhlIG5ldyBtYWludGFpbmVycyBhcmUgZW1wdHkgb3IgY29udGFpbiBkdXBsaWNhdGVzCiogSWYgdGhlIG1haW50YWluZXIgYWxyZWFkeSBhcHByb3ZlZAAAAAAQdHJhbnNmZXJfcHJvamVjdAAAAAMAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAAC21haW50YWluZXJzAAAAA+oAAAATAAAAAA==",
+        "AAAAAAAAAgRBY2NlcHQgdGhlIHRyYW5zZmVyIG9mIGEgcHJvamVjdC4KCkV2ZXJ5IG5ldyBtYWludGFpbmVyIG11c3QgYXV0aG9yaXplIHRoZSBjYWxsLiBUaGV5IHJlcGxhY2UgdGhlCm1haW50YWluZXJzLCB0aGUgbWFpbnRhaW5lcnMgcXVvcnVtIGlzIHJlc2V0IHRvIGEgbWFqb3JpdHkgYW5kIHRoZQpjb21taXQgdGhyZXNob2xkIGlzIGxvd2VyZWQgdG8gdGhlIG51bWJlciBvZiBuZXcgbWFpbnRhaW5lcnMgaWYKbmVlZGVkLiBQZW5kaW5nIGNvbW1pdHMgYW5kIG1haW50YWluZXJzIGNoYW5nZXMgYXJlIGRyb3BwZWQuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoKIyBQYW5pY3MKKiBJZiB0aGVyZSBpcyBubyB0cmFuc2ZlciBvZmZlcgoqIElmIGEgbmV3IG1haW50YWluZXIgZGlkIG5vdCBhdXRob3JpemUgdGhlIGNhbGwKKiBJZiB0aGUgcHJvamVjdCBpcyBhcmNoaXZlZCBvciBkZXJlZ2lzdGVyZWQAAAAPYWNjZXB0X3RyYW5zZmVyAAAAAAEAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAA",
+        "AAAAAAAAATZDYW5jZWwgdGhlIHRyYW5zZmVyIG9mZmVyIG9mIGEgcHJvamVjdC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZXJlIGlzIG5vIHRyYW5zZmVyIG9mZmVyAAAAAAAPY2FuY2VsX3RyYW5zZmVyAAAAAAIAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAA",
+        "AAAAAAAAAONHZXQgdGhlIG1haW50YWluZXJzIGEgcHJvamVjdCBpcyBvZmZlcmVkIHRvLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGBWZWM8QWRkcmVzcz5gIC0gVGhlIG5ldyBtYWludGFpbmVycwoKIyBQYW5pY3MKKiBJZiB0aGVyZSBpcyBubyB0cmFuc2ZlciBvZmZlcgAAAAASZ2V0X3RyYW5zZmVyX29mZmVyAAAAAAABAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAsZTZXQgdGhlIGxhdGVzdCBjb21taXQgaGFzaCBmb3IgYSBwcm9qZWN0LgoKVGhlIGhhc2ggaXMgYXBwZW5kZWQgdG8gdGhlIGNvbW1pdCBoaXN0b3J5LiBBIGdpdmVuIGBwYXJlbnRfaGFzaGAgbXVzdAptYXRjaCB0aGUgY3VycmVudCBjb21taXQgaGFzaCwgc2VlIGBmb3JjZV9jb21taXRgIGZvciByZXdyaXRlcy4gV2l0aAphIGNvbW1pdCB0aHJlc2hvbGQgYWJvdmUgMSB0aGlzIGlzIGFuIGFwcHJvdmFsLCBzZWUgYGFwcHJvdmVfY29tbWl0YC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIG1haW50YWluZXIgb3IgZGVsZWdhdGUgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGhhc2hgIC0gVGhlIG5ldyBjb21taXQgaGFzaAoqIFtgT3B0aW9uPHBhcmVudF9oYXNoPmBdIC0gVGhlIGV4cGVjdGVkIGN1cnJlbnQgY29tbWl0IGhhc2gKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIG9yIGRlbGVnYXRlIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIGhhc2ggZG9lcyBub3QgbWF0Y2ggdGhlIHByb2plY3QncyBoYXNoIGFsZ29yaXRobQoqIElmIHRoZSBwYXJlbnQgaGFzaCBkb2VzIG5vdCBtYXRjaCB0aGUgY3VycmVudCBjb21taXQgaGFzaAAAAAAABmNvbW1pdAAAAAAABAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAEaGFzaAAAABAAAAAAAAAAC3BhcmVudF9oYXNoAAAAA+gAAAAQAAAAAA==",
+        "AAAAAAAAAo5TZXQgdGhlIGxhdGVzdCBjb21taXQgaGFzaCBmb3IgYSBwcm9qZWN0LCByZXdyaXRpbmcgaXRzIGhpc3RvcnkuCgpTYW1lIGFzIGBjb21taXRgIHdpdGhvdXQgYW55IHBhcmVudCBoYXNoIGNoZWNrLiBUaGlzIGlzIG1lYW50IGZvcgpkZWxpYmVyYXRlIGhpc3RvcnkgcmV3cml0ZXMgKGUuZy4gZm9yY2UtcHVzaCkgYW5kIGVtaXRzIGEgZGVkaWNhdGVkCmBIaXN0b3J5UmV3cml0dGVuYCBldmVudCBzbyB0aGF0IHJld3JpdGVzIGFyZSB2aXNpYmxlIHRvIGV2ZXJ5b25lLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgaGFzaGAgLSBUaGUgbmV3IGNvbW1pdCBoYXNoCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBwcm9qZWN0IHJlcXVpcmVzIG1vcmUgdGhhbiBvbmUgbWFpbnRhaW5lciBhcHByb3ZhbAoqIElmIHRoZSBoYXNoIGRvZXMgbm90IG1hdGNoIHRoZSBwcm9qZWN0J3MgaGFzaCBhbGdvcml0aG0AAAAAAAxmb3JjZV9jb21taXQAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAARoYXNoAAAAEAAAAAA=",
+        "AAAAAAAAA61BcHByb3ZlIGEgY29tbWl0IGhhc2ggZm9yIGEgYnJhbmNoIG9mIGEgcHJvamVjdC4KClRoZSBoYXNoIG9ubHkgYmVjb21lcyB0aGUgaGVhZCBvZiB0aGUgYnJhbmNoIG9uY2UgZW5vdWdoIGRpc3RpbmN0Cm1haW50YWluZXJzIGFwcHJvdmVkIGl0IChzZWUgYHNldF9jb21taXRfdGhyZXNob2xkYCkgd2l0aGluIHRoZQphcHByb3ZhbCB3aW5kb3cuIEFwcHJvdmFscyB3aGljaCBhcmUgbm90IGNvbXBsZXRlZCBpbiB0aW1lIGV4cGlyZS4KV2l0aCBhIHRocmVzaG9sZCBvZiAxLCB0aGlzIGRpcmVjdGx5IGFuY2hvcnMgdGhlIGhhc2guCgpEZWxlZ2F0ZXMgY2FuIHByb3Bvc2UgYSBjb21taXQgdGhpcyB3YXksIGJ1dCBvbmx5IG1haW50YWluZXJzJwphcHByb3ZhbHMgY291bnQgdG93YXJkcyB0aGUgdGhyZXNob2xkLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBvciBkZWxlZ2F0ZSBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgYnJhbmNoYCAtIFRoZSBicmFuY2ggbmFtZQoqIGBoYXNoYCAtIFRoZSBjb21taXQgaGFzaCB0byBhcHByb3ZlCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBvciBkZWxlZ2F0ZSBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBoYXNoIGRvZXMgbm90IG1hdGNoIHRoZSBwcm9qZWN0J3MgaGFzaCBhbGdvcml0aG0KKiBJZiB0aGUgbWFpbnRhaW5lciBhbHJlYWR5IGFwcHJvdmVkIHRoaXMgY29tbWl0CiogSWYgdGhlcmUgYXJlIHRvbyBtYW55IHBlbmRpbmcgY29tbWl0cwoqIElmIHRoZSBoZWFkIG1vdmVkIGF3YXkgZnJvbSB0aGUgcGFyZW50IGhhc2ggb2YgdGhlIGNvbW1pdAAAAAAAAA5hcHByb3ZlX2NvbW1pdAAAAAAABAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAGYnJhbmNoAAAAAAAQAAAAAAAAAARoYXNoAAAAEAAAAAA=",
+        "AAAAAAAAAOlHZXQgdGhlIGNvbW1pdHMgb2YgYSBwcm9qZWN0IHdhaXRpbmcgZm9yIG1haW50YWluZXIgYXBwcm92YWxzLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGBWZWM8dHlwZXM6OlBlbmRpbmdDb21taXQ+YCAtIFBlbmRpbmcgY29tbWl0cyB3aGljaCBkaWQgbm90IGV4cGlyZQAAAAAAABNnZXRfcGVuZGluZ19jb21taXRzAAAAAAEAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAD6gAAB9AAAAANUGVuZGluZ0NvbW1pdAAAAA==",
+        "AAAAAAAAAbFEZWNsYXJlIHRoZSBoYXNoIGFsZ29yaXRobSBvZiB0aGUgcHJvamVjdCdzIHZlcnNpb24gY29udHJvbCBzeXN0ZW0uCgpDb21taXQgaGFzaGVzIGFyZSB2YWxpZGF0ZWQgYWdhaW5zdCBpdC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGhhc2hfYWxnb3JpdGhtYCAtIFRoZSBoYXNoIGFsZ29yaXRobSwgYEFueWAgYWNjZXB0cyA0MCBvciA2NApjaGFyYWN0ZXJzIGhleCBoYXNoZXMKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkAAAAAAAAEnNldF9oYXNoX2FsZ29yaXRobQAAAAAAAwAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAOaGFzaF9hbGdvcml0aG0AAAAAB9AAAAANSGFzaEFsZ29yaXRobQAAAAAAAAA=",
+        "AAAAAAAAAg1TZXQgaG93IG1hbnkgbWFpbnRhaW5lciBhcHByb3ZhbHMgYXJlIG5lZWRlZCB0byBhbmNob3IgYSBjb21taXQuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGB0aHJlc2hvbGRgIC0gTnVtYmVyIG9mIGRpc3RpbmN0IG1haW50YWluZXIgYXBwcm92YWxzIChLLW9mLU4pCiogYGFwcHJvdmFsX3dpbmRvd2AgLSBUaW1lIGluIHNlY29uZHMgdG8gZ2F0aGVyIHRoZSBhcHByb3ZhbHMKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIHRocmVzaG9sZCBpcyAwIG9yIGxhcmdlciB0aGFuIHRoZSBudW1iZXIgb2YgbWFpbnRhaW5lcnMKKiBJZiB0aGUgYXBwcm92YWwgd2luZG93IGlzIDAAAAAAAAAUc2V0X2NvbW1pdF90aHJlc2hvbGQAAAAEAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAEAAAAAAAAAA9hcHByb3ZhbF93aW5kb3cAAAAABgAAAAA=",
+        "AAAAAAAAAyZBZGQgYSBkZWxlZ2F0ZSBhbGxvd2VkIHRvIGNvbW1pdCBvbiBiZWhhbGYgb2YgdGhlIG1haW50YWluZXJzLgoKRGVsZWdhdGVzIGFyZSBtZWFudCBmb3IgYXV0b21hdGlvbiBzdWNoIGFzIENJLCBzbyB0aGF0IG1haW50YWluZXIga2V5cwpkbyBub3QgbmVlZCB0byBiZSBzaGFyZWQuIFRoZXkgY2FuIG9ubHkgY29tbWl0LCBvcHRpb25hbGx5IGxpbWl0ZWQgdG8Kc29tZSBicmFuY2hlcywgdW50aWwgdGhleSBleHBpcmUuIEFkZGluZyBhbiBleGlzdGluZyBkZWxlZ2F0ZSByZXBsYWNlcwppdHMgc2NvcGUuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBkZWxlZ2F0ZWAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgZGVsZWdhdGUKKiBgYnJhbmNoZXNgIC0gVGhlIGJyYW5jaGVzIHRoZSBkZWxlZ2F0ZSBjYW4gY29tbWl0IHRvLCBlbXB0eSBmb3IgYW55CiogYGV4cGlyZXNfYXRgIC0gTGVkZ2VyIHRpbWVzdGFtcCBhZnRlciB3aGljaCB0aGUgZGVsZWdhdGUgaXMgaW52YWxpZAoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0CiogSWYgdGhlIG1haW50YWluZXIgaXMgbm90IGF1dGhvcml6ZWQKKiBJZiB0aGVyZSBhcmUgdG9vIG1hbnkgYnJhbmNoZXMgb3IgYSBicmFuY2ggbmFtZSBpcyBpbnZhbGlkCiogSWYgdGhlIGV4cGlyeSBpcyBub3QgaW4gdGhlIGZ1dHVyZQAAAAAADGFkZF9kZWxlZ2F0ZQAAAAUAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAACGRlbGVnYXRlAAAAEwAAAAAAAAAIYnJhbmNoZXMAAAPqAAAAEAAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAA=",
+        "AAAAAAAAAUxSZXZva2UgYSBkZWxlZ2F0ZS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGRlbGVnYXRlYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBkZWxlZ2F0ZQoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0CiogSWYgdGhlIG1haW50YWluZXIgaXMgbm90IGF1dGhvcml6ZWQKKiBJZiB0aGUgZGVsZWdhdGUgZG9lc24ndCBleGlzdAAAAA9yZXZva2VfZGVsZWdhdGUAAAAAAwAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAIZGVsZWdhdGUAAAATAAAAAA==",
+        "AAAAAAAAAPRHZXQgdGhlIHNjb3BlIG9mIGEgZGVsZWdhdGUuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBkZWxlZ2F0ZWAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgZGVsZWdhdGUKCiMgUmV0dXJucwoqIGBPcHRpb248dHlwZXM6OkRlbGVnYXRlPmAgLSBUaGUgZGVsZWdhdGUsIE5vbmUgaWYgdW5rbm93biBvciByZXZva2VkAAAADGdldF9kZWxlZ2F0ZQAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAACGRlbGVnYXRlAAAAEwAAAAEAAAPoAAAH0AAAAAhEZWxlZ2F0ZQ==",
         "AAAAAAAAAN1HZXQgdGhlIGxhdGVzdCBjb21taXQgaGFzaCBmb3IgYSBwcm9qZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGBTdHJpbmdgIC0gVGhlIGN1cnJlbnQgY29tbWl0IGhhc2gKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAAAAAAAAApnZXRfY29tbWl0AAAAAAABAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAABA=",
+        "AAAAAAAAAwZTZXQgdGhlIGhlYWQgY29tbWl0IGhhc2ggb2YgYSBicmFuY2ggb2YgYSBwcm9qZWN0LgoKQ29tbWl0dGluZyB0byB0aGUgZGVmYXVsdCBicmFuY2ggKGBtYWluYCkgaXMgdGhlIHNhbWUgYXMgY2FsbGluZwpgY29tbWl0YCB3aXRob3V0IGEgcGFyZW50IGhhc2guIE90aGVyIGJyYW5jaGVzIGFyZSBjcmVhdGVkIG9uIHRoZWlyCmZpcnN0IGNvbW1pdC4gRXZlcnkgY29tbWl0IGlzIGFwcGVuZGVkIHRvIHRoZSBwcm9qZWN0J3MgaGlzdG9yeS4KCklmIHRoZSBwcm9qZWN0IHJlcXVpcmVzIG1vcmUgdGhhbiBvbmUgbWFpbnRhaW5lciBhcHByb3ZhbCwgdGhpcwpjb3VudHMgYXMgYW4gYXBwcm92YWwsIHNlZSBgYXBwcm92ZV9jb21taXRgLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBvciBkZWxlZ2F0ZSBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgYnJhbmNoYCAtIFRoZSBicmFuY2ggbmFtZQoqIGBoYXNoYCAtIFRoZSBuZXcgY29tbWl0IGhhc2gKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIG9yIGRlbGVnYXRlIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIGJyYW5jaCBuYW1lIGlzIGVtcHR5IG9yIHRvbyBsb25nCiogSWYgdGhlIHByb2plY3QgYWxyZWFkeSB0cmFja3MgdG9vIG1hbnkgYnJhbmNoZXMAAAAAAA1jb21taXRfYnJhbmNoAAAAAAAABAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAGYnJhbmNoAAAAAAAQAAAAAAAAAARoYXNoAAAAEAAAAAA=",
+        "AAAAAAAAASxHZXQgdGhlIGhlYWQgY29tbWl0IGhhc2ggb2YgYSBicmFuY2ggb2YgYSBwcm9qZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgYnJhbmNoYCAtIFRoZSBicmFuY2ggbmFtZQoKIyBSZXR1cm5zCiogYFN0cmluZ2AgLSBUaGUgaGVhZCBjb21taXQgaGFzaCBvZiB0aGUgYnJhbmNoCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgYnJhbmNoIGhhcyBubyBjb21taXQAAAAPZ2V0X2JyYW5jaF9oZWFkAAAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAABmJyYW5jaAAAAAAAEAAAAAEAAAAQ",
+        "AAAAAAAAANlMaXN0IHRoZSBicmFuY2hlcyBvZiBhIHByb2plY3QuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoKIyBSZXR1cm5zCiogYFZlYzxTdHJpbmc+YCAtIEJyYW5jaCBuYW1lcywgc3RhcnRpbmcgd2l0aCB0aGUgZGVmYXVsdCBicmFuY2ggaWYKaXQgaGFzIGEgY29tbWl0AAAAAAAADWxpc3RfYnJhbmNoZXMAAAAAAAABAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAA+oAAAAQ",
+        "AAAAAAAAAbBEZWxldGUgYSBicmFuY2ggb2YgYSBwcm9qZWN0LgoKT25seSB0aGUgYnJhbmNoIGhlYWQgaXMgcmVtb3ZlZCwgaXRzIGNvbW1pdHMgc3RheSBpbiB0aGUgaGlzdG9yeS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGJyYW5jaGAgLSBUaGUgYnJhbmNoIG5hbWUKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIGJyYW5jaCBpcyB0aGUgZGVmYXVsdCBicmFuY2gKKiBJZiB0aGUgYnJhbmNoIGRvZXNuJ3QgZXhpc3QAAAANZGVsZXRlX2JyYW5jaAAAAAAAAAMAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAABmJyYW5jaAAAAAAAEAAAAAA=",
+        "AAAAAAAAAXVHZXQgYSBwYWdlIG9mIHRoZSBjb21taXQgaGlzdG9yeSBvZiBhIHByb2plY3QuCgpDb21taXRzIGFyZSBvcmRlcmVkIGZyb20gb2xkZXN0IHRvIG5ld2VzdC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHBhZ2VgIC0gVGhlIHBhZ2UgbnVtYmVyICgwLWJhc2VkKQoKIyBSZXR1cm5zCiogYFZlYzx0eXBlczo6Q29tbWl0UmVjb3JkPmAgLSBMaXN0IG9mIGNvbW1pdHMgb24gdGhlIHJlcXVlc3RlZCBwYWdlLAplbXB0eSBpZiB0aGUgcGFnZSBkb2VzIG5vdCBleGlzdAoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0AAAAAAAAEmdldF9jb21taXRfaGlzdG9yeQAAAAAAAgAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAEcGFnZQAAAAQAAAABAAAD6gAAB9AAAAAMQ29tbWl0UmVjb3Jk",
+        "AAAAAAAAAWpHZXQgYSBzaW5nbGUgZW50cnkgb2YgdGhlIGNvbW1pdCBoaXN0b3J5IG9mIGEgcHJvamVjdC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGluZGV4YCAtIFRoZSBwb3NpdGlvbiBvZiB0aGUgY29tbWl0IGluIHRoZSBoaXN0b3J5ICgwLWJhc2VkKQoKIyBSZXR1cm5zCiogYHR5cGVzOjpDb21taXRSZWNvcmRgIC0gVGhlIGNvbW1pdCBhbmNob3JlZCBhdCB0aGF0IHBvc2l0aW9uCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGVyZSBpcyBubyBjb21taXQgYXQgdGhpcyBpbmRleAAAAAAADWdldF9jb21taXRfYXQAAAAAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAVpbmRleAAAAAAAAAQAAAABAAAH0AAAAAxDb21taXRSZWNvcmQ=",
+        "AAAAAAAAA5xUYWcgYSByZWxlYXNlIG9mIGEgcHJvamVjdC4KClJlbGVhc2VzIGFyZSBuYW1lZCB3aXRoIGEgc2VtdmVyIHZlcnNpb24gYW5kIHBvaW50IHRvIGEgY29tbWl0IGhhc2gKd2hpY2ggbXVzdCBhbHJlYWR5IGJlIGFuY2hvcmVkIHdpdGggYGNvbW1pdGAuIFRhZ3MgYXJlIGltbXV0YWJsZToKb25jZSBhIHZlcnNpb24gaXMgcHVibGlzaGVkIGl0IGNhbm5vdCBiZSBtb3ZlZCB0byBhbm90aGVyIGNvbW1pdC4KQnVpbGQgbWV0YWRhdGEgaXMgaWdub3JlZCB3aGVuIGNvbXBhcmluZyB2ZXJzaW9ucywgc28gYDEuMC4wK2FgIGFuZApgMS4wLjArYmAgYXJlIHRoZSBzYW1lIHJlbGVhc2UuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGB2ZXJzaW9uYCAtIFRoZSBzZW12ZXIgdmVyc2lvbiBvZiB0aGUgcmVsZWFzZQoqIGBjb21taXRfaGFzaGAgLSBUaGUgYW5jaG9yZWQgY29tbWl0IGhhc2ggb2YgdGhlIHJlbGVhc2UKKiBgaXBmc19ub3Rlc2AgLSBDSUQgb2YgdGhlIHJlbGVhc2Ugbm90ZXMKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIHZlcnNpb24gaXMgbm90IGEgdmFsaWQgc2VtdmVyIHZlcnNpb24KKiBJZiB0aGUgdmVyc2lvbiB3YXMgYWxyZWFkeSByZWxlYXNlZAoqIElmIHRoZSBoYXNoIGRvZXMgbm90IG1hdGNoIHRoZSBwcm9qZWN0J3MgaGFzaCBhbGdvcml0aG0KKiBJZiB0aGUgY29tbWl0IGhhc2ggd2FzIG5ldmVyIGFuY2hvcmVkCiogSWYgdGhlIENJRCBpcyBtYWxmb3JtZWQAAAALdGFnX3JlbGVhc2UAAAAABQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAAAAAAtjb21taXRfaGFzaAAAAAAQAAAAAAAAAAppcGZzX25vdGVzAAAAAAAQAAAAAA==",
+        "AAAAAAAAASlHZXQgYSByZWxlYXNlIG9mIGEgcHJvamVjdC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHZlcnNpb25gIC0gVGhlIHNlbXZlciB2ZXJzaW9uIG9mIHRoZSByZWxlYXNlCgojIFJldHVybnMKKiBgdHlwZXM6OlJlbGVhc2VgIC0gVGhlIHJlbGVhc2UKCiMgUGFuaWNzCiogSWYgdGhlIHZlcnNpb24gaXMgbm90IGEgdmFsaWQgc2VtdmVyIHZlcnNpb24KKiBJZiB0aGVyZSBpcyBubyBzdWNoIHJlbGVhc2UAAAAAAAALZ2V0X3JlbGVhc2UAAAAAAgAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAQAAB9AAAAAHUmVsZWFzZQA=",
+        "AAAAAAAAAVFHZXQgYSBwYWdlIG9mIHJlbGVhc2VzIG9mIGEgcHJvamVjdCwgaW4gcHVibGljYXRpb24gb3JkZXIuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBwYWdlYCAtIFRoZSBwYWdlIG51bWJlciAoMC1iYXNlZCkKCiMgUmV0dXJucwoqIGBWZWM8dHlwZXM6OlJlbGVhc2U+YCAtIExpc3Qgb2YgcmVsZWFzZXMgb24gdGhlIHJlcXVlc3RlZCBwYWdlLAplbXB0eSBpZiB0aGUgcGFnZSBkb2VzIG5vdCBleGlzdAoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0AAAAAAAADWxpc3RfcmVsZWFzZXMAAAAAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAARwYWdlAAAABAAAAAEAAAPqAAAH0AAAAAdSZWxlYXNlAA==",
+        "AAAAAAAAAUdHZXQgdGhlIGxhdGVzdCByZWxlYXNlIG9mIGEgcHJvamVjdC4KClRoZSBsYXRlc3QgcmVsZWFzZSBpcyB0aGUgb25lIHdpdGggdGhlIGhpZ2hlc3Qgc2VtdmVyIHByZWNlZGVuY2UsCnByZS1yZWxlYXNlcyBhcmUgbm90IGNvbnNpZGVyZWQuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoKIyBSZXR1cm5zCiogYHR5cGVzOjpSZWxlYXNlYCAtIFRoZSBsYXRlc3QgcmVsZWFzZQoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBoYXMgbm8gc3RhYmxlIHJlbGVhc2UAAAAADmxhdGVzdF9yZWxlYXNlAAAAAAABAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAB9AAAAAHUmVsZWFzZQA=",
+        "AAAAAAAAAnJQdWJsaXNoIHRoZSBNZXJrbGUgcm9vdCBvdmVyIHRoZSBmaWxlcyBvciBhcnRpZmFjdHMgb2YgYSBjb21taXQuCgpBIG1hbmlmZXN0IGlzIGltbXV0YWJsZSBvbmNlIHB1Ymxpc2hlZC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGNvbW1pdF9oYXNoYCAtIFRoZSBhbmNob3JlZCBjb21taXQgaGFzaAoqIGByb290YCAtIFRoZSBNZXJrbGUgcm9vdAoqIGBoYXNoX2Z1bmN0aW9uYCAtIFRoZSBoYXNoIGZ1bmN0aW9uIHVzZWQgdG8gYnVpbGQgdGhlIHRyZWUKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkCiogSWYgdGhlIGhhc2ggZG9lcyBub3QgbWF0Y2ggdGhlIHByb2plY3QncyBoYXNoIGFsZ29yaXRobQoqIElmIHRoZSBjb21taXQgaGFzaCB3YXMgbmV2ZXIgYW5jaG9yZWQKKiBJZiBhIG1hbmlmZXN0IHdhcyBhbHJlYWR5IHB1Ymxpc2hlZCBmb3IgdGhlIGNvbW1pdAAAAAAAEHB1Ymxpc2hfbWFuaWZlc3QAAAAFAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAtjb21taXRfaGFzaAAAAAAQAAAAAAAAAARyb290AAAD7gAAACAAAAAAAAAADWhhc2hfZnVuY3Rpb24AAAAAAAfQAAAACk1lcmtsZUhhc2gAAAAAAAA=",
+        "AAAAAAAAAUlHZXQgdGhlIG1hbmlmZXN0IHB1Ymxpc2hlZCBmb3IgYSBjb21taXQuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBjb21taXRfaGFzaGAgLSBUaGUgY29tbWl0IGhhc2gKCiMgUmV0dXJucwoqIGB0eXBlczo6TWFuaWZlc3RgIC0gVGhlIE1lcmtsZSByb290IGFuZCBob3cgaXQgd2FzIGJ1aWx0CgojIFBhbmljcwoqIElmIHRoZSBoYXNoIGlzIG5vdCBhIHZhbGlkIGhleCBoYXNoCiogSWYgbm8gbWFuaWZlc3Qgd2FzIHB1Ymxpc2hlZCBmb3IgdGhlIGNvbW1pdAAAAAAAAAxnZXRfbWFuaWZlc3QAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAtjb21taXRfaGFzaAAAAAAQAAAAAQAAB9AAAAAITWFuaWZlc3Q=",
+        "AAAAAAAAAqJQcm92ZSB0aGF0IGEgZmlsZSBvciBhbiBhcnRpZmFjdCBiZWxvbmdzIHRvIGFuIGFuY2hvcmVkIGNvbW1pdC4KClRoZSBsZWFmIGlzIHRoZSBoYXNoIG9mIHRoZSBmaWxlIGFuZCB0aGUgcHJvb2YgbGlzdHMgdGhlIHNpYmxpbmcKbm9kZXMgZnJvbSB0aGUgbGVhZiB1cCB0byB0aGUgcm9vdC4gUGFpcnMgb2Ygbm9kZXMgYXJlIHNvcnRlZCBiZWZvcmUKYmVpbmcgaGFzaGVkIHRvZ2V0aGVyLCBzbyB0aGUgcHJvb2YgZG9lcyBub3QgbmVlZCB0byB0ZWxsIG9uIHdoaWNoCnNpZGUgZWFjaCBzaWJsaW5nIGlzLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgY29tbWl0X2hhc2hgIC0gVGhlIGNvbW1pdCBoYXNoCiogYGxlYWZgIC0gVGhlIGhhc2ggb2YgdGhlIGZpbGUgb3IgYXJ0aWZhY3QKKiBgcHJvb2ZgIC0gVGhlIHNpYmxpbmcgbm9kZXMgZnJvbSB0aGUgbGVhZiB0byB0aGUgcm9vdAoKIyBSZXR1cm5zCiogYGJvb2xgIC0gVHJ1ZSBpZiB0aGUgcHJvb2YgbGVhZHMgdG8gdGhlIHB1Ymxpc2hlZCByb290CgojIFBhbmljcwoqIElmIHRoZSBoYXNoIGlzIG5vdCBhIHZhbGlkIGhleCBoYXNoCiogSWYgbm8gbWFuaWZlc3Qgd2FzIHB1Ymxpc2hlZCBmb3IgdGhlIGNvbW1pdAAAAAAAEHZlcmlmeV9pbmNsdXNpb24AAAAEAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAtjb21taXRfaGFzaAAAAAAQAAAAAAAAAARsZWFmAAAD7gAAACAAAAAAAAAABXByb29mAAAAAAAD6gAAA+4AAAAgAAAAAQAAAAE=",
         "AAAAAAAAASBHZXQgcHJvamVjdCBpbmZvcm1hdGlvbiBpbmNsdWRpbmcgY29uZmlndXJhdGlvbiBhbmQgbWFpbnRhaW5lcnMuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoKIyBSZXR1cm5zCiogYHR5cGVzOjpQcm9qZWN0YCAtIFByb2plY3QgaW5mb3JtYXRpb24gaW5jbHVkaW5nIG5hbWUsIGNvbmZpZywgYW5kIG1haW50YWluZXJzCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QAAAALZ2V0X3Byb2plY3QAAAAAAQAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAfQAAAAB1Byb2plY3QA",
-        "AAAAAAAAALZHZXQgYSBwYWdlIG9mIHByb2plY3RzLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcGFnZWAgLSBUaGUgcGFnZSBudW1iZXIgKDAtYmFzZWQpCgojIFJldHVybnMKKiBgVmVjPHR5cGVzOjpQcm9qZWN0PmAgLSBMaXN0IG9mIHByb2plY3RzIG9uIHRoZSByZXF1ZXN0ZWQgcGFnZQAAAAAADGdldF9wcm9qZWN0cwAAAAEAAAAAAAAABHBhZ2UAAAAEAAAAAQAAA+oAAAfQAAAAB1Byb2plY3QA",
+        "AAAAAAAAATdHZXQgYSBzdW1tYXJ5IG9mIGEgcHJvamVjdC4KCkxpZ2h0ZXIgdGhhbiBgZ2V0X3Byb2plY3RgIGFzIHRoZSBtYWludGFpbmVycyBhcmUgb25seSBjb3VudGVkLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGB0eXBlczo6UHJvamVjdFN1bW1hcnlgIC0gTmFtZSwgbGlua3MsIHN0YXR1cyBhbmQgbWV0YWRhdGEgb2YgdGhlIHByb2plY3QKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAAAAAATZ2V0X3Byb2plY3Rfc3VtbWFyeQAAAAABAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAB9AAAAAOUHJvamVjdFN1bW1hcnkAAA==",
+        "AAAAAAAAAp9FeHRlbmQgdGhlIFRUTCBvZiB0aGUgZW50cmllcyBvZiBhIHByb2plY3QsIGEgYmF0Y2ggYXQgYSB0aW1lLgoKQW55b25lIGNhbiBjYWxsIHRoaXMgdG8ga2VlcCBhIHByb2plY3QgZnJvbSBiZWluZyBhcmNoaXZlZCwgZXZlbiB3aGlsZQp0aGUgY29udHJhY3QgaXMgcGF1c2VkLiBTdGFydCB3aXRoIGEgY3Vyc29yIG9mIDAgYW5kIGNhbGwgaXQgYWdhaW4Kd2l0aCB0aGUgcmV0dXJuZWQgY3Vyc29yIHVudGlsIGl0IHJldHVybnMgTm9uZS4gRW50cmllcyBrZXllZCBieSBhCmNvbW1pdCBoYXNoLCBkZWxlZ2F0ZXMgYW5kIGZvcm1lciBhbGlhc2VzIGFyZSBvbmx5IGV4dGVuZGVkIHdoZW4gdGhleQphcmUgdXNlZC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYGN1cnNvcmAgLSBUaGUgcG9zaXRpb24gb2YgdGhlIGZpcnN0IGVudHJ5IHRvIGV4dGVuZAoqIGBsaW1pdGAgLSBUaGUgbWF4aW11bSBudW1iZXIgb2YgZW50cmllcyB0byBleHRlbmQgKDEgdG8gMzApCgojIFJldHVybnMKKiBgT3B0aW9uPHUzMj5gIC0gVGhlIGN1cnNvciBvZiB0aGUgbmV4dCBiYXRjaCwgTm9uZSBvbmNlIGRvbmUKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAAAAAASZXh0ZW5kX3Byb2plY3RfdHRsAAAAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAZjdXJzb3IAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPoAAAABA==",
+        "AAAAAAAAAVBHZXQgdGhlIFRUTCBwb2xpY3kgYXBwbGllZCB0byBhIHByb2plY3QuCgpDb250cmFjdHMgY2Fubm90IHJlYWQgdGhlIFRUTCBvZiBhbiBlbnRyeSwgdGhlIHJlcG9ydCBpcyBiYXNlZCBvbiB0aGUKbGFzdCBjb21wbGV0ZWQgcnVuIG9mIGBleHRlbmRfcHJvamVjdF90dGxgLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGB0eXBlczo6VHRsUmVwb3J0YCAtIFRoZSBUVEwgcmVwb3J0CgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QAAAAOZ2V0X3R0bF9yZXBvcnQAAAAAAAEAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAH0AAAAAlUdGxSZXBvcnQAAAA=",
+        "AAAAAAAAAUJHZXQgYSBwYWdlIG9mIHByb2plY3RzLgoKUGFnZXMgbGlzdCAxMCBwcm9qZWN0cywgZGVyZWdpc3RlcmVkIHByb2plY3RzIGFyZSBsZWZ0IG91dC4gU2VlCmBsaXN0X3Byb2plY3RzYCBmb3IgbGlnaHRlciByZXN1bHRzLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcGFnZWAgLSBUaGUgcGFnZSBudW1iZXIgKDAtYmFzZWQpCgojIFJldHVybnMKKiBgVmVjPHR5cGVzOjpQcm9qZWN0PmAgLSBMaXN0IG9mIHByb2plY3RzIG9uIHRoZSByZXF1ZXN0ZWQgcGFnZQoKIyBQYW5pY3MKKiBJZiB0aGUgcGFnZSBkb2Vzbid0IGV4aXN0AAAAAAAMZ2V0X3Byb2plY3RzAAAAAQAAAAAAAAAEcGFnZQAAAAQAAAABAAAD6gAAB9AAAAAHUHJvamVjdAA=",
+        "AAAAAAAAAc1MaXN0IHByb2plY3RzIGZyb20gYSBjdXJzb3IuCgpEZXJlZ2lzdGVyZWQgcHJvamVjdHMgYXJlIGxlZnQgb3V0LgpBdCBtb3N0IDUwIHByb2plY3RzIGFyZSBsb29rZWQgYXQgcGVyIGNhbGwsIHNvIHRoYXQgYSBmaWx0ZXJlZCByZXN1bHQKY2FuIGJlIGVtcHR5IHdoaWxlIGBuZXh0X2N1cnNvcmAgaXMgc2V0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgY3Vyc29yYCAtIFRoZSBwb3NpdGlvbiB0byBzdGFydCBmcm9tLCAwIG9yIGEgcHJldmlvdXMgYG5leHRfY3Vyc29yYAoqIGBsaW1pdGAgLSBUaGUgbWF4aW11bSBudW1iZXIgb2YgcHJvamVjdHMgKDEgdG8gMjApCiogYGZpbHRlcmAgLSBUaGUgcHJvamVjdHMgdG8ga2VlcAoKIyBSZXR1cm5zCiogYHR5cGVzOjpQcm9qZWN0TGlzdGAgLSBUaGUgcHJvamVjdHMgYW5kIHRoZSBjdXJzb3Igb2YgdGhlIG5leHQgY2FsbAAAAAAAAA1saXN0X3Byb2plY3RzAAAAAAAAAwAAAAAAAAAGY3Vyc29yAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAAAAAAABmZpbHRlcgAAAAAH0AAAAA1Qcm9qZWN0RmlsdGVyAAAAAAAAAQAAB9AAAAALUHJvamVjdExpc3QA",
+        "AAAAAAAAATlHZXQgYSBwYWdlIG9mIHRoZSBwcm9qZWN0cyBtYWludGFpbmVkIGJ5IGFuIGFkZHJlc3MuCgpBcmNoaXZlZCBwcm9qZWN0cyBhcmUgaW5jbHVkZWQsIGRlcmVnaXN0ZXJlZCBvbmVzIGFyZSBub3QuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBtYWludGFpbmVyIGFkZHJlc3MKKiBgcGFnZWAgLSBUaGUgcGFnZSBudW1iZXIgKDAtYmFzZWQpCgojIFJldHVybnMKKiBgVmVjPEJ5dGVzPmAgLSBQcm9qZWN0IGtleXMgb24gdGhlIHJlcXVlc3RlZCBwYWdlLCBlbXB0eSBpZiBub25lAAAAAAAAGmdldF9wcm9qZWN0c19ieV9tYWludGFpbmVyAAAAAAACAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAARwYWdlAAAABAAAAAEAAAPqAAAADg==",
         "AAAAAAAAAOdHZXQgc3ViLXByb2plY3RzIGZvciBhIHByb2plY3QgKGlmIGl0J3MgYW4gb3JnYW5pemF0aW9uKS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCgojIFJldHVybnMKKiBgVmVjPEJ5dGVzPmAgLSBMaXN0IG9mIHN1Yi1wcm9qZWN0IGtleXMsIGVtcHR5IGlmIG5vdCBhbiBvcmdhbml6YXRpb24AAAAAEGdldF9zdWJfcHJvamVjdHMAAAABAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAA+oAAAAO",
-        "AAAAAAAAAn5TZXQgc3ViLXByb2plY3RzIGZvciBhIHByb2plY3QgKG1ha2luZyBpdCBhbiBvcmdhbml6YXRpb24pLgoKTm90ZTogYnkgZGVzaWduLCBzdWItcHJvamVjdCBrZXlzIGFyZSBub3QgdmFsaWRhdGVkIGFnYWluc3QgZXhpc3RpbmcKcHJvamVjdHMuIFRoaXMgYWxsb3dzIHJlc2VydmluZyBhIHByb2plY3Qgc3BhY2UgYmVmb3JlIHRoZSBwcm9qZWN0IGlzCnJlZ2lzdGVyZWQgKHNpbmNlIHRoZSBrZXkgaXMgZGVyaXZlZCBmcm9tIHRoZSBuYW1lKS4gQSBwcm9qZWN0IGNhbgphbHNvIGFwcGVhciBpbiBtdWx0aXBsZSBvcmdhbml6YXRpb25zLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgbWFpbnRhaW5lciBhZGRyZXNzIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBzdWJfcHJvamVjdHNgIC0gTGlzdCBvZiBzdWItcHJvamVjdCBrZXlzIHRvIGFzc29jaWF0ZQoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0CiogSWYgdGhlIG1haW50YWluZXIgaXMgbm90IGF1dGhvcml6ZWQKKiBJZiBtb3JlIHRoYW4gMTAgc3ViLXByb2plY3RzIGFyZSBwcm92aWRlZAAAAAAAEHNldF9zdWJfcHJvamVjdHMAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAxzdWJfcHJvamVjdHMAAAPqAAAADgAAAAA=",
-        "AAAABAAAAAAAAAAAAAAADkNvbnRyYWN0RXJyb3JzAAAAAAAdAAAAAAAAAA9VbmV4cGVjdGVkRXJyb3IAAAAAAAAAAAAAAAASVW5hdXRob3JpemVkU2lnbmVyAAAAAABkAAAAAAAAAApXcm9uZ1ZvdGVyAAAAAABlAAAAAAAAABhNYWludGFpbmVyTm90RG9tYWluT3duZXIAAABmAAAAAAAAAApJbnZhbGlkS2V5AAAAAADIAAAAAAAAABNQcm9qZWN0QWxyZWFkeUV4aXN0AAAAAMkAAAAAAAAAElRvb01hbnlTdWJQcm9qZWN0cwAAAAAAygAAAAAAAAAXUHJvcG9zYWxJbnB1dFZhbGlkYXRpb24AAAAAywAAAAAAAAANVW5rbm93bk1lbWJlcgAAAAAAAMwAAAAAAAAAEk1lbWJlckFscmVhZHlFeGlzdAAAAAAAzQAAAAAAAAASSW52YWxpZERvbWFpbkVycm9yAAAAAADOAAAAAAAAAA1Xcm9uZ1ZvdGVUeXBlAAAAAAAAzwAAAAAAAAANQmFkQ29tbWl0bWVudAAAAAAAANAAAAAAAAAAC1ZvdGVyV2VpZ2h0AAAAANEAAAAAAAAAEVZvdGVMaW1pdEV4Y2VlZGVkAAAAAAAA0gAAAAAAAAALTm9IYXNoRm91bmQAAAABLAAAAAAAAAAVTm9Qcm9wb3NhbG9yUGFnZUZvdW5kAAAAAAABLQAAAAAAAAASTm9Qcm9qZWN0UGFnZUZvdW5kAAAAAAEuAAAAAAAAABdOb0Fub255bW91c1ZvdGluZ0NvbmZpZwAAAAEvAAAAAAAAAAxBbHJlYWR5Vm90ZWQAAAGQAAAAAAAAABJQcm9wb3NhbFZvdGluZ1RpbWUAAAAAAZEAAAAAAAAADlByb3Bvc2FsQWN0aXZlAAAAAAGSAAAAAAAAAAxPdXRjb21lRXJyb3IAAAGTAAAAAAAAAA5UYWxseVNlZWRFcnJvcgAAAAAB9AAAAAAAAAAMSW52YWxpZFByb29mAAAB9QAAAAAAAAAOQ29udHJhY3RQYXVzZWQAAAAAAlgAAAAAAAAADFVwZ3JhZGVFcnJvcgAAAlkAAAAAAAAAEkNvbnRyYWN0VmFsaWRhdGlvbgAAAAACWgAAAAAAAAAPQ29sbGF0ZXJhbEVycm9yAAAAAls=",
+        "AAAAAAAAAxNTZXQgc3ViLXByb2plY3RzIGZvciBhIHByb2plY3QgKG1ha2luZyBpdCBhbiBvcmdhbml6YXRpb24pLgoKU3ViLXByb2plY3RzIG11c3QgYmUgcmVnaXN0ZXJlZCBhbmQgYmVsb25nIHRvIG5vIG90aGVyIG9yZ2FuaXphdGlvbi4KVGhlaXIgbWFpbnRhaW5lcnMgbXVzdCBmaXJzdCBhZ3JlZSB0byBqb2luIHdpdGggYGFjY2VwdF9wYXJlbnRgLgpTdWItcHJvamVjdHMgbWlzc2luZyBmcm9tIHRoZSBuZXcgbGlzdCBhcmUgZGV0YWNoZWQuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBtYWludGFpbmVyIGFkZHJlc3MgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHN1Yl9wcm9qZWN0c2AgLSBMaXN0IG9mIHN1Yi1wcm9qZWN0IGtleXMgdG8gYXNzb2NpYXRlCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIG1vcmUgdGhhbiAxMCBzdWItcHJvamVjdHMgYXJlIHByb3ZpZGVkCiogSWYgYSBzdWItcHJvamVjdCBkb2Vzbid0IGV4aXN0CiogSWYgYSBzdWItcHJvamVjdCBpcyBsaXN0ZWQgdHdpY2Ugb3IgYmVsb25ncyB0byBhbm90aGVyIG9yZ2FuaXphdGlvbgoqIElmIGEgc3ViLXByb2plY3QgaXMgdGhlIHByb2plY3QgaXRzZWxmIG9yIG9uZSBvZiBpdHMgcGFyZW50cwoqIElmIGEgc3ViLXByb2plY3QgZGlkIG5vdCBhZ3JlZSB0byBqb2luAAAAABBzZXRfc3ViX3Byb2plY3RzAAAAAwAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAMc3ViX3Byb2plY3RzAAAD6gAAAA4AAAAA",
+        "AAAAAAAAAc9BZ3JlZSB0byBqb2luIGFuIG9yZ2FuaXphdGlvbiBhcyBvbmUgb2YgaXRzIHN1Yi1wcm9qZWN0cy4KClRoZSBvcmdhbml6YXRpb24gdGhlbiBhZGRzIHRoZSBwcm9qZWN0IHdpdGggYHNldF9zdWJfcHJvamVjdHNgLiBBIG5ldwpjYWxsIHJlcGxhY2VzIHRoZSBwcmV2aW91cyBhZ3JlZW1lbnQuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBtYWludGFpbmVyIGFkZHJlc3MgY2FsbGluZyB0aGlzIGZ1bmN0aW9uCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHBhcmVudF9rZXlgIC0gVGhlIGtleSBvZiB0aGUgb3JnYW5pemF0aW9uCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IG9yIHRoZSBvcmdhbml6YXRpb24gZG9lc24ndCBleGlzdAoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkAAAAAA1hY2NlcHRfcGFyZW50AAAAAAAAAwAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAKcGFyZW50X2tleQAAAAAADgAAAAA=",
+        "AAAAAAAAAMhHZXQgdGhlIG9yZ2FuaXphdGlvbiBhIHByb2plY3QgYmVsb25ncyB0by4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCgojIFJldHVybnMKKiBgT3B0aW9uPEJ5dGVzPmAgLSBUaGUga2V5IG9mIHRoZSBvcmdhbml6YXRpb24sIGlmIGFueQAAAApnZXRfcGFyZW50AAAAAAABAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAA+gAAAAO",
+        "AAAAAAAAAcZHZXQgYSBwcm9qZWN0IGFuZCBpdHMgc3ViLXByb2plY3RzLCByZWN1cnNpdmVseS4KClRoZSBub2RlcyBhcmUgbGlzdGVkIGRlcHRoIGZpcnN0LCBlYWNoIHN1Yi1wcm9qZWN0IGFmdGVyIGl0cyBwYXJlbnQuClRoZSBkZXB0aCBpcyBjYXBwZWQgYXQgNSBsZXZlbHMgYmVsb3cgdGhlIHByb2plY3QuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBkZXB0aGAgLSBMZXZlbHMgb2Ygc3ViLXByb2plY3RzIHRvIGluY2x1ZGUsIDAgZm9yIHRoZSBwcm9qZWN0IGFsb25lCgojIFJldHVybnMKKiBgVmVjPHR5cGVzOjpQcm9qZWN0Tm9kZT5gIC0gVGhlIHByb2plY3QgZm9sbG93ZWQgYnkgaXRzIHN1Yi1wcm9qZWN0cwoKIyBQYW5pY3MKKiBJZiB0aGUgcHJvamVjdCBkb2Vzbid0IGV4aXN0AAAAAAAQZ2V0X3Byb2plY3RfdHJlZQAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAABWRlcHRoAAAAAAAABAAAAAEAAAPqAAAH0AAAAAtQcm9qZWN0Tm9kZQA=",
+        "AAAABAAAAAAAAAAAAAAADkNvbnRyYWN0RXJyb3JzAAAAAABCAAAAAAAAAA9VbmV4cGVjdGVkRXJyb3IAAAAAAAAAAAAAAAASVW5hdXRob3JpemVkU2lnbmVyAAAAAABkAAAAAAAAAApXcm9uZ1ZvdGVyAAAAAABlAAAAAAAAABhNYWludGFpbmVyTm90RG9tYWluT3duZXIAAABmAAAAAAAAABZDb21taXRBcHByb3ZhbFJlcXVpcmVkAAAAAABnAAAAAAAAABBRdW9ydW1Ob3RSZWFjaGVkAAAAaAAAAAAAAAAKSW52YWxpZEtleQAAAAAAyAAAAAAAAAATUHJvamVjdEFscmVhZHlFeGlzdAAAAADJAAAAAAAAABJUb29NYW55U3ViUHJvamVjdHMAAAAAAMoAAAAAAAAAF1Byb3Bvc2FsSW5wdXRWYWxpZGF0aW9uAAAAAMsAAAAAAAAADVVua25vd25NZW1iZXIAAAAAAADMAAAAAAAAABJNZW1iZXJBbHJlYWR5RXhpc3QAAAAAAM0AAAAAAAAAEkludmFsaWREb21haW5FcnJvcgAAAAAAzgAAAAAAAAANV3JvbmdWb3RlVHlwZQAAAAAAAM8AAAAAAAAADUJhZENvbW1pdG1lbnQAAAAAAADQAAAAAAAAAAtWb3RlcldlaWdodAAAAADRAAAAAAAAABFWb3RlTGltaXRFeGNlZWRlZAAAAAAAANIAAAAAAAAAElBhcmVudEhhc2hNaXNtYXRjaAAAAAAA0wAAAAAAAAAOSW52YWxpZFZlcnNpb24AAAAAANQAAAAAAAAAE1JlbGVhc2VBbHJlYWR5RXhpc3QAAAAA1QAAAAAAAAARSGFzaEFscmVhZHlZYW5rZWQAAAAAAADWAAAAAAAAAA9Ub29NYW55QnJhbmNoZXMAAAAA1wAAAAAAAAANSW52YWxpZEJyYW5jaAAAAAAAANgAAAAAAAAAEEludmFsaWRUaHJlc2hvbGQAAADZAAAAAAAAABVUb29NYW55UGVuZGluZ0NvbW1pdHMAAAAAAADaAAAAAAAAABFJbnZhbGlkSGFzaExlbmd0aAAAAAAAANsAAAAAAAAAE0ludmFsaWRIYXNoRW5jb2RpbmcAAAAA3AAAAAAAAAAUTWFuaWZlc3RBbHJlYWR5RXhpc3QAAADdAAAAAAAAAA9BbHJlYWR5QXR0ZXN0ZWQAAAAA3gAAAAAAAAATVG9vTWFueUF0dGVzdGF0aW9ucwAAAADfAAAAAAAAABhJbnZhbGlkTWFpbnRhaW5lcnNDaGFuZ2UAAADgAAAAAAAAABFJbnZhbGlkU3ViUHJvamVjdAAAAAAAAOEAAAAAAAAAD1N1YlByb2plY3RDeWNsZQAAAADiAAAAAAAAABNUb29NYW55RGVwZW5kZW5jaWVzAAAAAOMAAAAAAAAAD0ludmFsaWRNZXRhZGF0YQAAAADkAAAAAAAAAApJbnZhbGlkQ2lkAAAAAADlAAAAAAAAABNJbnZhbGlkRG9tYWluTGVuZ3RoAAAAAOYAAAAAAAAAFkludmFsaWREb21haW5DaGFyYWN0ZXIAAAAAAOcAAAAAAAAADlJlc2VydmVkRG9tYWluAAAAAADoAAAAAAAAAAtOb0hhc2hGb3VuZAAAAAEsAAAAAAAAABVOb1Byb3Bvc2Fsb3JQYWdlRm91bmQAAAAAAAEtAAAAAAAAABJOb1Byb2plY3RQYWdlRm91bmQAAAAAAS4AAAAAAAAAF05vQW5vbnltb3VzVm90aW5nQ29uZmlnAAAAAS8AAAAAAAAADk5vUmVsZWFzZUZvdW5kAAAAAAEwAAAAAAAAAA9Ob0Fkdmlzb3J5Rm91bmQAAAABMQAAAAAAAAAPTm9NYW5pZmVzdEZvdW5kAAAAATIAAAAAAAAAD1Vua25vd25EZWxlZ2F0ZQAAAAEzAAAAAAAAABhOb01haW50YWluZXJzQ2hhbmdlRm91bmQAAAE0AAAAAAAAABhNYWludGFpbmVyc0NoYW5nZVBlbmRpbmcAAAE1AAAAAAAAAA9Qcm9qZWN0UmVhZE9ubHkAAAABNgAAAAAAAAAPTm9UcmFuc2ZlckZvdW5kAAAAATcAAAAAAAAAD05vUGFyZW50Q29uc2VudAAAAAE4AAAAAAAAABFOb0RlcGVuZGVuY3lGb3VuZAAAAAAAATkAAAAAAAAAD05vQXBwcm92YWxGb3VuZAAAAAE6AAAAAAAAAAxBbHJlYWR5Vm90ZWQAAAGQAAAAAAAAABJQcm9wb3NhbFZvdGluZ1RpbWUAAAAAAZEAAAAAAAAADlByb3Bvc2FsQWN0aXZlAAAAAAGSAAAAAAAAAAxPdXRjb21lRXJyb3IAAAGTAAAAAAAAAA1JbnZhbGlkRXhwaXJ5AAAAAAABlAAAAAAAAAAOVGFsbHlTZWVkRXJyb3IAAAAAAfQAAAAAAAAADEludmFsaWRQcm9vZgAAAfUAAAAAAAAADkNvbnRyYWN0UGF1c2VkAAAAAAJYAAAAAAAAAAxVcGdyYWRlRXJyb3IAAAJZAAAAAAAAABJDb250cmFjdFZhbGlkYXRpb24AAAAAAloAAAAAAAAAD0NvbGxhdGVyYWxFcnJvcgAAAAJbAAAAAAAAABBNaWdyYXRpb25QZW5kaW5nAAACXA==",
         "AAAABQAAAAAAAAAAAAAAEVByb2plY3RSZWdpc3RlcmVkAAAAAAAAAQAAABJwcm9qZWN0X3JlZ2lzdGVyZWQAAAAAAAMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAD01ldGFkYXRhVXBkYXRlZAAAAAABAAAAEG1ldGFkYXRhX3VwZGF0ZWQAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADVByb2plY3RGb3JrZWQAAAAAAAABAAAADnByb2plY3RfZm9ya2VkAAAAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAMdXBzdHJlYW1fa2V5AAAADgAAAAAAAAAAAAAAC2NvbW1pdF9oYXNoAAAAA+gAAAAQAAAAAAAAAAI=",
         "AAAABQAAAAAAAAAAAAAAFFByb2plY3RDb25maWdVcGRhdGVkAAAAAQAAABZwcm9qZWN0X2NvbmZpZ191cGRhdGVkAAAAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
         "AAAABQAAAAAAAAAAAAAABkNvbW1pdAAAAAAAAQAAAAZjb21taXQAAAAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAARoYXNoAAAAEAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADkNvbW1pdEFwcHJvdmVkAAAAAAABAAAAD2NvbW1pdF9hcHByb3ZlZAAAAAAGAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAGYnJhbmNoAAAAAAAQAAAAAAAAAAAAAAAEaGFzaAAAABAAAAAAAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAAAAAAPYXBwcm92YWxzX2NvdW50AAAAAAQAAAAAAAAAAAAAABF0aHJlc2hvbGRfcmVhY2hlZAAAAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADEJyYW5jaENvbW1pdAAAAAEAAAANYnJhbmNoX2NvbW1pdAAAAAAAAAMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAAZicmFuY2gAAAAAABAAAAABAAAAAAAAAARoYXNoAAAAEAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADUJyYW5jaERlbGV0ZWQAAAAAAAABAAAADmJyYW5jaF9kZWxldGVkAAAAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAGYnJhbmNoAAAAAAAQAAAAAQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEEhpc3RvcnlSZXdyaXR0ZW4AAAABAAAAEWhpc3RvcnlfcmV3cml0dGVuAAAAAAAABAAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAAAAAAADXByZXZpb3VzX2hhc2gAAAAAAAPoAAAAEAAAAAAAAAAAAAAABGhhc2gAAAAQAAAAAAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAFVByb2plY3RBY3Rpb25BcHByb3ZlZAAAAAAAAAEAAAAXcHJvamVjdF9hY3Rpb25fYXBwcm92ZWQAAAAABQAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAAAAAAABmFjdGlvbgAAAAAH0AAAAA1Qcm9qZWN0QWN0aW9uAAAAAAAAAAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAAAAAAD2FwcHJvdmFsc19jb3VudAAAAAAEAAAAAAAAAAAAAAAOcXVvcnVtX3JlYWNoZWQAAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAD1Byb2plY3RBcmNoaXZlZAAAAAABAAAAEHByb2plY3RfYXJjaGl2ZWQAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAE1Byb2plY3REZXJlZ2lzdGVyZWQAAAAAAQAAABRwcm9qZWN0X2RlcmVnaXN0ZXJlZAAAAAMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAAAAAAA9kb21haW5fcmVsZWFzZWQAAAAAAQAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADlByb2plY3RSZW5hbWVkAAAAAAABAAAAD3Byb2plY3RfcmVuYW1lZAAAAAAFAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAANcHJldmlvdXNfbmFtZQAAAAAAABAAAAAAAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAAAAAABWFsaWFzAAAAAAAADgAAAAAAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAFlByb2plY3RUcmFuc2Zlck9mZmVyZWQAAAAAAAEAAAAYcHJvamVjdF90cmFuc2Zlcl9vZmZlcmVkAAAAAgAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAAAAAAAC21haW50YWluZXJzAAAAA+oAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAGFByb2plY3RUcmFuc2ZlckNhbmNlbGxlZAAAAAEAAAAacHJvamVjdF90cmFuc2Zlcl9jYW5jZWxsZWQAAAAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAElByb2plY3RUcmFuc2ZlcnJlZAAAAAAAAQAAABNwcm9qZWN0X3RyYW5zZmVycmVkAAAAAAMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAABRwcmV2aW91c19tYWludGFpbmVycwAAA+oAAAATAAAAAAAAAAAAAAALbWFpbnRhaW5lcnMAAAAD6gAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAGU1haW50YWluZXJzQ2hhbmdlUHJvcG9zZWQAAAAAAAABAAAAG21haW50YWluZXJzX2NoYW5nZV9wcm9wb3NlZAAAAAAFAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAADYWRkAAAAA+oAAAATAAAAAAAAAAAAAAAGcmVtb3ZlAAAAAAPqAAAAEwAAAAAAAAAAAAAADWV4ZWN1dGFibGVfYXQAAAAAAAAGAAAAAAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAGU1haW50YWluZXJzQ2hhbmdlQXBwcm92ZWQAAAAAAAABAAAAG21haW50YWluZXJzX2NoYW5nZV9hcHByb3ZlZAAAAAAEAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAAAAAAD2FwcHJvdmFsc19jb3VudAAAAAAEAAAAAAAAAAAAAAAOcXVvcnVtX3JlYWNoZWQAAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAGk1haW50YWluZXJzQ2hhbmdlQ2FuY2VsbGVkAAAAAAABAAAAHG1haW50YWluZXJzX2NoYW5nZV9jYW5jZWxsZWQAAAACAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAD01haW50YWluZXJBZGRlZAAAAAABAAAAEG1haW50YWluZXJfYWRkZWQAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAAAAAACGV4ZWN1dG9yAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEU1haW50YWluZXJSZW1vdmVkAAAAAAAAAQAAABJtYWludGFpbmVyX3JlbW92ZWQAAAAAAAMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAAAAAAIZXhlY3V0b3IAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADURlbGVnYXRlQWRkZWQAAAAAAAABAAAADmRlbGVnYXRlX2FkZGVkAAAAAAAFAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAIZGVsZWdhdGUAAAATAAAAAAAAAAAAAAAIYnJhbmNoZXMAAAPqAAAAEAAAAAAAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAD0RlbGVnYXRlUmV2b2tlZAAAAAABAAAAEGRlbGVnYXRlX3Jldm9rZWQAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAIZGVsZWdhdGUAAAATAAAAAAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADVJlbGVhc2VUYWdnZWQAAAAAAAABAAAADnJlbGVhc2VfdGFnZ2VkAAAAAAAEAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAHdmVyc2lvbgAAAAAQAAAAAAAAAAAAAAALY29tbWl0X2hhc2gAAAAAEAAAAAAAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEU1hbmlmZXN0UHVibGlzaGVkAAAAAAAAAQAAABJtYW5pZmVzdF9wdWJsaXNoZWQAAAAAAAQAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAAtjb21taXRfaGFzaAAAAAAQAAAAAAAAAAAAAAAEcm9vdAAAA+4AAAAgAAAAAAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAACkhhc2hZYW5rZWQAAAAAAAEAAAALaGFzaF95YW5rZWQAAAAABAAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAAAAAAABGhhc2gAAAAQAAAAAAAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEUFkdmlzb3J5UHVibGlzaGVkAAAAAAAAAQAAABJhZHZpc29yeV9wdWJsaXNoZWQAAAAAAAQAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAAthZHZpc29yeV9pZAAAAAAEAAAAAAAAAAAAAAAIYWZmZWN0ZWQAAAAQAAAAAAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEkRlcGVuZGVuY3lEZWNsYXJlZAAAAAAAAQAAABNkZXBlbmRlbmN5X2RlY2xhcmVkAAAAAAQAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAA5kZXBlbmRlbmN5X2tleQAAAAAADgAAAAAAAAAAAAAAC3JlcXVpcmVtZW50AAAAABAAAAAAAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEURlcGVuZGVuY3lSZW1vdmVkAAAAAAAAAQAAABJkZXBlbmRlbmN5X3JlbW92ZWQAAAAAAAMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAA5kZXBlbmRlbmN5X2tleQAAAAAADgAAAAAAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEEFydGlmYWN0QXR0ZXN0ZWQAAAABAAAAEWFydGlmYWN0X2F0dGVzdGVkAAAAAAAABQAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAAAAAAAC2NvbW1pdF9oYXNoAAAAABAAAAAAAAAAAAAAAAZkaWdlc3QAAAAAA+4AAAAgAAAAAAAAAAAAAAAIYXR0ZXN0ZXIAAAATAAAAAAAAAAAAAAAIdmVyaWZpZWQAAAABAAAAAAAAAAI=",
         "AAAABQAAAAAAAAAAAAAAC01lbWJlckFkZGVkAAAAAAEAAAAMbWVtYmVyX2FkZGVkAAAAAQAAAAAAAAAObWVtYmVyX2FkZHJlc3MAAAAAABMAAAAAAAAAAg==",
         "AAAABQAAAAAAAAAAAAAADUJhZGdlc1VwZGF0ZWQAAAAAAAABAAAADmJhZGdlc191cGRhdGVkAAAAAAAEAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAAAAAABm1lbWJlcgAAAAAAEwAAAAAAAAAAAAAADGJhZGdlc19jb3VudAAAAAQAAAAAAAAAAg==",
         "AAAABQAAAAAAAAAAAAAAD1Byb3Bvc2FsQ3JlYXRlZAAAAAABAAAAEHByb3Bvc2FsX2NyZWF0ZWQAAAAHAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAAAAAAAAAAABXRpdGxlAAAAAAAAEAAAAAAAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAAAAAADnZvdGluZ19lbmRzX2F0AAAAAAAGAAAAAAAAAAAAAAANcHVibGljX3ZvdGluZwAAAAAAAAEAAAAAAAAAAAAAAA50b2tlbl9jb250cmFjdAAAAAAD6AAAABMAAAAAAAAAAg==",
@@ -109,13 +238,17 @@ export class Client extends ContractClient {
         "AAAABQAAAAAAAAAAAAAADkNvbnRyYWN0UGF1c2VkAAAAAAABAAAAD2NvbnRyYWN0X3BhdXNlZAAAAAACAAAAAAAAAAZwYXVzZWQAAAAAAAEAAAAAAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAg==",
         "AAAABQAAAAAAAAAAAAAAFEFub255bW91c1ZvdGluZ1NldHVwAAAAAQAAABZhbm9ueW1vdXNfdm90aW5nX3NldHVwAAAAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAAAAAACnB1YmxpY19rZXkAAAAAABAAAAAAAAAAAg==",
         "AAAABQAAAAAAAAAAAAAAD0NvbnRyYWN0VXBkYXRlZAAAAAABAAAAEGNvbnRyYWN0X3VwZGF0ZWQAAAAEAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAAAAAAxjb250cmFjdF9rZXkAAAAQAAAAAAAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAAAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD6AAAA+4AAAAgAAAAAAAAAAI=",
-        "AAAABQAAAAAAAAAAAAAAD1VwZ3JhZGVQcm9wb3NlZAAAAAABAAAAEHVwZ3JhZGVfcHJvcG9zZWQAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAAOAAAAAAAAAAAAAAANZXhlY3V0YWJsZV9hdAAAAAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADE5hbWVSZXNlcnZlZAAAAAEAAAANbmFtZV9yZXNlcnZlZAAAAAAAAAMAAAAAAAAABG5hbWUAAAAQAAAAAQAAAAAAAAAIcmVzZXJ2ZWQAAAABAAAAAAAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAD1VwZ3JhZGVQcm9wb3NlZAAAAAABAAAAEHVwZ3JhZGVfcHJvcG9zZWQAAAAEAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAAOAAAAAAAAAAAAAAANZXhlY3V0YWJsZV9hdAAAAAAAAAYAAAAAAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAI=",
         "AAAABQAAAAAAAAAAAAAAD1VwZ3JhZGVBcHByb3ZlZAAAAAABAAAAEHVwZ3JhZGVfYXBwcm92ZWQAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAAAAAA9hcHByb3ZhbHNfY291bnQAAAAABAAAAAAAAAAAAAAAEXRocmVzaG9sZF9yZWFjaGVkAAAAAAAAAQAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAFlVwZ3JhZGVBcHByb3ZhbFJldm9rZWQAAAAAAAEAAAAYdXBncmFkZV9hcHByb3ZhbF9yZXZva2VkAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAAAAAAPYXBwcm92YWxzX2NvdW50AAAAAAQAAAAAAAAAAg==",
         "AAAABQAAAAAAAAAAAAAADVVwZ3JhZGVTdGF0dXMAAAAAAAABAAAADnVwZ3JhZGVfc3RhdHVzAAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAAOAAAAAAAAAAAAAAAGc3RhdHVzAAAAAAAQAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADlNjaGVtYU1pZ3JhdGVkAAAAAAABAAAAD3NjaGVtYV9taWdyYXRlZAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAAAAAAA5zY2hlbWFfdmVyc2lvbgAAAAAABAAAAAAAAAAC",
         "AAAABQAAAAAAAAAAAAAAElN1YlByb2plY3RzVXBkYXRlZAAAAAAAAQAAABRzdWJfcHJvamVjdHNfdXBkYXRlZAAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAABAAAAAAAAAAxzdWJfcHJvamVjdHMAAAPqAAAADgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADlBhcmVudEFjY2VwdGVkAAAAAAABAAAAD3BhcmVudF9hY2NlcHRlZAAAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAAAAAAAAKcGFyZW50X2tleQAAAAAADgAAAAAAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAAg==",
         "AAAAAQAAAAAAAAAAAAAACENvbnRyYWN0AAAAAgAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPoAAAD7gAAACA=",
         "AAAAAgAAAAAAAAAAAAAAC0NvbnRyYWN0S2V5AAAAAAIAAAAAAAAAAAAAAA5Eb21haW5Db250cmFjdAAAAAAAAAAAAAAAAAASQ29sbGF0ZXJhbENvbnRyYWN0AAA=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAEAAAAAAAAABk1lbWJlcgAAAAAAAQAAABMAAAAAAAAAAAAAAAZQYXVzZWQAAAAAAAAAAAAAAAAAD1VwZ3JhZGVQcm9wb3NhbAAAAAAAAAAAAAAAAAxBZG1pbnNDb25maWc=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAADAAAAAEAAAAAAAAABk1lbWJlcgAAAAAAAQAAABMAAAAAAAAAAAAAAAZQYXVzZWQAAAAAAAAAAAAAAAAAD1VwZ3JhZGVQcm9wb3NhbAAAAAAAAAAAAAAAAAxBZG1pbnNDb25maWcAAAABAAAAAAAAABJNYWludGFpbmVyUHJvamVjdHMAAAAAAAIAAAATAAAABAAAAAEAAAAAAAAAF1RvdGFsTWFpbnRhaW5lclByb2plY3RzAAAAAAEAAAATAAAAAQAAAAAAAAAOTWVtYmVyUHJvamVjdHMAAAAAAAIAAAATAAAABAAAAAEAAAAAAAAAE1RvdGFsTWVtYmVyUHJvamVjdHMAAAAAAQAAABMAAAAAAAAAAAAAAA1TY2hlbWFWZXJzaW9uAAAAAAAAAAAAAAAAAAAPTWlncmF0aW9uQ3Vyc29yAAAAAAAAAAAAAAAADk1pZ3JhdGlvbkVudHJ5AAAAAAAAAAAAAAAAABBQZW5kaW5nTWlncmF0aW9u",
         "AAAAAQAAAAAAAAAAAAAABkJhZGdlcwAAAAAABAAAAAAAAAAJY29tbXVuaXR5AAAAAAAD6gAAABMAAAAAAAAACWRldmVsb3BlcgAAAAAAA+oAAAATAAAAAAAAAAZ0cmlhZ2UAAAAAA+oAAAATAAAAAAAAAAh2ZXJpZmllZAAAA+oAAAAT",
         "AAAAAwAAAAAAAAAAAAAABUJhZGdlAAAAAAAABQAAAAAAAAAJRGV2ZWxvcGVyAAAAAJiWgAAAAAAAAAAGVHJpYWdlAAAATEtAAAAAAAAAAAlDb21tdW5pdHkAAAAAD0JAAAAAAAAAAAhWZXJpZmllZAAHoSAAAAAAAAAAB0RlZmF1bHQAAAAAAQ==",
         "AAAAAQAAAAAAAAAAAAAADVByb2plY3RCYWRnZXMAAAAAAAACAAAAAAAAAAZiYWRnZXMAAAAAA+oAAAfQAAAABUJhZGdlAAAAAAAAAAAAAAdwcm9qZWN0AAAAAA4=",
@@ -128,13 +261,44 @@ export class Client extends ContractClient {
         "AAAAAQAAAAAAAAAAAAAADUFub255bW91c1ZvdGUAAAAAAAAFAAAAAAAAAAdhZGRyZXNzAAAAABMAAAAAAAAAC2NvbW1pdG1lbnRzAAAAA+oAAAPuAAAAYAAAAAAAAAAPZW5jcnlwdGVkX3NlZWRzAAAAA+oAAAAQAAAAAAAAAA9lbmNyeXB0ZWRfdm90ZXMAAAAD6gAAABAAAAAAAAAABndlaWdodAAAAAAABA==",
         "AAAAAQAAAAAAAAAAAAAACFZvdGVEYXRhAAAABAAAAAAAAAANcHVibGljX3ZvdGluZwAAAAAAAAEAAAAAAAAADnRva2VuX2NvbnRyYWN0AAAAAAPoAAAAEwAAAAAAAAAFdm90ZXMAAAAAAAPqAAAH0AAAAARWb3RlAAAAAAAAAA52b3RpbmdfZW5kc19hdAAAAAAABg==",
         "AAAAAQAAAAAAAAAAAAAAE0Fub255bW91c1ZvdGVDb25maWcAAAAAAwAAAAAAAAAKcHVibGljX2tleQAAAAAAEAAAAAAAAAAUc2VlZF9nZW5lcmF0b3JfcG9pbnQAAAPuAAAAYAAAAAAAAAAUdm90ZV9nZW5lcmF0b3JfcG9pbnQAAAPuAAAAYA==",
-        "AAAAAQAAAAAAAAAAAAAADEFkbWluc0NvbmZpZwAAAAIAAAAAAAAABmFkbWlucwAAAAAD6gAAABMAAAAAAAAACXRocmVzaG9sZAAAAAAAAAQ=",
-        "AAAAAQAAAAAAAAAAAAAAD1VwZ3JhZGVQcm9wb3NhbAAAAAAEAAAAAAAAAA1hZG1pbnNfY29uZmlnAAAAAAAH0AAAAAxBZG1pbnNDb25maWcAAAAAAAAACWFwcHJvdmFscwAAAAAAA+oAAAATAAAAAAAAAA1leGVjdXRhYmxlX2F0AAAAAAAABgAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
-        "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAABwAAAAAAAAACaWQAAAAAAAQAAAAAAAAABGlwZnMAAAAQAAAAAAAAABFvdXRjb21lX2NvbnRyYWN0cwAAAAAAA+gAAAPqAAAH0AAAAA9PdXRjb21lQ29udHJhY3QAAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAABnN0YXR1cwAAAAAH0AAAAA5Qcm9wb3NhbFN0YXR1cwAAAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAACXZvdGVfZGF0YQAAAAAAB9AAAAAIVm90ZURhdGE=",
+        "AAAAAQAAAAAAAAAAAAAADEFkbWluc0NvbmZpZwAAAAMAAAAAAAAABmFkbWlucwAAAAAD6gAAABMAAAAAAAAACXRocmVzaG9sZAAAAAAAAAQAAAAAAAAACHRpbWVsb2NrAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAAD1VwZ3JhZGVQcm9wb3NhbAAAAAAGAAAAAAAAAA1hZG1pbnNfY29uZmlnAAAAAAAH0AAAAAxBZG1pbnNDb25maWcAAAAAAAAACWFwcHJvdmFscwAAAAAAA+oAAAATAAAAAAAAAA1leGVjdXRhYmxlX2F0AAAAAAAABgAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAJbWlncmF0aW9uAAAAAAAH0AAAABBVcGdyYWRlTWlncmF0aW9uAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIA==",
+        "AAAAAQAAAAAAAAAAAAAADU1pZ3JhdGlvbkNhbGwAAAAAAAACAAAAAAAAAARhcmdzAAAD6gAAAAAAAAAAAAAACGZ1bmN0aW9uAAAAEQ==",
+        "AAAAAgAAAAAAAAAAAAAAEFVwZ3JhZGVNaWdyYXRpb24AAAACAAAAAAAAAAAAAAAETm9uZQAAAAEAAAAAAAAABENhbGwAAAABAAAH0AAAAA1NaWdyYXRpb25DYWxsAAAA",
+        "AAAAAQAAAAAAAAAAAAAAEFBlbmRpbmdNaWdyYXRpb24AAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABGNhbGwAAAfQAAAADU1pZ3JhdGlvbkNhbGwAAAA=",
+        "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAABwAAAAAAAAACaWQAAAAAAAQAAAAAAAAABGlwZnMAAAAOAAAAAAAAABFvdXRjb21lX2NvbnRyYWN0cwAAAAAAA+gAAAPqAAAH0AAAAA9PdXRjb21lQ29udHJhY3QAAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAABnN0YXR1cwAAAAAH0AAAAA5Qcm9wb3NhbFN0YXR1cwAAAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAACXZvdGVfZGF0YQAAAAAAB9AAAAAIVm90ZURhdGE=",
         "AAAAAQAAAAAAAAAAAAAAA0RhbwAAAAABAAAAAAAAAAlwcm9wb3NhbHMAAAAAAAPqAAAH0AAAAAhQcm9wb3NhbA==",
-        "AAAAAgAAAAAAAAAAAAAAClByb2plY3RLZXkAAAAAAAgAAAABAAAAAAAAAANLZXkAAAAAAQAAAA4AAAABAAAAAAAAAAZCYWRnZXMAAAAAAAEAAAAOAAAAAQAAAAAAAAAITGFzdEhhc2gAAAABAAAADgAAAAEAAAAAAAAAA0RhbwAAAAACAAAADgAAAAQAAAABAAAAAAAAABFEYW9Ub3RhbFByb3Bvc2FscwAAAAAAAAEAAAAOAAAAAQAAAAAAAAATQW5vbnltb3VzVm90ZUNvbmZpZwAAAAABAAAADgAAAAEAAAAAAAAAC1Byb2plY3RLZXlzAAAAAAEAAAAEAAAAAAAAAAAAAAANVG90YWxQcm9qZWN0cwAAAA==",
-        "AAAAAQAAAAAAAAAAAAAABkNvbmZpZwAAAAAAAgAAAAAAAAAEaXBmcwAAABAAAAAAAAAAA3VybAAAAAAQ",
-        "AAAAAQAAAAAAAAAAAAAAB1Byb2plY3QAAAAABAAAAAAAAAAGY29uZmlnAAAAAAfQAAAABkNvbmZpZwAAAAAAAAAAAAttYWludGFpbmVycwAAAAPqAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAADHN1Yl9wcm9qZWN0cwAAA+gAAAPqAAAADg==",
+        "AAAAAgAAAAAAAAAAAAAAClByb2plY3RLZXkAAAAAACsAAAABAAAAAAAAAANLZXkAAAAAAQAAAA4AAAABAAAAAAAAAAZCYWRnZXMAAAAAAAEAAAAOAAAAAQAAAAAAAAAITGFzdEhhc2gAAAABAAAADgAAAAEAAAAAAAAAA0RhbwAAAAACAAAADgAAAAQAAAABAAAAAAAAABFEYW9Ub3RhbFByb3Bvc2FscwAAAAAAAAEAAAAOAAAAAQAAAAAAAAATQW5vbnltb3VzVm90ZUNvbmZpZwAAAAABAAAADgAAAAEAAAAAAAAAC1Byb2plY3RLZXlzAAAAAAEAAAAEAAAAAAAAAAAAAAANVG90YWxQcm9qZWN0cwAAAAAAAAEAAAAAAAAADUNvbW1pdEhpc3RvcnkAAAAAAAACAAAADgAAAAQAAAABAAAAAAAAAAxUb3RhbENvbW1pdHMAAAABAAAADgAAAAEAAAAAAAAAC0NvbW1pdEluZGV4AAAAAAIAAAAOAAAADgAAAAEAAAAAAAAAB1JlbGVhc2UAAAAAAgAAAA4AAAAQAAAAAQAAAAAAAAAIUmVsZWFzZXMAAAACAAAADgAAAAQAAAABAAAAAAAAAA1Ub3RhbFJlbGVhc2VzAAAAAAAAAQAAAA4AAAABAAAAAAAAAA1MYXRlc3RSZWxlYXNlAAAAAAAAAQAAAA4AAAABAAAAAAAAAA5Db21taXRSZWxlYXNlcwAAAAAAAgAAAA4AAAAOAAAAAQAAAAAAAAAEWWFuawAAAAIAAAAOAAAADgAAAAEAAAAAAAAACEFkdmlzb3J5AAAAAgAAAA4AAAAEAAAAAQAAAAAAAAAPVG90YWxBZHZpc29yaWVzAAAAAAEAAAAOAAAAAQAAAAAAAAAKQnJhbmNoSGVhZAAAAAAAAgAAAA4AAAAQAAAAAQAAAAAAAAAIQnJhbmNoZXMAAAABAAAADgAAAAEAAAAAAAAADlBlbmRpbmdDb21taXRzAAAAAAABAAAADgAAAAEAAAAAAAAACE1hbmlmZXN0AAAAAgAAAA4AAAAOAAAAAQAAAAAAAAAMQXR0ZXN0YXRpb25zAAAAAgAAAA4AAAAOAAAAAQAAAAAAAAAUQXR0ZXN0YXRpb25UaHJlc2hvbGQAAAABAAAADgAAAAEAAAAAAAAACERlbGVnYXRlAAAAAgAAAA4AAAATAAAAAQAAAAAAAAARTWFpbnRhaW5lcnNDaGFuZ2UAAAAAAAABAAAADgAAAAEAAAAAAAAAEU1haW50YWluZXJzUXVvcnVtAAAAAAAAAQAAAA4AAAABAAAAAAAAAAZTdGF0dXMAAAAAAAEAAAAOAAAAAQAAAAAAAAAPQWN0aW9uQXBwcm92YWxzAAAAAAIAAAAOAAAH0AAAAA1Qcm9qZWN0QWN0aW9uAAAAAAAAAQAAAAAAAAAFQWxpYXMAAAAAAAABAAAADgAAAAEAAAAAAAAADVRyYW5zZmVyT2ZmZXIAAAAAAAABAAAADgAAAAEAAAAAAAAABlBhcmVudAAAAAAAAQAAAA4AAAABAAAAAAAAAA1QYXJlbnRDb25zZW50AAAAAAAAAQAAAA4AAAABAAAAAAAAAAxEZXBlbmRlbmNpZXMAAAABAAAADgAAAAEAAAAAAAAACkRlcGVuZGVudHMAAAAAAAIAAAAOAAAABAAAAAEAAAAAAAAAD1RvdGFsRGVwZW5kZW50cwAAAAABAAAADgAAAAEAAAAAAAAABUZvcmtzAAAAAAAAAgAAAA4AAAAEAAAAAQAAAAAAAAAKVG90YWxGb3JrcwAAAAAAAQAAAA4AAAABAAAAAAAAAAhNZXRhZGF0YQAAAAEAAAAOAAAAAQAAAAAAAAAIUmVzZXJ2ZWQAAAABAAAADgAAAAEAAAAAAAAAC1R0bEV4dGVuZGVkAAAAAAEAAAAOAAAAAQAAAAAAAAAGTGlzdGVkAAAAAAABAAAADg==",
+        "AAAAAgAAAAAAAAAAAAAADUhhc2hBbGdvcml0aG0AAAAAAAAGAAAAAAAAAAAAAAADQW55AAAAAAAAAAAAAAAAB0dpdFNoYTEAAAAAAAAAAAAAAAAJR2l0U2hhMjU2AAAAAAAAAAAAAAAAAAAJTWVyY3VyaWFsAAAAAAAAAAAAAAAAAAAGRm9zc2lsAAAAAAAAAAAAAAAAAAZTaGEyNTYAAA==",
+        "AAAAAQAAAAAAAAAAAAAABkNvbmZpZwAAAAAABQAAAAAAAAAWY29tbWl0X2FwcHJvdmFsX3dpbmRvdwAAAAAABgAAAAAAAAAQY29tbWl0X3RocmVzaG9sZAAAAAQAAAAAAAAADmhhc2hfYWxnb3JpdGhtAAAAAAfQAAAADUhhc2hBbGdvcml0aG0AAAAAAAAAAAAABGlwZnMAAAAOAAAAAAAAAAN1cmwAAAAAEA==",
+        "AAAAAQAAAAAAAAAAAAAAB1Byb2plY3QAAAAABQAAAAAAAAAGY29uZmlnAAAAAAfQAAAABkNvbmZpZwAAAAAAAAAAAAttYWludGFpbmVycwAAAAPqAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAABm9yaWdpbgAAAAAH0AAAAAZPcmlnaW4AAAAAAAAAAAAMc3ViX3Byb2plY3RzAAAD6AAAA+oAAAAO",
+        "AAAAAQAAAAAAAAAAAAAACE1ldGFkYXRhAAAABQAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAIaG9tZXBhZ2UAAAAQAAAAAAAAAAdsaWNlbnNlAAAAABAAAAAAAAAAB21pcnJvcnMAAAAD6gAAABAAAAAAAAAABHRhZ3MAAAPqAAAAEA==",
+        "AAAAAQAAAAAAAAAAAAAADlByb2plY3RTdW1tYXJ5AAAAAAAGAAAAAAAAAARpcGZzAAAADgAAAAAAAAARbWFpbnRhaW5lcnNfY291bnQAAAAAAAAEAAAAAAAAAAhtZXRhZGF0YQAAB9AAAAAITWV0YWRhdGEAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAANUHJvamVjdFN0YXR1cwAAAAAAAAAAAAADdXJsAAAAABA=",
+        "AAAAAgAAAAAAAAAAAAAADVByb2plY3RGaWx0ZXIAAAAAAAAFAAAAAAAAAAAAAAADQWxsAAAAAAAAAAAAAAAABkhhc0RhbwAAAAAAAAAAAAAAAAASSGFzQW5vbnltb3VzVm90aW5nAAAAAAAAAAAAAAAAAAhBcmNoaXZlZAAAAAEAAAAAAAAACk1haW50YWluZXIAAAAAAAEAAAAT",
+        "AAAAAQAAAAAAAAAAAAAAD1Byb2plY3RMaXN0SXRlbQAAAAAFAAAAAAAAAANrZXkAAAAADgAAAAAAAAARbWFpbnRhaW5lcnNfY291bnQAAAAAAAAEAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAGc3RhdHVzAAAAAAfQAAAADVByb2plY3RTdGF0dXMAAAAAAAAAAAAAA3VybAAAAAAQ",
+        "AAAAAQAAAAAAAAAAAAAAC1Byb2plY3RMaXN0AAAAAAIAAAAAAAAABWl0ZW1zAAAAAAAD6gAAB9AAAAAPUHJvamVjdExpc3RJdGVtAAAAAAAAAAALbmV4dF9jdXJzb3IAAAAD6AAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAAD01pZ3JhdGlvblN0YXR1cwAAAAADAAAAAAAAAAZjdXJzb3IAAAAAAAQAAAAAAAAABGRvbmUAAAABAAAAAAAAAA5zY2hlbWFfdmVyc2lvbgAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAAC0luZGV4UmVwb3J0AAAAAAcAAAAAAAAACmNvbnNpc3RlbnQAAAAAAAEAAAAAAAAACmR1cGxpY2F0ZXMAAAAAA+oAAAAOAAAAAAAAAAZsaXN0ZWQAAAAAAAQAAAAAAAAACW5leHRfcGFnZQAAAAAAA+gAAAAEAAAAAAAAAA1wYXJ0aWFsX3BhZ2VzAAAAAAAD6gAAAAQAAAAAAAAABXRvdGFsAAAAAAAABAAAAAAAAAAHdW5rbm93bgAAAAPqAAAADg==",
+        "AAAAAQAAAAAAAAAAAAAACVR0bFJlcG9ydAAAAAAAAAUAAAAAAAAAB2VudHJpZXMAAAAABAAAAAAAAAAJZXh0ZW5kX3RvAAAAAAAABAAAAAAAAAALZXh0ZW5kZWRfYXQAAAAABAAAAAAAAAAKbGl2ZV91bnRpbAAAAAAABAAAAAAAAAAJdGhyZXNob2xkAAAAAAAABA==",
+        "AAAAAgAAAAAAAAAAAAAABk9yaWdpbgAAAAAAAgAAAAAAAAAAAAAACE9yaWdpbmFsAAAAAQAAAAAAAAAERm9yawAAAAEAAAfQAAAABEZvcms=",
+        "AAAAAQAAAAAAAAAAAAAABEZvcmsAAAADAAAAAAAAAAtjb21taXRfaGFzaAAAAAPoAAAADgAAAAAAAAAJdGltZXN0YW1wAAAAAAAABgAAAAAAAAAIdXBzdHJlYW0AAAAO",
+        "AAAAAQAAAAAAAAAAAAAAC1Byb2plY3ROb2RlAAAAAAQAAAAAAAAABWRlcHRoAAAAAAAABAAAAAAAAAADa2V5AAAAAA4AAAAAAAAABG5hbWUAAAAQAAAAAAAAAAZwYXJlbnQAAAAAA+gAAAAO",
+        "AAAAAQAAAAAAAAAAAAAADENvbW1pdFJlY29yZAAAAAUAAAAAAAAABmJyYW5jaAAAAAAAEAAAAAAAAAAJY29tbWl0dGVyAAAAAAAAEwAAAAAAAAAEaGFzaAAAAA4AAAAAAAAACHNlcXVlbmNlAAAABAAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAAB1JlbGVhc2UAAAAABQAAAAAAAAALY29tbWl0X2hhc2gAAAAADgAAAAAAAAAEaXBmcwAAAA4AAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAYAAAAAAAAAB3ZlcnNpb24AAAAAEA==",
+        "AAAAAgAAAAAAAAAAAAAACk1lcmtsZUhhc2gAAAAAAAIAAAAAAAAAAAAAAAlLZWNjYWsyNTYAAAAAAAAAAAAAAAAAAAZTaGEyNTYAAA==",
+        "AAAAAQAAAAAAAAAAAAAACE1hbmlmZXN0AAAABAAAAAAAAAANaGFzaF9mdW5jdGlvbgAAAAAAB9AAAAAKTWVya2xlSGFzaAAAAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAARyb290AAAD7gAAACAAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAY=",
+        "AAAAAQAAAAAAAAAAAAAABFlhbmsAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAZyZWFzb24AAAAAABAAAAAAAAAACXRpbWVzdGFtcAAAAAAAAAY=",
+        "AAAAAgAAAAAAAAAAAAAACFNldmVyaXR5AAAABAAAAAAAAAAAAAAAA0xvdwAAAAAAAAAAAAAAAAZNZWRpdW0AAAAAAAAAAAAAAAAABEhpZ2gAAAAAAAAAAAAAAAhDcml0aWNhbA==",
+        "AAAAAQAAAAAAAAAAAAAACEFkdmlzb3J5AAAABwAAAAAAAAAIYWZmZWN0ZWQAAAAQAAAAAAAAAA1maXhlZF92ZXJzaW9uAAAAAAAD6AAAABAAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAARpcGZzAAAADgAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAIc2V2ZXJpdHkAAAfQAAAACFNldmVyaXR5AAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+        "AAAAAQAAAAAAAAAAAAAACkRlcGVuZGVuY3kAAAAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAAC3JlcXVpcmVtZW50AAAAABA=",
+        "AAAAAQAAAAAAAAAAAAAADkFydGlmYWN0RGlnZXN0AAAAAAACAAAAAAAAAAlhdHRlc3RlcnMAAAAAAAPqAAAAEwAAAAAAAAAGZGlnZXN0AAAAAAPuAAAAIA==",
+        "AAAAAQAAAAAAAAAAAAAAEUJ1aWxkVmVyaWZpY2F0aW9uAAAAAAAAAwAAAAAAAAAHZGlnZXN0cwAAAAPqAAAH0AAAAA5BcnRpZmFjdERpZ2VzdAAAAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAEAAAAAAAAAA92ZXJpZmllZF9kaWdlc3QAAAAD6AAAA+4AAAAg",
+        "AAAAAgAAAAAAAAAAAAAADVByb2plY3RTdGF0dXMAAAAAAAADAAAAAAAAAAAAAAAGQWN0aXZlAAAAAAAAAAAAAAAAAAhBcmNoaXZlZAAAAAAAAAAAAAAADERlcmVnaXN0ZXJlZA==",
+        "AAAAAgAAAAAAAAAAAAAADVByb2plY3RBY3Rpb24AAAAAAAAEAAAAAAAAAAAAAAAHQXJjaGl2ZQAAAAAAAAAAAAAAAApEZXJlZ2lzdGVyAAAAAAABAAAAAAAAAAZSZW5hbWUAAAAAAAEAAAAQAAAAAQAAAAAAAAAIVHJhbnNmZXIAAAABAAAD6gAAABM=",
+        "AAAAAQAAAAAAAAAAAAAAEU1haW50YWluZXJzQ2hhbmdlAAAAAAAABQAAAAAAAAADYWRkAAAAA+oAAAATAAAAAAAAAAlhcHByb3ZhbHMAAAAAAAPqAAAAEwAAAAAAAAANZXhlY3V0YWJsZV9hdAAAAAAAAAYAAAAAAAAABnF1b3J1bQAAAAAD6AAAAAQAAAAAAAAABnJlbW92ZQAAAAAD6gAAABM=",
+        "AAAAAgAAAAAAAAAAAAAABFJvbGUAAAACAAAAAAAAAAAAAAAKTWFpbnRhaW5lcgAAAAAAAQAAAAAAAAAJQ29tbWl0dGVyAAAAAAAAAQAAABA=",
+        "AAAAAQAAAAAAAAAAAAAACERlbGVnYXRlAAAAAwAAAAAAAAAIYnJhbmNoZXMAAAPqAAAAEAAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEw==",
+        "AAAAAQAAAAAAAAAAAAAADVBlbmRpbmdDb21taXQAAAAAAAAFAAAAAAAAAAlhcHByb3ZhbHMAAAAAAAPqAAAAEwAAAAAAAAAGYnJhbmNoAAAAAAAQAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAARoYXNoAAAADgAAAAAAAAALcGFyZW50X2hhc2gAAAAD6AAAAA4=",
       ]),
       options,
     );
@@ -155,25 +319,90 @@ export class Client extends ContractClient {
     get_member: this.txFromJSON,
     set_badges: this.txFromJSON,
     get_badges: this.txFromJSON,
+    get_projects_by_member: this.txFromJSON,
     get_max_weight: this.txFromJSON,
     add_projects_to_pagination: this.txFromJSON,
+    migrate: this.txFromJSON,
+    get_migration_status: this.txFromJSON,
+    verify_project_index: this.txFromJSON,
+    yank: this.txFromJSON,
+    get_yank: this.txFromJSON,
+    publish_advisory: this.txFromJSON,
+    get_advisory: this.txFromJSON,
+    is_version_affected: this.txFromJSON,
+    attest_build: this.txFromJSON,
+    set_attestation_threshold: this.txFromJSON,
+    get_build_verification: this.txFromJSON,
+    add_dependency: this.txFromJSON,
+    remove_dependency: this.txFromJSON,
+    get_dependencies: this.txFromJSON,
+    get_dependents: this.txFromJSON,
     pause: this.txFromJSON,
     require_not_paused: this.txFromJSON,
     get_admins_config: this.txFromJSON,
     set_domain_contract: this.txFromJSON,
     set_collateral_contract: this.txFromJSON,
+    set_reserved_name: this.txFromJSON,
+    is_name_reserved: this.txFromJSON,
     propose_upgrade: this.txFromJSON,
     approve_upgrade: this.txFromJSON,
+    revoke_upgrade_approval: this.txFromJSON,
     finalize_upgrade: this.txFromJSON,
     get_upgrade_proposal: this.txFromJSON,
     version: this.txFromJSON,
     register: this.txFromJSON,
+    register_fork: this.txFromJSON,
+    get_forks: this.txFromJSON,
     update_config: this.txFromJSON,
+    set_metadata: this.txFromJSON,
+    propose_maintainers_change: this.txFromJSON,
+    approve_maintainers_change: this.txFromJSON,
+    finalize_maintainers_change: this.txFromJSON,
+    get_maintainers_change: this.txFromJSON,
+    get_maintainers_quorum: this.txFromJSON,
+    archive_project: this.txFromJSON,
+    deregister_project: this.txFromJSON,
+    get_project_status: this.txFromJSON,
+    rename_project: this.txFromJSON,
+    resolve_project_key: this.txFromJSON,
+    transfer_project: this.txFromJSON,
+    accept_transfer: this.txFromJSON,
+    cancel_transfer: this.txFromJSON,
+    get_transfer_offer: this.txFromJSON,
     commit: this.txFromJSON,
+    force_commit: this.txFromJSON,
+    approve_commit: this.txFromJSON,
+    get_pending_commits: this.txFromJSON,
+    set_hash_algorithm: this.txFromJSON,
+    set_commit_threshold: this.txFromJSON,
+    add_delegate: this.txFromJSON,
+    revoke_delegate: this.txFromJSON,
+    get_delegate: this.txFromJSON,
     get_commit: this.txFromJSON,
+    commit_branch: this.txFromJSON,
+    get_branch_head: this.txFromJSON,
+    list_branches: this.txFromJSON,
+    delete_branch: this.txFromJSON,
+    get_commit_history: this.txFromJSON,
+    get_commit_at: this.txFromJSON,
+    tag_release: this.txFromJSON,
+    get_release: this.txFromJSON,
+    list_releases: this.txFromJSON,
+    latest_release: this.txFromJSON,
+    publish_manifest: this.txFromJSON,
+    get_manifest: this.txFromJSON,
+    verify_inclusion: this.txFromJSON,
     get_project: this.txFromJSON,
+    get_project_summary: this.txFromJSON,
+    extend_project_ttl: this.txFromJSON,
+    get_ttl_report: this.txFromJSON,
     get_projects: this.txFromJSON,
+    list_projects: this.txFromJSON,
+    get_projects_by_maintainer: this.txFromJSON,
     get_sub_projects: this.txFromJSON,
     set_sub_projects: this.txFromJSON,
+    accept_parent: this.txFromJSON,
+    get_parent: this.txFromJSON,
+    get_project_tree: this.txFromJSON,
   };
 }
@@ -295,7 +295,7 @@ const CreateProjectModal: FC<ModalProps> = ({ onClose }) => {
   const handleRegisterProject = async () => {
     setIsLoading(true);
     // Dynamic imports for heavy libs
-    const [{ fetchTomlFromIpfs }] = await Promise.all([
+    const [{ cidToString, fetchTomlFromIpfs }] = await Promise.all([
       import("utils/ipfsFunctions"),
     ]);
     const { loadedPublicKey } = await import("@service/walletService");
@@ -350,7 +350,9 @@ ${maintainerGithubs.map((gh) => `[[PRINCIPALS]]\ngithub="${gh}"`).join("\n\n")}
           setProjectRepoInfo(username, repoName);
         }
 
-        const tomlData = await fetchTomlFromIpfs(project.config.ipfs);
+        const tomlData = await fetchTomlFromIpfs(
+          cidToString(project.config.ipfs),
+        );
         if (tomlData) {
           const configData = extractConfigData(tomlData, project);
           setConfigData(configData);
@@ -1,4 +1,7 @@
-import { fetchTomlFromIpfs } from "../../../utils/ipfsFunctions";
+import {
+  cidToString,
+  fetchTomlFromIpfs,
+} from "../../../utils/ipfsFunctions";
 import {
   getProjectFromName,
   getProjectHash,
@@ -47,7 +50,9 @@ const ProjectCard = ({ config }: { config: ProjectConfig }) => {
 
         (async () => {
           try {
-            const tomlData = await fetchTomlFromIpfs(project.config.ipfs);
+            const tomlData = await fetchTomlFromIpfs(
+              cidToString(project.config.ipfs),
+            );
             if (tomlData) {
               setConfigData(extractConfigData(tomlData, project));
             }
@@ -1,7 +1,10 @@
 import { useStore } from "@nanostores/react";
 import { useEffect, useState, useCallback, useRef } from "react";
 import { getFeaturedProjectsConfigData } from "../../../constants/featuredProjectsConfigData.js";
-import { fetchTomlFromIpfs } from "../../../utils/ipfsFunctions";
+import {
+  cidToString,
+  fetchTomlFromIpfs,
+} from "../../../utils/ipfsFunctions";
 import {
   getProjectFromName,
   getMember,
@@ -215,7 +218,9 @@ const ProjectList = () => {
     try {
       const project = await getProjectFromName(projectName);
       if (project && project.name && project.config && project.maintainers) {
-        const tomlData = await fetchTomlFromIpfs(project.config.ipfs);
+        const tomlData = await fetchTomlFromIpfs(
+          cidToString(project.config.ipfs),
+        );
         if (tomlData) {
           const configData = extractConfigData(tomlData, project);
           setConfigInfo(configData);
@@ -311,7 +316,7 @@ const ProjectList = () => {
       setIsLoadingOnChain(false);
 
       const results = await Promise.allSettled(
-        projects.map((p) => fetchTomlFromIpfs(p.config.ipfs)),
+        projects.map((p) => fetchTomlFromIpfs(cidToString(p.config.ipfs))),
       );
       const enrichedList = projects.map((project, i) => {
         const result = results[i];
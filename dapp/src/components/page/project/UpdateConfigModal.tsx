@@ -141,7 +141,6 @@ const UpdateConfigModal = () => {
       await updateConfigFlow({
         tomlFile,
         githubRepoUrl,
-        maintainers: maintainerAddresses,
         onProgress: setStep,
       });
       // refresh state
@@ -212,18 +211,14 @@ const UpdateConfigModal = () => {
                     <Step step={1} totalSteps={3} />
                     <Title
                       title="Maintainers"
-                      description="Edit maintainer addresses & GitHub handles"
+                      description="Edit maintainer GitHub handles, addresses are changed with a maintainers proposal"
                     />
                     {maintainerAddresses.map((addr, i) => (
                       <div key={i} className="flex gap-3 mb-3">
                         <Input
                           value={addr ?? ""}
                           error={addrErrors[i] || undefined}
-                          onChange={(e) => {
-                            const v = [...maintainerAddresses];
-                            v[i] = e.target.value;
-                            setMaintainerAddresses(v);
-                          }}
+                          readOnly
                         />
                         <Input
                           value={maintainerGithubs[i] ?? ""}
@@ -236,17 +231,6 @@ const UpdateConfigModal = () => {
                         />
                       </div>
                     ))}
-                    <Button
-                      type="tertiary"
-                      onClick={() => {
-                        setMaintainerAddresses([...maintainerAddresses, ""]);
-                        setMaintainerGithubs([...maintainerGithubs, ""]);
-                        setAddrErrors([...addrErrors, null]);
-                        setGhErrors([...ghErrors, null]);
-                      }}
-                    >
-                      Add Maintainer
-                    </Button>
                     <div className="flex justify-end mt-4">
                       <Button
                         onClick={() => {
@@ -18,6 +18,8 @@ export const contractErrorMessages = {
   100: "The user is not a maintainer.",
   101: "You are invalid voter.",
   102: "The maintainer is not the domain owner.",
+  103: "The commit needs the approval of other maintainers.",
+  104: "Not enough maintainers have approved yet.",
 
   // Validation (200-299)
   200: "The provided key is invalid.",
@@ -31,18 +33,52 @@ export const contractErrorMessages = {
   208: "Bad commitment.",
   209: "Invalid voter weight calculation.",
   210: "Too many voters already.",
+  211: "The project has a newer commit, reload it and try again.",
+  212: "The version is not a valid semantic version.",
+  213: "This release already exists.",
+  214: "This commit has already been yanked.",
+  215: "Too many branches. Delete a branch first.",
+  216: "The branch name is invalid.",
+  217: "The threshold is invalid.",
+  218: "Too many commits are waiting for approval.",
+  219: "The commit hash has an invalid length.",
+  220: "The commit hash is not valid hexadecimal.",
+  221: "A manifest was already published for this commit.",
+  222: "You have already attested this build.",
+  223: "Too many attestations for this build.",
+  224: "The maintainers change is invalid.",
+  225: "The sub-project is invalid.",
+  226: "The sub-projects would form a cycle.",
+  227: "Too many dependencies.",
+  228: "The project metadata is invalid.",
+  229: "The IPFS CID is invalid.",
+  230: "The project name must be 1 to 15 characters long.",
+  231: "The project name can only contain letters.",
+  232: "This project name is reserved.",
 
   // State (300-399)
   300: "No hash was found.",
   301: "Proposal or page could not be found.",
   302: "Project page could not be found.",
   303: "This is not the anonymous voting config.",
+  304: "Release could not be found.",
+  305: "Advisory could not be found.",
+  306: "Manifest could not be found.",
+  307: "The delegate does not exist.",
+  308: "Maintainers change could not be found.",
+  309: "A maintainers change is already pending.",
+  310: "The project is archived or deregistered.",
+  311: "Transfer offer could not be found.",
+  312: "The sub-project has not accepted this parent.",
+  313: "Dependency could not be found.",
+  314: "Upgrade approval could not be found.",
 
   // Execution/Timing (400-499)
   400: "You have already voted.",
   401: "The proposal is still in voting, so cannot be executed.",
   402: "The proposal has already been executed.",
   403: "There was an error executing outcome contracts.",
+  404: "The expiry must be in the future.",
 
   // Voting/Cryptographic (500-599)
   500: "There is a tally seed error.",
@@ -53,6 +89,7 @@ export const contractErrorMessages = {
   601: "Contract upgrade error.",
   602: "Contract validation error.",
   603: "Collateral error.",
+  604: "A storage migration is pending.",
 };
 
 export type ContractErrorMessageKey = keyof typeof contractErrorMessages;
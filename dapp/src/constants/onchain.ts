@@ -16,11 +16,11 @@ export const ONCHAIN_METHODS: Record<string, MethodMeta> = {
     summary: () => "created project",
   },
   commit: {
-    paramNames: ["maintainer", "project_key", "hash"],
+    paramNames: ["maintainer", "project_key", "hash", "parent_hash"],
     summary: (d) => `committed ${((d.hash as string) ?? "").slice(0, 7)}`,
   },
   update_config: {
-    paramNames: ["maintainer", "project_key", "maintainers", "url", "hash"],
+    paramNames: ["maintainer", "project_key", "url", "hash"],
     summary: () => "updated config",
   },
   add_member: {
@@ -15,6 +15,7 @@ import { handleFreighterError } from "../utils/errorHandler";
 //
 import type { VoteType } from "types/proposal";
 import { signAndSend } from "./TxService";
+import { getProjectHash } from "./ReadContractService";
 
 /**
  * Get configured contract client instance (using proven working Tansu instance)
@@ -98,10 +99,14 @@ export async function commitHash(commit_hash: string): Promise<boolean> {
     ? projectId
     : Buffer.from(projectId, "hex");
 
+  // Chain the commit to the anchored one so that a concurrent update fails
+  const parentHash = await getProjectHash();
+
   const assembledTx = await client.commit({
     maintainer: client.options.publicKey!,
     project_key: projectKey,
     hash: commit_hash,
+    parent_hash: parentHash ?? undefined,
   });
 
   // Check for simulation errors (contract errors) before submitting
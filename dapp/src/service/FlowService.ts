@@ -378,7 +378,6 @@ export async function createProjectFlow({
 
 /** Create and sign an update_config transaction */
 async function createSignedUpdateConfigTransaction(
-  maintainers: string[],
   configUrl: string,
   cid: string,
 ): Promise<string> {
@@ -398,7 +397,6 @@ async function createSignedUpdateConfigTransaction(
   const tx = await Tansu.update_config({
     maintainer: publicKey,
     key: projectKey,
-    maintainers: maintainers,
     url: configUrl,
     ipfs: cid,
   });
@@ -412,12 +410,10 @@ async function createSignedUpdateConfigTransaction(
 export async function updateConfigFlow({
   tomlFile,
   githubRepoUrl,
-  maintainers,
   onProgress,
 }: {
   tomlFile: File;
   githubRepoUrl: string;
-  maintainers: string[];
   onProgress?: (step: number) => void;
 }): Promise<boolean> {
   const expectedCid = await calculateDirectoryCid([tomlFile]);
@@ -428,7 +424,6 @@ export async function updateConfigFlow({
   // sign tx
   onProgress?.(7); // UI offset -4 → shows "Sign"
   const signedTxXdr = await createSignedUpdateConfigTransaction(
-    maintainers,
     githubRepoUrl,
     expectedCid,
   );
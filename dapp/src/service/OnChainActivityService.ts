@@ -216,8 +216,10 @@ export async function fetchOnChainActions(
           }
           case "update_config": {
             projectKey = paramBytesToHex(args[1]);
-            details.url = paramToString(args[3]);
-            details.ipfs = paramToString(args[4]); // ipfs is arg4 (maintainer, key, maintainers, url, ipfs)
+            // (maintainer, key, url, ipfs), older calls also had maintainers
+            const offset = args.length > 4 ? 1 : 0;
+            details.url = paramToString(args[2 + offset]);
+            details.ipfs = paramToString(args[3 + offset]);
             break;
           }
           case "add_member": {
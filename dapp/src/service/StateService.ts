@@ -9,6 +9,7 @@ import { projectInfo as projectInfoStore } from "utils/store";
 import { projectRepoInfo as projectRepoInfoStore } from "utils/store";
 import { projectLatestSha as projectLatestShaStore } from "utils/store";
 import { configData as configDataStore } from "utils/store";
+import { cidToString } from "utils/ipfsFunctions";
 
 const projectState: {
   project_name: string | undefined;
@@ -42,7 +43,10 @@ const projectLatestSha: {
   sha: undefined,
 };
 
-// Add this new type definition
+// The current project, with the CID of its configuration as a string
+type ProjectInfo = Pick<Project, "name" | "maintainers" | "sub_projects"> & {
+  config: { url: string; ipfs: string };
+};
 
 // Add this new state variable
 let configData: ConfigData | undefined = undefined;
@@ -81,7 +85,7 @@ function setProjectId(project_name: string): void {
 function setProject(project: Project): void {
   projectInfo.project_maintainers = project.maintainers;
   projectInfo.project_config_url = project.config.url;
-  projectInfo.project_config_ipfs = project.config.ipfs;
+  projectInfo.project_config_ipfs = cidToString(project.config.ipfs);
   if (!project.sub_projects) {
     project.sub_projects = [];
   }
@@ -91,7 +95,7 @@ function setProject(project: Project): void {
   }
 }
 
-function loadProjectInfo(): Project | undefined {
+function loadProjectInfo(): ProjectInfo | undefined {
   if (
     !projectInfo.project_maintainers ||
     !projectInfo.project_config_url ||
@@ -1,5 +1,6 @@
 /** IPFS gateway and fetch helpers. Single retrieval path: CID + path → cache → gateways. */
 
+import { CID } from "multiformats/cid";
 import toml from "toml";
 
 const VALID_CID_PATTERN = /^(bafy|Qm)[a-zA-Z0-9]{44,}$/;
@@ -210,6 +211,19 @@ export async function fetchTomlFromIpfs(
 /** @deprecated Use fetchTomlFromIpfs. Kept for compatibility. */
 export const fetchTomlFromCid = fetchTomlFromIpfs;
 
+/**
+ * Encode a binary CID, as stored by the contract, to its string form:
+ * base58btc for a CIDv0 and base32 for a CIDv1.
+ */
+export function cidToString(cid: Uint8Array | undefined): string {
+  if (!cid || cid.length === 0) return "";
+  try {
+    return CID.decode(cid).toString();
+  } catch {
+    return "";
+  }
+}
+
 // --- URL helpers (display only; do not use for fetch) ---
 
 export const getIpfsBasicLink = (cid: string): string => {
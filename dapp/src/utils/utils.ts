@@ -11,6 +11,7 @@ import {
   type ProposalView,
   type ProposalViewStatus,
 } from "types/proposal";
+import { cidToString } from "utils/ipfsFunctions";
 
 export function truncateMiddle(str: string, maxLength: number): string {
   if (str.length <= maxLength) return str;
@@ -176,7 +177,7 @@ export const modifyProposalFromContract = (
     return {
       id: proposal.id,
       title: proposal.title,
-      ipfs: proposal.ipfs,
+      ipfs: cidToString(proposal.ipfs),
       proposer: proposal.proposer,
       status,
       voting_ends_at: Number(proposal.vote_data.voting_ends_at),
@@ -232,7 +233,7 @@ export const modifyProposalFromContract = (
   return {
     id: proposal.id,
     title: proposal.title,
-    ipfs: proposal.ipfs,
+    ipfs: cidToString(proposal.ipfs),
     proposer: proposal.proposer,
     status,
     voting_ends_at: Number(proposal.vote_data.voting_ends_at),